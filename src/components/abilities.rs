@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use crate::components::ai::EnemyType;
 
 /// Special abilities unlocked from capstones
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -112,18 +113,19 @@ impl AbilityController {
     pub fn activate(&mut self, ability: SpecialAbility) -> bool {
         if self.is_unlocked(ability) && !self.is_on_cooldown(ability) {
             self.cooldowns.push((ability, ability.cooldown()));
-            println!("[Abilities] Activated: {}", ability.name());
             true
         } else {
             false
         }
     }
     
-    pub fn update_cooldowns(&mut self, delta: f32) {
+    /// Tick cooldowns and effects. Returns the abilities that came off cooldown this tick.
+    pub fn update_cooldowns(&mut self, delta: f32) -> Vec<SpecialAbility> {
+        let mut ready = Vec::new();
         self.cooldowns.retain_mut(|(ability, time)| {
             *time -= delta;
             if *time <= 0.0 {
-                println!("[Abilities] {} ready!", ability.name());
+                ready.push(*ability);
                 false
             } else {
                 true
@@ -134,6 +136,37 @@ impl AbilityController {
             effect.remaining_duration -= delta;
             effect.remaining_duration > 0.0
         });
+        
+        ready
+    }
+    
+    /// Abilities for an elite enemy - more of them unlock in harder systems
+    pub fn for_enemy(enemy_type: EnemyType, difficulty: u32) -> Option<Self> {
+        let abilities: &[(SpecialAbility, u32)] = match enemy_type {
+            EnemyType::Frigate => &[
+                (SpecialAbility::QuantumDash, 2),
+                (SpecialAbility::FortressMode, 4),
+            ],
+            EnemyType::CapitalShip => &[
+                (SpecialAbility::FortressMode, 1),
+                (SpecialAbility::MissileStorm, 3),
+                (SpecialAbility::QuantumDash, 6),
+            ],
+            EnemyType::Fighter | EnemyType::Corvette => &[],
+        };
+        
+        let mut controller = Self::new();
+        for (ability, min_difficulty) in abilities {
+            if difficulty >= *min_difficulty {
+                controller.unlocked_abilities.push(*ability);
+            }
+        }
+        
+        if controller.unlocked_abilities.is_empty() {
+            None
+        } else {
+            Some(controller)
+        }
     }
     
     pub fn add_active_effect(&mut self, effect: ActiveEffect) {
@@ -158,7 +191,18 @@ pub enum AbilityEffectType {
     Invisible,
     AllWeaponsFiring,
     DamageBoost(u32), // Percentage
-    Immobile,
+}
+
+/// A quantum dash in flight - the ship is carried to `destination` over a few ticks,
+/// so it slides there instead of popping
+#[derive(Component)]
+pub struct QuantumDashMotion {
+    pub destination: Vec3,
+    pub remaining: f32,
+}
+
+impl QuantumDashMotion {
+    pub const DURATION: f32 = 0.2;
 }
 
 /// Marker for ability visual effects
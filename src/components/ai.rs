@@ -1,14 +1,170 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// AI behavior state
+/// AI behavior state - the maneuver currently being flown
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum AIBehaviorState {
     Patrol,
     Pursue,
     Attack,
+    Flank,
     Evade,
     Retreat,
+    Regroup,
+}
+
+/// Tactical actions the utility AI scores on every think tick
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AIAction {
+    Attack,
+    Flank,
+    Evade,
+    Retreat,
+    Regroup,
+}
+
+impl AIAction {
+    pub const ALL: [AIAction; 5] = [
+        AIAction::Attack,
+        AIAction::Flank,
+        AIAction::Evade,
+        AIAction::Retreat,
+        AIAction::Regroup,
+    ];
+
+    pub fn index(&self) -> usize {
+        match self {
+            AIAction::Attack => 0,
+            AIAction::Flank => 1,
+            AIAction::Evade => 2,
+            AIAction::Retreat => 3,
+            AIAction::Regroup => 4,
+        }
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            AIAction::Attack => "ATK",
+            AIAction::Flank => "FLK",
+            AIAction::Evade => "EVA",
+            AIAction::Retreat => "RET",
+            AIAction::Regroup => "REG",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AIAction::Attack => "ATTACK",
+            AIAction::Flank => "FLANK",
+            AIAction::Evade => "EVADE",
+            AIAction::Retreat => "RETREAT",
+            AIAction::Regroup => "REGROUP",
+        }
+    }
+}
+
+/// What an AI ship knows about its situation, gathered each think tick
+#[derive(Clone, Copy, Debug)]
+pub struct AIPerception {
+    pub distance: f32,       // World units to the target
+    pub own_health: f32,     // Combined hull/shield fraction (0-1)
+    pub target_health: f32,  // Target's combined hull/shield fraction (0-1)
+    pub incoming_fire: f32,  // Hostile shots on a collision course (0-1, saturates at 3)
+    pub allies_nearby: f32,  // Friendly ships in support range (0-1, saturates at 3)
+    pub has_allies: bool,    // Any friendly ship close enough to regroup with
+    pub morale: f32,         // Squadron morale (1 for ships flying solo)
+}
+
+/// Per-enemy-type utility weights - how much each action appeals to this hull
+#[derive(Clone, Copy, Debug)]
+pub struct UtilityProfile {
+    pub attack: f32,
+    pub flank: f32,
+    pub evade: f32,
+    pub retreat: f32,
+    pub regroup: f32,
+    pub preferred_range: f32, // Distance this ship likes to fight at
+    pub engage_range: f32,    // Beyond this, attacking means closing in first
+    pub commitment: f32,      // Score margin a rival action needs to replace the current one
+}
+
+impl UtilityProfile {
+    pub fn for_enemy(enemy_type: EnemyType) -> Self {
+        match enemy_type {
+            // Nimble dogfighters - dodge a lot, swing wide, bail when hurt
+            EnemyType::Fighter => Self {
+                attack: 1.0, flank: 0.9, evade: 1.0, retreat: 0.6, regroup: 0.5,
+                preferred_range: 30.0, engage_range: 100.0, commitment: 0.1,
+            },
+            // Escorts - stick together and fall back to the group
+            EnemyType::Corvette => Self {
+                attack: 1.0, flank: 0.7, evade: 0.8, retreat: 0.8, regroup: 0.8,
+                preferred_range: 45.0, engage_range: 100.0, commitment: 0.12,
+            },
+            // Line ships - work the target's flank at medium range
+            EnemyType::Frigate => Self {
+                attack: 1.0, flank: 1.0, evade: 0.6, retreat: 0.7, regroup: 0.6,
+                preferred_range: 60.0, engage_range: 120.0, commitment: 0.15,
+            },
+            // Capital ships - slug it out and rarely change their minds
+            EnemyType::CapitalShip => Self {
+                attack: 1.2, flank: 0.3, evade: 0.3, retreat: 0.4, regroup: 0.3,
+                preferred_range: 80.0, engage_range: 120.0, commitment: 0.2,
+            },
+        }
+    }
+
+    /// Utility of an action given the current perception (0 = never, ~1 = strongly preferred)
+    pub fn score(&self, action: AIAction, p: &AIPerception, aggression: f32, evasiveness: f32) -> f32 {
+        let advantage = p.own_health - p.target_health; // -1 (losing) to 1 (winning)
+        let range_fit = (1.0 - ((p.distance - self.preferred_range) / self.preferred_range).abs()).clamp(0.0, 1.0);
+
+        let score = match action {
+            AIAction::Attack => {
+                self.attack
+                    * (0.3 + 0.7 * p.own_health)
+                    * (0.5 + 0.5 * aggression)
+                    * (1.0 - 0.4 * p.incoming_fire)
+                    * (1.0 + 0.3 * advantage)
+                    * (0.5 + 0.5 * p.morale)
+            }
+            AIAction::Flank => {
+                // Swing around when the target is at a workable range and shooting back
+                self.flank
+                    * p.own_health
+                    * (0.3 + 0.7 * range_fit)
+                    * (0.4 + 0.6 * p.incoming_fire)
+                    * (0.7 + 0.3 * p.allies_nearby)
+            }
+            AIAction::Evade => {
+                self.evade
+                    * p.incoming_fire
+                    * (1.2 - p.own_health).clamp(0.0, 1.0)
+                    * (0.6 + evasiveness)
+            }
+            AIAction::Retreat => {
+                let damage = 1.0 - p.own_health;
+                self.retreat
+                    * damage * damage
+                    * (1.0 + (-advantage).max(0.0))
+                    * (1.0 - 0.5 * p.allies_nearby)
+                    * (1.0 - 0.5 * aggression)
+                    * (2.0 - p.morale)
+                    * 2.0
+            }
+            AIAction::Regroup => {
+                if !p.has_allies {
+                    0.0
+                } else {
+                    self.regroup
+                        * (1.0 - p.allies_nearby)
+                        * (1.0 - 0.5 * p.own_health)
+                        * (0.5 + 0.5 * p.incoming_fire)
+                }
+            }
+        };
+        score.max(0.0)
+    }
 }
 
 /// AI controller component
@@ -17,9 +173,18 @@ pub struct AIController {
     pub state: AIBehaviorState,
     pub target: Option<Entity>,
     pub patrol_point: Vec3,
+    pub regroup_point: Vec3,
     pub aggression: f32,
     pub evasion_threshold: f32,
     pub state_timer: f32,
+    pub profile: UtilityProfile,
+    pub action: AIAction,
+    pub scores: [f32; 5], // Latest utility score per AIAction (indexed by AIAction::index)
+    pub think_timer: f32, // Seconds until the next re-evaluation
+    pub squad_order: Option<AIAction>, // Action the squadron leader wants from this wingman
+    pub formation_slot: Option<Vec3>,  // World position to hold while the squadron closes in
+    pub flank_side: f32,               // Orbit direction when flanking (+1 / -1), set per wing for pincers
+    pub distress_sent: bool,           // Already called for reinforcements while retreating
 }
 
 /// Enemy ship type
@@ -31,6 +196,28 @@ pub enum EnemyType {
     CapitalShip,
 }
 
+impl EnemyType {
+    /// Chance a retreating ship of this type calls for reinforcements
+    pub fn distress_chance(&self) -> f32 {
+        match self {
+            EnemyType::Fighter => 0.3,
+            EnemyType::Corvette => 0.5,
+            EnemyType::Frigate => 0.7,
+            EnemyType::CapitalShip => 1.0,
+        }
+    }
+
+    /// Threat points a ship of this type costs against the encounter director's budget
+    pub fn threat(&self) -> u32 {
+        match self {
+            EnemyType::Fighter => 1,
+            EnemyType::Corvette => 2,
+            EnemyType::Frigate => 4,
+            EnemyType::CapitalShip => 8,
+        }
+    }
+}
+
 /// Enemy marker component
 #[derive(Component)]
 pub struct Enemy {
@@ -38,14 +225,28 @@ pub struct Enemy {
 }
 
 impl AIController {
+    /// Score bonus for the action a squadron leader has ordered
+    pub const SQUAD_ORDER_BONUS: f32 = 0.3;
+    /// Seconds a retreating ship has to survive before its distress call gets out
+    pub const DISTRESS_TRANSMIT_TIME: f32 = 3.0;
+
     pub fn new() -> Self {
         Self {
             state: AIBehaviorState::Patrol,
             target: None,
             patrol_point: Vec3::ZERO,
+            regroup_point: Vec3::ZERO,
             aggression: 0.7,
             evasion_threshold: 0.3,
             state_timer: 0.0,
+            profile: UtilityProfile::for_enemy(EnemyType::Corvette),
+            action: AIAction::Attack,
+            scores: [0.0; 5],
+            think_timer: 0.0,
+            squad_order: None,
+            formation_slot: None,
+            flank_side: 1.0,
+            distress_sent: false,
         }
     }
 
@@ -54,9 +255,18 @@ impl AIController {
             state: AIBehaviorState::Patrol,
             target: None,
             patrol_point: Vec3::ZERO,
+            regroup_point: Vec3::ZERO,
             aggression: 0.9,
             evasion_threshold: 0.4,
             state_timer: 0.0,
+            profile: UtilityProfile::for_enemy(EnemyType::Fighter),
+            action: AIAction::Attack,
+            scores: [0.0; 5],
+            think_timer: 0.0,
+            squad_order: None,
+            formation_slot: None,
+            flank_side: 1.0,
+            distress_sent: false,
         }
     }
 
@@ -65,9 +275,18 @@ impl AIController {
             state: AIBehaviorState::Patrol,
             target: None,
             patrol_point: Vec3::ZERO,
+            regroup_point: Vec3::ZERO,
             aggression: 0.7,
             evasion_threshold: 0.35,
             state_timer: 0.0,
+            profile: UtilityProfile::for_enemy(EnemyType::Corvette),
+            action: AIAction::Attack,
+            scores: [0.0; 5],
+            think_timer: 0.0,
+            squad_order: None,
+            formation_slot: None,
+            flank_side: 1.0,
+            distress_sent: false,
         }
     }
 
@@ -76,9 +295,18 @@ impl AIController {
             state: AIBehaviorState::Patrol,
             target: None,
             patrol_point: Vec3::ZERO,
+            regroup_point: Vec3::ZERO,
             aggression: 0.6,
             evasion_threshold: 0.25,
             state_timer: 0.0,
+            profile: UtilityProfile::for_enemy(EnemyType::Frigate),
+            action: AIAction::Attack,
+            scores: [0.0; 5],
+            think_timer: 0.0,
+            squad_order: None,
+            formation_slot: None,
+            flank_side: 1.0,
+            distress_sent: false,
         }
     }
 
@@ -87,10 +315,134 @@ impl AIController {
             state: AIBehaviorState::Attack,
             target: None,
             patrol_point: Vec3::ZERO,
+            regroup_point: Vec3::ZERO,
             aggression: 0.5,
             evasion_threshold: 0.15,
             state_timer: 0.0,
+            profile: UtilityProfile::for_enemy(EnemyType::CapitalShip),
+            action: AIAction::Attack,
+            scores: [0.0; 5],
+            think_timer: 0.0,
+            squad_order: None,
+            formation_slot: None,
+            flank_side: 1.0,
+            distress_sent: false,
+        }
+    }
+
+    /// Score every action and switch when a rival beats the current one by the commitment margin.
+    /// Returns true when the chosen action changed.
+    pub fn decide(&mut self, perception: &AIPerception) -> bool {
+        for action in AIAction::ALL {
+            self.scores[action.index()] = self.profile.score(action, perception, self.aggression, self.evasion_threshold);
+        }
+        if let Some(order) = self.squad_order {
+            self.scores[order.index()] += Self::SQUAD_ORDER_BONUS;
+        }
+
+        let (best, best_score) = AIAction::ALL.iter()
+            .map(|a| (*a, self.scores[a.index()]))
+            .fold((self.action, f32::MIN), |acc, (a, score)| if score > acc.1 { (a, score) } else { acc });
+
+        if best != self.action && best_score > self.scores[self.action.index()] + self.profile.commitment {
+            self.action = best;
+            return true;
+        }
+        false
+    }
+
+    /// Maneuver that carries out the current action at the given distance
+    pub fn behavior_for_action(&self, distance: f32) -> AIBehaviorState {
+        match self.action {
+            AIAction::Attack if distance > self.profile.engage_range => AIBehaviorState::Pursue,
+            AIAction::Attack => AIBehaviorState::Attack,
+            AIAction::Flank => AIBehaviorState::Flank,
+            AIAction::Evade => AIBehaviorState::Evade,
+            AIAction::Retreat => AIBehaviorState::Retreat,
+            AIAction::Regroup => AIBehaviorState::Regroup,
         }
     }
 }
 
+/// Pilot skill - how fast and how well an AI ship shoots, scaled by the system's difficulty
+#[derive(Component, Clone, Debug)]
+pub struct AISkill {
+    pub reaction_time: f32, // Seconds to register a new target and catch up with its maneuvers
+    pub aim_error: f32,     // Half-angle of the aim error cone (radians)
+    pub lead_quality: f32,  // Fraction of the true intercept lead applied (0 = fire at the hull, 1 = perfect)
+    pub burst_length: u32,  // Shots per burst before easing off the trigger
+    pub burst_pause: f32,   // Seconds between bursts
+    pub tracked_target: Option<Entity>,
+    pub reaction_timer: f32,     // Counts down after a target change - no firing until it hits 0
+    pub perceived_velocity: Vec3, // Target velocity as this pilot currently reads it
+    pub burst_shots: u32,
+    pub burst_timer: f32,
+}
+
+impl AISkill {
+    pub fn for_enemy(enemy_type: EnemyType, difficulty: u32) -> Self {
+        // (reaction time, aim error in degrees, lead quality, burst length, burst pause)
+        let (reaction_time, aim_error, lead_quality, burst_length, burst_pause) = match enemy_type {
+            EnemyType::Fighter => (0.6, 4.0, 0.5, 3, 1.0),
+            EnemyType::Corvette => (0.7, 3.5, 0.6, 4, 1.2),
+            EnemyType::Frigate => (0.5, 2.5, 0.8, 5, 1.0),
+            EnemyType::CapitalShip => (0.8, 2.0, 0.9, 6, 1.5),
+        };
+        
+        // Difficulty 1 flies rookies, difficulty 10 flies aces
+        let veterancy = (difficulty.clamp(1, 10) - 1) as f32 / 9.0;
+        
+        Self {
+            reaction_time: reaction_time * (1.0 - 0.6 * veterancy),
+            aim_error: (aim_error * (1.0 - 0.7 * veterancy)).to_radians(),
+            lead_quality: lead_quality + (1.0 - lead_quality) * veterancy,
+            burst_length: burst_length + (veterancy * 3.0) as u32,
+            burst_pause: burst_pause * (1.0 - 0.5 * veterancy),
+            tracked_target: None,
+            reaction_timer: reaction_time,
+            perceived_velocity: Vec3::ZERO,
+            burst_shots: 0,
+            burst_timer: 0.0,
+        }
+    }
+    
+    /// Follow the target's motion - a new target restarts the reaction delay, and maneuvers
+    /// are only picked up over roughly one reaction time
+    pub fn track(&mut self, target: Entity, target_velocity: Vec3, dt: f32) {
+        if self.tracked_target != Some(target) {
+            self.tracked_target = Some(target);
+            self.reaction_timer = self.reaction_time;
+            self.perceived_velocity = target_velocity;
+            self.burst_shots = 0;
+        }
+        
+        self.reaction_timer = (self.reaction_timer - dt).max(0.0);
+        self.burst_timer = (self.burst_timer - dt).max(0.0);
+        let catch_up = (dt / self.reaction_time.max(0.05)).min(1.0);
+        self.perceived_velocity = self.perceived_velocity.lerp(target_velocity, catch_up);
+    }
+    
+    /// Where this pilot actually aims, given the target's position and the solved intercept point
+    pub fn aim_point(&self, target_pos: Vec3, intercept: Vec3) -> Vec3 {
+        target_pos + (intercept - target_pos) * self.lead_quality
+    }
+    
+    pub fn ready_to_fire(&self) -> bool {
+        self.reaction_timer <= 0.0 && self.burst_timer <= 0.0
+    }
+    
+    /// Count a shot toward the current burst, pausing once the burst is spent
+    pub fn register_shot(&mut self) {
+        self.burst_shots += 1;
+        if self.burst_shots >= self.burst_length {
+            self.burst_shots = 0;
+            self.burst_timer = self.burst_pause;
+        }
+    }
+}
+
+/// Debug overlay showing every AI ship's decision and action scores (F12)
+#[derive(Resource, Default)]
+pub struct AIDebugOverlay {
+    pub enabled: bool,
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::abilities::SpecialAbility;
+use crate::components::ai::EnemyType;
+use crate::components::combat::WeaponType;
+use crate::components::galaxy::SystemId;
+
+/// One stage of a boss fight - entered when the boss's hull drops to `health_threshold`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BossPhase {
+    pub health_threshold: f32,            // Fraction of max hull (1.0 = opening phase)
+    pub weapons: Vec<WeaponType>,         // Replaces the boss's weapon mount
+    pub adds: Vec<EnemyType>,             // Escorts launched on entering the phase
+    pub abilities: Vec<SpecialAbility>,   // Unlocked (and readied) on entering the phase
+}
+
+/// Unique flagship guarding a high-difficulty system
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BossDefinition {
+    pub name: String,
+    pub health: f32,
+    pub shield: f32,
+    pub color: [f32; 3], // RGB
+    pub phases: Vec<BossPhase>,
+}
+
+impl BossDefinition {
+    /// Systems at or above this difficulty are guarded by a boss
+    pub const MIN_DIFFICULTY: u32 = 6;
+
+    pub fn generate(difficulty: u32, rng: &mut impl rand::Rng) -> Option<Self> {
+        if difficulty < Self::MIN_DIFFICULTY {
+            return None;
+        }
+
+        let titles = ["Warlord", "Dread Captain", "Tyrant", "Reaver Lord", "Butcher"];
+        let names = ["Vask", "Korrin", "Malreth", "Ixon", "Sable", "Drummond"];
+        let name = format!(
+            "{} {}",
+            titles[rng.gen_range(0..titles.len())],
+            names[rng.gen_range(0..names.len())],
+        );
+
+        // Every boss escalates the same way, the opening loadout and escort mix vary
+        let opening = if rng.gen_bool(0.5) {
+            vec![WeaponType::Laser, WeaponType::Plasma]
+        } else {
+            vec![WeaponType::Autocannon, WeaponType::Plasma]
+        };
+        let heavy_escort = if rng.gen_bool(0.5) { EnemyType::Corvette } else { EnemyType::Frigate };
+
+        let mut phases = vec![
+            BossPhase {
+                health_threshold: 1.0,
+                weapons: opening,
+                adds: Vec::new(),
+                abilities: Vec::new(),
+            },
+            BossPhase {
+                health_threshold: 0.7,
+                weapons: vec![WeaponType::Plasma, WeaponType::Missile, WeaponType::FlakCannon],
+                adds: vec![EnemyType::Fighter, EnemyType::Fighter],
+                abilities: vec![SpecialAbility::FortressMode],
+            },
+            BossPhase {
+                health_threshold: 0.4,
+                weapons: vec![WeaponType::Railgun, WeaponType::BeamLaser, WeaponType::Missile],
+                adds: vec![heavy_escort, EnemyType::Fighter, EnemyType::Fighter],
+                abilities: vec![SpecialAbility::QuantumDash, SpecialAbility::MissileStorm],
+            },
+        ];
+
+        // The deepest systems get a last stand
+        if difficulty >= 9 {
+            phases.push(BossPhase {
+                health_threshold: 0.15,
+                weapons: vec![WeaponType::BeamLaser, WeaponType::IonCannon, WeaponType::Railgun, WeaponType::Missile],
+                adds: vec![EnemyType::Frigate, EnemyType::Corvette, EnemyType::Corvette],
+                abilities: vec![SpecialAbility::FortressMode, SpecialAbility::MissileStorm],
+            });
+        }
+
+        Some(Self {
+            name,
+            health: 1500.0 + difficulty as f32 * 250.0,
+            shield: 800.0 + difficulty as f32 * 120.0,
+            color: [rng.gen_range(0.5..0.8), rng.gen_range(0.05..0.2), rng.gen_range(0.4..0.7)],
+            phases,
+        })
+    }
+}
+
+/// Live boss ship - tracks which phase of its definition it's in
+#[derive(Component, Clone, Debug)]
+pub struct Boss {
+    pub name: String,
+    pub system_id: SystemId,
+    pub phases: Vec<BossPhase>,
+    pub phase: usize,
+}
+
+impl Boss {
+    /// Next phase whose threshold the hull has dropped to, if any
+    pub fn pending_phase(&self, hull_fraction: f32) -> Option<usize> {
+        self.phases.iter()
+            .enumerate()
+            .skip(self.phase + 1)
+            .take_while(|(_, phase)| hull_fraction <= phase.health_threshold)
+            .last()
+            .map(|(index, _)| index)
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+/// Capital ship hangar - launches fighter wings at intervals while the hangar bay survives
+#[derive(Component, Clone, Debug)]
+pub struct Carrier {
+    pub launch_interval: f32, // Seconds between wing launches
+    pub launch_timer: f32,
+    pub wing_size: usize,     // Fighters per launch
+    pub max_fighters: usize,  // Cap on this carrier's fighters alive at once
+    pub launched: Vec<Entity>,
+    pub hangar_online: bool,
+    pub turrets_online: usize,
+    pub difficulty: u32,      // Launched fighters are scaled like the carrier's own system
+}
+
+impl Carrier {
+    /// Ship-local position of the hangar bay (launch point)
+    pub const HANGAR_OFFSET: Vec3 = Vec3::new(0.0, -0.9, -2.0);
+
+    pub fn new(difficulty: u32) -> Self {
+        Self {
+            launch_interval: (25.0 - difficulty as f32 * 1.5).max(12.0),
+            launch_timer: 8.0, // First wing launches shortly after the carrier arrives
+            wing_size: if difficulty >= 5 { 3 } else { 2 },
+            max_fighters: (2 + difficulty as usize).min(6),
+            launched: Vec::new(),
+            hangar_online: true,
+            turrets_online: 0,
+            difficulty,
+        }
+    }
+}
+
+/// Fighter launched from a carrier's hangar
+#[derive(Component, Clone, Debug)]
+pub struct CarrierFighter {
+    pub carrier: Entity,
+}
@@ -18,6 +18,153 @@ pub struct Shield {
     pub time_since_last_hit: f32,
 }
 
+/// Shield quadrant relative to the ship's orientation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShieldFacing {
+    Front,
+    Rear,
+    Left,
+    Right,
+}
+
+impl ShieldFacing {
+    pub const ALL: [ShieldFacing; 4] = [ShieldFacing::Front, ShieldFacing::Rear, ShieldFacing::Left, ShieldFacing::Right];
+
+    pub fn index(&self) -> usize {
+        match self {
+            ShieldFacing::Front => 0,
+            ShieldFacing::Rear => 1,
+            ShieldFacing::Left => 2,
+            ShieldFacing::Right => 3,
+        }
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            ShieldFacing::Front => "FWD",
+            ShieldFacing::Rear => "AFT",
+            ShieldFacing::Left => "PORT",
+            ShieldFacing::Right => "STBD",
+        }
+    }
+
+    /// Direction of this facing in ship-local space (ships fly along -Z)
+    pub fn local_direction(&self) -> Vec3 {
+        match self {
+            ShieldFacing::Front => Vec3::NEG_Z,
+            ShieldFacing::Rear => Vec3::Z,
+            ShieldFacing::Left => Vec3::NEG_X,
+            ShieldFacing::Right => Vec3::X,
+        }
+    }
+
+    /// Which facing takes a hit at `impact_point`, from the hit vector in the ship's local frame
+    pub fn from_hit(ship_transform: &Transform, impact_point: Vec3) -> Self {
+        let local = ship_transform.rotation.inverse() * (impact_point - ship_transform.translation);
+        if local.z.abs() >= local.x.abs() {
+            if local.z <= 0.0 { ShieldFacing::Front } else { ShieldFacing::Rear }
+        } else if local.x < 0.0 {
+            ShieldFacing::Left
+        } else {
+            ShieldFacing::Right
+        }
+    }
+}
+
+/// Directional shield quadrants - splits the `Shield` pool into four facings.
+/// `Shield.current` is kept equal to the sum of the facings so the rest of the game can treat it as one pool.
+#[derive(Component, Clone, Debug)]
+pub struct ShieldFacings {
+    pub current: [f32; 4],
+    pub allocation: [f32; 4], // Share of `Shield.max` each facing can hold (sums to 1.0)
+}
+
+impl ShieldFacings {
+    pub const MIN_ALLOCATION: f32 = 0.1;
+    pub const SHUNT_STEP: f32 = 0.15;
+
+    /// Balanced facings holding `total` shield points
+    pub fn new(total: f32) -> Self {
+        let mut facings = Self {
+            current: [0.0; 4],
+            allocation: [0.25; 4],
+        };
+        facings.redistribute(total);
+        facings
+    }
+
+    pub fn total(&self) -> f32 {
+        self.current.iter().sum()
+    }
+
+    pub fn capacity(&self, facing: ShieldFacing, max: f32) -> f32 {
+        self.allocation[facing.index()] * max
+    }
+
+    pub fn fraction(&self, facing: ShieldFacing, max: f32) -> f32 {
+        let capacity = self.capacity(facing, max);
+        if capacity > 0.0 { self.current[facing.index()] / capacity } else { 0.0 }
+    }
+
+    /// Spread a shield total across the facings according to the allocation
+    pub fn redistribute(&mut self, total: f32) {
+        for i in 0..4 {
+            self.current[i] = total.max(0.0) * self.allocation[i];
+        }
+    }
+
+    /// Drain one facing. Returns the damage the facing couldn't absorb.
+    pub fn absorb(&mut self, facing: ShieldFacing, damage: f32) -> f32 {
+        let slot = &mut self.current[facing.index()];
+        let absorbed = damage.min(*slot);
+        *slot -= absorbed;
+        damage - absorbed
+    }
+
+    /// Recharge facings toward their capacity, spilling over into facings that still have room
+    pub fn recharge(&mut self, amount: f32, max: f32) {
+        let mut remaining = amount;
+        // Two passes: proportional share first, then leftovers into whatever isn't full
+        for pass in 0..2 {
+            for facing in ShieldFacing::ALL {
+                let i = facing.index();
+                let room = (self.capacity(facing, max) - self.current[i]).max(0.0);
+                let share = if pass == 0 { amount * self.allocation[i] } else { remaining };
+                let added = share.min(room).min(remaining);
+                self.current[i] += added;
+                remaining -= added;
+            }
+        }
+    }
+
+    /// Move shield capacity (and any charge above the new limits) from the other facings into `facing`
+    pub fn shunt(&mut self, facing: ShieldFacing, max: f32) {
+        let target = facing.index();
+        for other in ShieldFacing::ALL {
+            let i = other.index();
+            if i == target {
+                continue;
+            }
+            let moved = (Self::SHUNT_STEP / 3.0).min(self.allocation[i] - Self::MIN_ALLOCATION).max(0.0);
+            self.allocation[i] -= moved;
+            self.allocation[target] += moved;
+
+            let excess = self.current[i] - self.allocation[i] * max;
+            if excess > 0.0 {
+                self.current[i] -= excess;
+                self.current[target] = (self.current[target] + excess).min(self.allocation[target] * max);
+            }
+        }
+    }
+
+    /// Return to an even split, keeping the current total
+    pub fn balance(&mut self) {
+        let total = self.total();
+        self.allocation = [0.25; 4];
+        self.redistribute(total);
+    }
+}
+
 /// Energy for weapons and abilities
 #[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Energy {
@@ -26,6 +173,87 @@ pub struct Energy {
     pub recharge_rate: f32,
 }
 
+/// Ship system that can draw reactor power
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerSystem {
+    Weapons,
+    Shields,
+    Engines,
+}
+
+/// Reactor power distribution - pips split between weapons, shields and engines.
+/// Each system's multiplier scales its rates: energy recharge, shield recharge, and top speed/boost.
+#[derive(Component, Clone, Debug, Serialize, Deserialize)]
+pub struct PowerDistribution {
+    pub weapons: u8,
+    pub shields: u8,
+    pub engines: u8,
+}
+
+impl Default for PowerDistribution {
+    fn default() -> Self {
+        Self::balanced()
+    }
+}
+
+impl PowerDistribution {
+    pub const TOTAL_PIPS: u8 = 6;
+    pub const MAX_PIPS: u8 = 4;
+
+    pub fn balanced() -> Self {
+        Self { weapons: 2, shields: 2, engines: 2 }
+    }
+
+    /// Fixed allocation - clamped per system, must add up to TOTAL_PIPS
+    pub fn preset(weapons: u8, shields: u8, engines: u8) -> Self {
+        debug_assert_eq!(weapons + shields + engines, Self::TOTAL_PIPS);
+        Self {
+            weapons: weapons.min(Self::MAX_PIPS),
+            shields: shields.min(Self::MAX_PIPS),
+            engines: engines.min(Self::MAX_PIPS),
+        }
+    }
+
+    pub fn pips(&self, system: PowerSystem) -> u8 {
+        match system {
+            PowerSystem::Weapons => self.weapons,
+            PowerSystem::Shields => self.shields,
+            PowerSystem::Engines => self.engines,
+        }
+    }
+
+    fn pips_mut(&mut self, system: PowerSystem) -> &mut u8 {
+        match system {
+            PowerSystem::Weapons => &mut self.weapons,
+            PowerSystem::Shields => &mut self.shields,
+            PowerSystem::Engines => &mut self.engines,
+        }
+    }
+
+    /// 0 pips = 50%, 2 pips (balanced) = 100%, 4 pips = 150%
+    pub fn multiplier(&self, system: PowerSystem) -> f32 {
+        0.5 + self.pips(system) as f32 * 0.25
+    }
+
+    /// Move one pip into `system`, taken from whichever other system has the most.
+    /// Returns false if the system is already at max or nothing can be taken.
+    pub fn boost(&mut self, system: PowerSystem) -> bool {
+        if self.pips(system) >= Self::MAX_PIPS {
+            return false;
+        }
+        let donor = [PowerSystem::Weapons, PowerSystem::Shields, PowerSystem::Engines]
+            .into_iter()
+            .filter(|s| *s != system && self.pips(*s) > 0)
+            .max_by_key(|s| self.pips(*s));
+        let Some(donor) = donor else {
+            return false;
+        };
+        *self.pips_mut(donor) -= 1;
+        *self.pips_mut(system) += 1;
+        true
+    }
+}
+
 /// Weapon types
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum WeaponType {
@@ -52,6 +280,8 @@ pub struct Weapon {
     pub alt_fire_charge: f32, // For charged weapons
     pub shield_damage_multiplier: f32, // Multiplier for shield damage
     pub hull_damage_multiplier: f32, // Multiplier for hull damage
+    #[serde(default)]
+    pub piercing: bool, // Rounds pass through targets (railgun always pierces)
     
     // Weapon state tracking
     pub heat: f32,              // Current heat level (0.0 - max_heat)
@@ -62,6 +292,7 @@ pub struct Weapon {
     pub current_ammo: u32,      // Current ammo in magazine
     pub max_ammo: u32,          // Magazine capacity (0 = infinite)
     pub reserve_ammo: u32,      // Reserve ammo pool
+    pub max_reserve_ammo: u32,  // Reserve pool capacity (resupply cap)
     pub reload_time: f32,       // Time to reload in seconds
     pub reload_timer: f32,      // Current reload progress
     pub is_reloading: bool,     // Currently reloading flag
@@ -88,6 +319,14 @@ pub struct Projectile {
     pub homing_strength: f32, // 0.0 = no homing, higher = stronger homing
     pub homing_target: Option<Entity>,
     pub initial_direction: Vec3, // Direction the projectile was fired in (for rotation)
+    pub is_critical: bool, // Rolled a critical hit when fired (damage already includes the multiplier)
+}
+
+impl Projectile {
+    /// Homing or large area munitions can be intercepted by point defense
+    pub fn is_interceptable(&self) -> bool {
+        self.homing_strength > 0.0 || self.area_damage >= 5.0
+    }
 }
 
 /// Damage type for resistances
@@ -98,49 +337,267 @@ pub enum DamageType {
     Explosive,
 }
 
-/// Faction for friend/foe identification
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
+/// Faction for friend/foe identification - who is actually hostile to whom comes from `Reputation`
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Faction {
     Player,
-    Enemy,
-    Neutral,
+    Enemy,   // Pirates
+    Neutral, // Civilian traders and miners
+    Militia, // System security
+}
+
+impl Faction {
+    /// Every faction the player can have a standing with
+    pub const NPC: [Faction; 3] = [Faction::Enemy, Faction::Neutral, Faction::Militia];
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            Faction::Player => "PLAYER",
+            Faction::Enemy => "PIRATES",
+            Faction::Neutral => "CIVILIANS",
+            Faction::Militia => "MILITIA",
+        }
+    }
 }
 
 /// Auto-turret component - independent targeting and firing system
+/// Every hardpoint aims and fires on its own, drawing from the shared weapon bank
 #[derive(Component, Clone, Debug)]
 pub struct AutoTurret {
     pub enabled: bool,
-    pub current_target: Option<Entity>,
-    pub current_rotation: Quat, // Turret's independent rotation
+    pub hardpoints: Vec<TurretHardpoint>,
     pub max_lock_range: f32,
     pub max_fire_range: f32,
     pub turn_rate: f32, // Turret rotation speed (rad/s)
     pub fire_cone_angle: f32, // Angle within which to fire
-    pub weapons: Vec<Weapon>, // Turret's weapons
-    pub current_weapon: usize, // Current weapon index
-    pub firing_cooldown: f32, // Current cooldown timer
+    pub weapons: Vec<Weapon>, // Turret's weapons (shared by all hardpoints)
+    pub mode: TurretMode,
+    pub policy: TurretTargetPolicy,
+    pub point_defense_range: f32, // Max range to engage incoming projectiles
+}
+
+/// Independently aimed turret mount on a ship
+#[derive(Clone, Debug)]
+pub struct TurretHardpoint {
+    pub name: &'static str,
+    pub offset: Vec3,          // Mount position in ship-local space
+    pub mount_direction: Vec3, // Ship-local direction the firing arc is centered on
+    pub arc_limit: f32,        // Max angle (radians) the barrel can swing away from the mount direction
+    pub current_target: Option<Entity>,
+    pub current_rotation: Quat, // Hardpoint's independent world-space rotation
+    pub current_weapon: usize,  // Index into the turret's weapon bank
+    pub firing_cooldown: f32,
+    pub destroyed: bool, // Mount shot off the hull (carrier turrets)
+}
+
+impl TurretHardpoint {
+    pub fn new(name: &'static str, offset: Vec3, mount_direction: Vec3, arc_limit_degrees: f32) -> Self {
+        let mount_direction = mount_direction.normalize();
+        Self {
+            name,
+            offset,
+            mount_direction,
+            arc_limit: arc_limit_degrees.to_radians(),
+            current_target: None,
+            current_rotation: Quat::from_rotation_arc(Vec3::Z, mount_direction),
+            current_weapon: 0,
+            firing_cooldown: 0.0,
+            destroyed: false,
+        }
+    }
+
+    /// World-space position of the mount
+    pub fn world_position(&self, ship_transform: &Transform) -> Vec3 {
+        ship_transform.translation + ship_transform.rotation * self.offset
+    }
+
+    /// World-space center of the firing arc
+    pub fn world_mount_direction(&self, ship_transform: &Transform) -> Vec3 {
+        ship_transform.rotation * self.mount_direction
+    }
+
+    /// Whether a world-space point can be reached from this hardpoint without leaving its arc
+    pub fn in_arc(&self, ship_transform: &Transform, point: Vec3) -> bool {
+        let to_point = (point - self.world_position(ship_transform)).normalize_or_zero();
+        let mount = self.world_mount_direction(ship_transform);
+        mount.dot(to_point).clamp(-1.0, 1.0).acos() <= self.arc_limit
+    }
+
+    /// Pull a world-space aim direction back inside the firing arc
+    pub fn clamp_to_arc(&self, ship_transform: &Transform, direction: Vec3) -> Vec3 {
+        let mount = self.world_mount_direction(ship_transform);
+        let angle = mount.dot(direction).clamp(-1.0, 1.0).acos();
+        if angle <= self.arc_limit {
+            return direction;
+        }
+        let axis = mount.cross(direction);
+        if axis.length_squared() < 0.0001 {
+            return mount; // Pointing straight away from the mount - snap back to center
+        }
+        Quat::from_axis_angle(axis.normalize(), self.arc_limit) * mount
+    }
+}
+
+impl AutoTurret {
+    /// Capital ship turret battery - four slow, heavy mounts covering every side of the hull
+    pub fn carrier() -> Self {
+        Self {
+            enabled: true,
+            hardpoints: vec![
+                TurretHardpoint::new("Dorsal", Vec3::new(0.0, 1.0, 3.0), Vec3::Y, 110.0),
+                TurretHardpoint::new("Port", Vec3::new(-2.7, 0.5, 1.5), Vec3::NEG_X, 100.0),
+                TurretHardpoint::new("Starboard", Vec3::new(2.7, 0.5, 1.5), Vec3::X, 100.0),
+                TurretHardpoint::new("Ventral", Vec3::new(0.0, -1.0, 0.5), Vec3::NEG_Y, 110.0),
+            ],
+            max_lock_range: 150.0,
+            max_fire_range: 120.0,
+            turn_rate: 1.5,
+            fire_cone_angle: 0.15,
+            weapons: vec![Weapon::laser(), Weapon::autocannon(), Weapon::flak_cannon()],
+            mode: TurretMode::Offensive,
+            policy: TurretTargetPolicy::Nearest,
+            point_defense_range: 0.0,
+        }
+    }
+}
+
+/// Turret engagement mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TurretMode {
+    Offensive,    // Engage enemy ships
+    PointDefense, // Shoot down incoming missiles/area projectiles first, ships otherwise
+}
+
+/// How turret hardpoints choose between enemy ships
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TurretTargetPolicy {
+    Nearest,
+    LowestHealth,  // Finish off damaged ships (hull + shields remaining)
+    HighestThreat, // Bigger ships, and anything attacking the player, first
+    PlayerTarget,  // Engage whatever the player is locking, nearest otherwise
+}
+
+impl TurretTargetPolicy {
+    pub fn next(&self) -> Self {
+        match self {
+            TurretTargetPolicy::Nearest => TurretTargetPolicy::LowestHealth,
+            TurretTargetPolicy::LowestHealth => TurretTargetPolicy::HighestThreat,
+            TurretTargetPolicy::HighestThreat => TurretTargetPolicy::PlayerTarget,
+            TurretTargetPolicy::PlayerTarget => TurretTargetPolicy::Nearest,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TurretTargetPolicy::Nearest => "NEAREST",
+            TurretTargetPolicy::LowestHealth => "WEAKEST",
+            TurretTargetPolicy::HighestThreat => "THREAT",
+            TurretTargetPolicy::PlayerTarget => "ASSIST",
+        }
+    }
 }
 
 impl Default for AutoTurret {
     fn default() -> Self {
         Self {
             enabled: false,
-            current_target: None,
-            current_rotation: Quat::IDENTITY,
+            // Dorsal mount covers everything above the ship, ventral mount everything below
+            hardpoints: vec![
+                TurretHardpoint::new("Dorsal", Vec3::new(0.0, 2.0, 3.0), Vec3::Y, 100.0),
+                TurretHardpoint::new("Ventral", Vec3::new(0.0, -1.5, 0.5), Vec3::NEG_Y, 100.0),
+            ],
             max_lock_range: 250.0, // Increased from 150.0
             max_fire_range: 200.0, // Increased from 120.0
             turn_rate: 6.0, // Turrets turn faster than ships
             fire_cone_angle: 0.1, // ~5.7 degrees
             weapons: vec![Weapon::laser(), Weapon::autocannon(), Weapon::plasma()], // Multiple weapons
-            current_weapon: 0,
-            firing_cooldown: 0.0,
+            mode: TurretMode::Offensive,
+            policy: TurretTargetPolicy::Nearest,
+            point_defense_range: 120.0,
         }
     }
 }
 
-/// Marker for turret visual entity
+/// Hit points for projectiles that can be shot down (missiles, large area munitions)
 #[derive(Component)]
-pub struct TurretVisual;
+pub struct ProjectileHealth {
+    pub current: f32,
+}
+
+/// Missile lock-on state - a target must stay inside the lock cone for `lock_time` seconds
+#[derive(Component, Clone)]
+pub struct MissileLock {
+    pub candidate: Option<Entity>, // Target currently being locked
+    pub target: Option<Entity>,    // Fully locked target (missiles fired now will home on it)
+    pub progress: f32,             // Seconds the candidate has been held in the cone
+    pub lock_time: f32,            // Seconds required for a full lock
+    pub cone_angle: f32,           // Half-angle of the lock cone (radians)
+    pub range: f32,
+}
+
+impl MissileLock {
+    pub fn new(lock_time: f32) -> Self {
+        Self {
+            candidate: None,
+            target: None,
+            progress: 0.0,
+            lock_time,
+            cone_angle: 12.0_f32.to_radians(),
+            range: 250.0,
+        }
+    }
+    
+    pub fn is_locked(&self) -> bool {
+        self.target.is_some()
+    }
+    
+    /// Lock progress 0.0 - 1.0
+    pub fn progress_fraction(&self) -> f32 {
+        (self.progress / self.lock_time).clamp(0.0, 1.0)
+    }
+    
+    pub fn reset(&mut self) {
+        self.candidate = None;
+        self.target = None;
+        self.progress = 0.0;
+    }
+}
+
+/// Flare/chaff launcher with limited charges - spoofs incoming missiles and enemy locks
+#[derive(Component, Clone)]
+pub struct Countermeasures {
+    pub charges: u32,
+    pub max_charges: u32,
+    pub cooldown: f32,       // Seconds between deployments
+    pub cooldown_timer: f32,
+}
+
+impl Countermeasures {
+    pub fn new(charges: u32) -> Self {
+        Self {
+            charges,
+            max_charges: charges,
+            cooldown: 1.5,
+            cooldown_timer: 0.0,
+        }
+    }
+    
+    pub fn can_deploy(&self) -> bool {
+        self.charges > 0 && self.cooldown_timer <= 0.0
+    }
+}
+
+/// Flare decoy - missiles spoofed by countermeasures chase this instead of the ship
+#[derive(Component)]
+pub struct Decoy {
+    pub lifetime: f32,
+}
+
+/// Turret visual entity for one hardpoint of the player's AutoTurret
+#[derive(Component)]
+pub struct TurretVisual {
+    pub hardpoint: usize,
+}
 
 impl Weapon {
     /// Laser - Anti-Shield weapon (2.5x shield, 0.3x hull)
@@ -158,6 +615,7 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 2.5,  // 30 damage to shields
             hull_damage_multiplier: 0.1,     // 3.6 damage to hull
+            piercing: false,
             
             // Heat mechanics
             heat: 0.0,
@@ -169,6 +627,7 @@ impl Weapon {
             current_ammo: 0,
             max_ammo: 0,                     // 0 = infinite
             reserve_ammo: 0,
+            max_reserve_ammo: 0,
             reload_time: 0.0,
             reload_timer: 0.0,
             is_reloading: false,
@@ -190,6 +649,7 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 0.2,  // 2.8 damage to shields (50% less than before)
             hull_damage_multiplier: 2.0,     // 28 damage to hull
+            piercing: false,
             
             // No heat
             heat: 0.0,
@@ -201,6 +661,7 @@ impl Weapon {
             current_ammo: 60,                // Start with full mag
             max_ammo: 60,                    // 60 rounds per magazine
             reserve_ammo: 300,               // 5 additional magazines
+            max_reserve_ammo: 300,
             reload_time: 2.0,                // 2 seconds to reload
             reload_timer: 0.0,
             is_reloading: false,
@@ -222,6 +683,7 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 1.5,  // 60 damage to shields (60% of total)
             hull_damage_multiplier: 1.0,     // 40 damage to hull (40% of total)
+            piercing: false,
             
             // No heat
             heat: 0.0,
@@ -229,10 +691,11 @@ impl Weapon {
             heat_per_shot: 0.0,
             cooling_rate: 0.0,
             
-            // Limited ammo (no reload, just total count)
+            // Limited ammo (rack reloads instantly from resupplied reserves)
             current_ammo: 20,
             max_ammo: 20,
             reserve_ammo: 0,
+            max_reserve_ammo: 40,
             reload_time: 0.0,
             reload_timer: 0.0,
             is_reloading: false,
@@ -254,6 +717,7 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 1.2,
             hull_damage_multiplier: 1.3,
+            piercing: false,
             
             // No special mechanics
             heat: 0.0,
@@ -263,6 +727,7 @@ impl Weapon {
             current_ammo: 0,
             max_ammo: 0,
             reserve_ammo: 0,
+            max_reserve_ammo: 0,
             reload_time: 0.0,
             reload_timer: 0.0,
             is_reloading: false,
@@ -283,8 +748,9 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 0.6,  // 36 damage to shields
             hull_damage_multiplier: 2.5,     // 150 damage to hull
+            piercing: false,
             heat: 0.0, max_heat: 0.0, heat_per_shot: 0.0, cooling_rate: 0.0,
-            current_ammo: 0, max_ammo: 0, reserve_ammo: 0,
+            current_ammo: 0, max_ammo: 0, reserve_ammo: 0, max_reserve_ammo: 0,
             reload_time: 0.0, reload_timer: 0.0, is_reloading: false,
         }
     }
@@ -303,8 +769,9 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 5.0,  // 40 damage to shields
             hull_damage_multiplier: 0.1,     // 0.8 damage to hull
+            piercing: false,
             heat: 0.0, max_heat: 0.0, heat_per_shot: 0.0, cooling_rate: 0.0,
-            current_ammo: 0, max_ammo: 0, reserve_ammo: 0,
+            current_ammo: 0, max_ammo: 0, reserve_ammo: 0, max_reserve_ammo: 0,
             reload_time: 0.0, reload_timer: 0.0, is_reloading: false,
         }
     }
@@ -323,8 +790,9 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 1.0,
             hull_damage_multiplier: 1.5,
+            piercing: false,
             heat: 0.0, max_heat: 0.0, heat_per_shot: 0.0, cooling_rate: 0.0,
-            current_ammo: 0, max_ammo: 0, reserve_ammo: 0,
+            current_ammo: 0, max_ammo: 0, reserve_ammo: 0, max_reserve_ammo: 0,
             reload_time: 0.0, reload_timer: 0.0, is_reloading: false,
         }
     }
@@ -343,11 +811,42 @@ impl Weapon {
             alt_fire_charge: 0.0,
             shield_damage_multiplier: 2.0,
             hull_damage_multiplier: 0.8,
+            piercing: false,
             heat: 0.0, max_heat: 0.0, heat_per_shot: 0.0, cooling_rate: 0.0,
-            current_ammo: 0, max_ammo: 0, reserve_ammo: 0,
+            current_ammo: 0, max_ammo: 0, reserve_ammo: 0, max_reserve_ammo: 0,
             reload_time: 0.0, reload_timer: 0.0, is_reloading: false,
         }
     }
+
+    /// Whether this weapon consumes ammunition (0 max_ammo = infinite)
+    pub fn uses_ammo(&self) -> bool {
+        self.max_ammo > 0
+    }
+
+    /// Whether the reserve pool has room for more rounds
+    pub fn needs_resupply(&self) -> bool {
+        self.uses_ammo() && self.reserve_ammo < self.max_reserve_ammo
+    }
+
+    /// Add rounds to the reserve pool, capped at capacity. Returns rounds actually added.
+    pub fn resupply(&mut self, rounds: u32) -> u32 {
+        if !self.uses_ammo() {
+            return 0;
+        }
+        let space = self.max_reserve_ammo.saturating_sub(self.reserve_ammo);
+        let added = rounds.min(space);
+        self.reserve_ammo += added;
+        added
+    }
+
+    /// Total rounds left (magazine + reserve) as a fraction of full capacity
+    pub fn ammo_fraction(&self) -> f32 {
+        let capacity = self.max_ammo + self.max_reserve_ammo;
+        if capacity == 0 {
+            return 1.0;
+        }
+        (self.current_ammo + self.reserve_ammo) as f32 / capacity as f32
+    }
 }
 
 
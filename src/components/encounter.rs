@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use crate::components::ai::EnemyType;
+use crate::components::galaxy::SystemId;
+use crate::components::squadron::FormationType;
+
+/// One entry in the encounter table - a group the director can send at the player
+#[derive(Clone, Debug)]
+pub struct EncounterTemplate {
+    pub name: &'static str,
+    pub min_difficulty: u32,
+    pub max_difficulty: u32,
+    pub weight: f32,
+    pub leader: EnemyType,
+    pub escorts: &'static [EnemyType],
+    pub formation: FormationType,
+}
+
+impl EncounterTemplate {
+    /// Threat cost of the whole group
+    pub fn threat(&self) -> u32 {
+        self.leader.threat() + self.escorts.iter().map(|e| e.threat()).sum::<u32>()
+    }
+
+    pub fn available_at(&self, difficulty: u32) -> bool {
+        (self.min_difficulty..=self.max_difficulty).contains(&difficulty)
+    }
+}
+
+/// Every encounter the director knows about, gated by system difficulty
+pub const ENCOUNTER_TABLE: &[EncounterTemplate] = &[
+    EncounterTemplate { name: "Lone Raider", min_difficulty: 1, max_difficulty: 4, weight: 3.0, leader: EnemyType::Fighter, escorts: &[], formation: FormationType::Wedge },
+    EncounterTemplate { name: "Fighter Pair", min_difficulty: 1, max_difficulty: 7, weight: 3.0, leader: EnemyType::Fighter, escorts: &[EnemyType::Fighter], formation: FormationType::Wedge },
+    EncounterTemplate { name: "Fighter Wing", min_difficulty: 2, max_difficulty: 10, weight: 3.0, leader: EnemyType::Fighter, escorts: &[EnemyType::Fighter, EnemyType::Fighter], formation: FormationType::Wedge },
+    EncounterTemplate { name: "Gunboat", min_difficulty: 3, max_difficulty: 6, weight: 2.0, leader: EnemyType::Corvette, escorts: &[], formation: FormationType::Line },
+    EncounterTemplate { name: "Gunboat Patrol", min_difficulty: 3, max_difficulty: 10, weight: 2.5, leader: EnemyType::Corvette, escorts: &[EnemyType::Fighter, EnemyType::Fighter], formation: FormationType::Line },
+    EncounterTemplate { name: "Frigate Picket", min_difficulty: 5, max_difficulty: 10, weight: 1.5, leader: EnemyType::Frigate, escorts: &[], formation: FormationType::Line },
+    EncounterTemplate { name: "Strike Group", min_difficulty: 5, max_difficulty: 10, weight: 2.0, leader: EnemyType::Frigate, escorts: &[EnemyType::Corvette, EnemyType::Fighter, EnemyType::Fighter], formation: FormationType::Wedge },
+    EncounterTemplate { name: "Carrier Group", min_difficulty: 8, max_difficulty: 10, weight: 1.0, leader: EnemyType::CapitalShip, escorts: &[EnemyType::Corvette, EnemyType::Fighter, EnemyType::Corvette, EnemyType::Fighter], formation: FormationType::Escort },
+];
+
+/// Pacing phase - pressure builds, peaks, then the player gets a breather
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirectorPhase {
+    BuildUp,
+    Peak,
+    Relax,
+}
+
+impl DirectorPhase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DirectorPhase::BuildUp => "BUILD-UP",
+            DirectorPhase::Peak => "PEAK",
+            DirectorPhase::Relax => "RELAX",
+        }
+    }
+
+    /// Minimum seconds before the phase can end
+    pub fn duration(&self) -> f32 {
+        match self {
+            DirectorPhase::BuildUp => 40.0,
+            DirectorPhase::Peak => 30.0,
+            DirectorPhase::Relax => 20.0,
+        }
+    }
+
+    /// Seconds between wave attempts (relax sends nothing)
+    pub fn wave_interval(&self) -> Option<f32> {
+        match self {
+            DirectorPhase::BuildUp => Some(8.0),
+            DirectorPhase::Peak => Some(4.0),
+            DirectorPhase::Relax => None,
+        }
+    }
+}
+
+/// Encounter director - schedules waves against a threat budget and paces the action.
+/// Public so the debug overlay (F12) can show what it's thinking.
+#[derive(Resource, Clone, Debug)]
+pub struct EncounterDirector {
+    pub system_id: Option<SystemId>,
+    pub phase: DirectorPhase,
+    pub phase_timer: f32,     // Seconds spent in the current phase
+    pub wave_timer: f32,      // Seconds until the next wave attempt
+    pub intensity: f32,       // 0-1, how hard the player is being pressed right now
+    pub threat_budget: u32,   // Threat allowed on the field this frame
+    pub live_threat: u32,     // Threat currently on the field
+    pub last_encounter: Option<&'static str>,
+    pub waves_spawned: u32,
+}
+
+impl EncounterDirector {
+    /// Intensity added each time the player takes a hit
+    pub const INTENSITY_PER_HIT: f32 = 0.04;
+    /// Intensity shed per second
+    pub const INTENSITY_DECAY: f32 = 0.05;
+    /// Peak ends early once the player is this hard-pressed
+    pub const PEAK_INTENSITY: f32 = 0.9;
+    /// Relax lasts until intensity falls below this
+    pub const CALM_INTENSITY: f32 = 0.3;
+
+    /// Full threat budget for a system - harder systems field more at once
+    pub fn base_budget(difficulty: u32) -> u32 {
+        4 + difficulty * 2
+    }
+
+    /// Fraction of the full budget allowed in the current phase
+    pub fn budget_scale(&self) -> f32 {
+        match self.phase {
+            DirectorPhase::BuildUp => 0.4 + 0.5 * (self.phase_timer / DirectorPhase::BuildUp.duration()).min(1.0),
+            DirectorPhase::Peak => 1.0,
+            DirectorPhase::Relax => 0.25,
+        }
+    }
+
+    pub fn enter_phase(&mut self, phase: DirectorPhase) {
+        println!("[Director] {} -> {} (intensity {:.2}, threat {}/{})",
+            self.phase.name(), phase.name(), self.intensity, self.live_threat, self.threat_budget);
+        self.phase = phase;
+        self.phase_timer = 0.0;
+        self.wave_timer = phase.wave_interval().unwrap_or(0.0).min(3.0);
+    }
+}
+
+impl Default for EncounterDirector {
+    fn default() -> Self {
+        Self {
+            system_id: None,
+            phase: DirectorPhase::BuildUp,
+            phase_timer: 0.0,
+            wave_timer: 3.0, // First contact shortly after arriving
+            intensity: 0.0,
+            threat_budget: 0,
+            live_threat: 0,
+            last_encounter: None,
+            waves_spawned: 0,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::combat::Faction;
+
+/// Player standing with each NPC faction (-100 at war, 100 allied).
+/// Hostility between ships is derived from this rather than from faction equality.
+#[derive(Resource, Clone, Debug)]
+pub struct Reputation {
+    pub standings: HashMap<Faction, f32>,
+}
+
+impl Reputation {
+    /// Factions below this standing attack the player on sight
+    pub const HOSTILE_BELOW: f32 = -25.0;
+    /// Factions above this standing count as friends
+    pub const FRIENDLY_ABOVE: f32 = 25.0;
+    /// Standing lost per hit on a faction's ship
+    pub const ATTACK_PENALTY: f32 = 2.0;
+    /// Standing lost for destroying a faction's ship
+    pub const KILL_PENALTY: f32 = 20.0;
+    /// Standing gained with every faction at odds with a ship the player destroys
+    pub const HELP_BONUS: f32 = 3.0;
+
+    pub fn standing(&self, faction: Faction) -> f32 {
+        if faction == Faction::Player {
+            return 100.0;
+        }
+        self.standings.get(&faction).copied().unwrap_or(0.0)
+    }
+
+    /// Whether ships of faction `a` engage ships of faction `b`
+    pub fn is_hostile(&self, a: Faction, b: Faction) -> bool {
+        match (a, b) {
+            _ if a == b => false,
+            (Faction::Player, other) | (other, Faction::Player) => self.standing(other) < Self::HOSTILE_BELOW,
+            _ => Self::npc_hostile(a, b),
+        }
+    }
+
+    /// Standing between NPC factions - pirates prey on everyone, militia and civilians get along
+    fn npc_hostile(a: Faction, b: Faction) -> bool {
+        a != b && (a == Faction::Enemy || b == Faction::Enemy)
+    }
+
+    pub fn label(&self, faction: Faction) -> &'static str {
+        let standing = self.standing(faction);
+        if standing < Self::HOSTILE_BELOW {
+            "HOSTILE"
+        } else if standing > Self::FRIENDLY_ABOVE {
+            "FRIENDLY"
+        } else {
+            "NEUTRAL"
+        }
+    }
+
+    /// The player shot a ship of `faction` - its friends take offence too, at half the penalty
+    pub fn record_attack(&mut self, faction: Faction) {
+        self.adjust(faction, -Self::ATTACK_PENALTY);
+        for other in Faction::NPC {
+            if other != faction && !Self::npc_hostile(other, faction) {
+                self.adjust(other, -Self::ATTACK_PENALTY * 0.5);
+            }
+        }
+    }
+
+    /// The player destroyed a ship of `faction` - its enemies are grateful
+    pub fn record_kill(&mut self, faction: Faction) {
+        self.adjust(faction, -Self::KILL_PENALTY);
+        for other in Faction::NPC {
+            if Self::npc_hostile(other, faction) {
+                self.adjust(other, Self::HELP_BONUS);
+            }
+        }
+    }
+
+    fn adjust(&mut self, faction: Faction, delta: f32) {
+        if faction == Faction::Player {
+            return;
+        }
+        let before = self.label(faction);
+        let standing = self.standings.entry(faction).or_insert(0.0);
+        *standing = (*standing + delta).clamp(-100.0, 100.0);
+        let after = self.label(faction);
+        if before != after {
+            println!("[Reputation] {} now {} ({:.0})", faction.name(), after, self.standing(faction));
+        }
+    }
+}
+
+impl Default for Reputation {
+    fn default() -> Self {
+        Self {
+            standings: HashMap::from([
+                (Faction::Enemy, -100.0),
+                (Faction::Neutral, 10.0),
+                (Faction::Militia, 30.0),
+            ]),
+        }
+    }
+}
+
+/// What a civilian ship is doing in the system
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CivilianKind {
+    Trader, // Gate to gate
+    Miner,  // Gate to a planet, works it for a while, then leaves
+}
+
+/// Neutral traffic - flies to `destination` (a gate or planet) and keeps `standoff` from it
+#[derive(Component, Clone, Debug)]
+pub struct Civilian {
+    pub kind: CivilianKind,
+    pub destination: Entity,
+    pub standoff: f32,
+    pub mining_timer: f32,
+    pub leaving: bool, // Heading for the exit gate
+}
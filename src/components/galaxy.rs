@@ -15,6 +15,9 @@ pub struct StarSystem {
     pub resource_multipliers: ResourceMultipliers,
     pub planets: Vec<PlanetData>,
     pub connected_systems: Vec<SystemId>,
+    pub faction_presence: Vec<(crate::components::combat::Faction, f32)>, // How strongly each NPC faction shows up here (0-1)
+    pub boss: Option<crate::components::boss::BossDefinition>, // Flagship guarding the system (high difficulty only)
+    pub boss_defeated: bool,
 }
 
 /// Resource multipliers for a system
@@ -56,6 +59,12 @@ pub struct Planet {
     pub center: Vec3,
 }
 
+/// Solid volume AI ships steer around (planets, jump gates)
+#[derive(Component)]
+pub struct Obstacle {
+    pub radius: f32,
+}
+
 /// Component for system node visuals in galaxy map
 #[derive(Component)]
 pub struct SystemNode {
@@ -103,6 +112,12 @@ impl StarSystem {
         // Generate system name
         let name = Self::generate_name(id, &mut rng);
         
+        // Generate faction presence
+        let faction_presence = Self::generate_faction_presence(difficulty, &mut rng);
+        
+        // Generate boss for high-difficulty systems
+        let boss = crate::components::boss::BossDefinition::generate(difficulty, &mut rng);
+        
         Self {
             id,
             name,
@@ -112,9 +127,31 @@ impl StarSystem {
             resource_multipliers,
             planets,
             connected_systems: Vec::new(),
+            faction_presence,
+            boss,
+            boss_defeated: false,
         }
     }
     
+    /// Presence of an NPC faction in this system (0 = absent)
+    pub fn presence(&self, faction: crate::components::combat::Faction) -> f32 {
+        self.faction_presence.iter()
+            .find(|(f, _)| *f == faction)
+            .map_or(0.0, |(_, presence)| *presence)
+    }
+    
+    fn generate_faction_presence(difficulty: u32, rng: &mut impl rand::Rng) -> Vec<(crate::components::combat::Faction, f32)> {
+        use crate::components::combat::Faction;
+        
+        // Pirates grow bolder further out, militia patrol the core systems, traders thin out toward the frontier
+        let difficulty = difficulty as f32;
+        vec![
+            (Faction::Enemy, (0.4 + difficulty * 0.06 + rng.gen_range(0.0..0.1)).min(1.0)),
+            (Faction::Militia, (0.65 - difficulty * 0.07).max(0.0) + rng.gen_range(0.0..0.1)),
+            (Faction::Neutral, (0.8 - difficulty * 0.05).max(0.1) + rng.gen_range(0.0..0.1)),
+        ]
+    }
+    
     fn generate_name(id: SystemId, rng: &mut impl rand::Rng) -> String {
         let prefixes = ["Alpha", "Beta", "Gamma", "Delta", "Epsilon", "Zeta", "Eta", "Theta"];
         let suffixes = ["Centauri", "Draconis", "Orionis", "Lyrae", "Cygni", "Aquarii", "Phoenicis", "Scorpii"];
@@ -8,6 +8,14 @@ pub mod galaxy;
 pub mod travel;
 pub mod ship_classes;
 pub mod abilities;
+pub mod weapon_items;
+pub mod status_effects;
+pub mod squadron;
+pub mod carrier;
+pub mod wingman;
+pub mod faction;
+pub mod boss;
+pub mod encounter;
 
 pub use ship::*;
 pub use combat::*;
@@ -19,4 +27,12 @@ pub use galaxy::*;
 pub use travel::*;
 pub use ship_classes::*;
 pub use abilities::*;
+pub use weapon_items::*;
+pub use status_effects::*;
+pub use squadron::*;
+pub use carrier::*;
+pub use wingman::*;
+pub use faction::*;
+pub use boss::*;
+pub use encounter::*;
 
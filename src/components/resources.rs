@@ -8,6 +8,7 @@ pub enum ResourceType {
     EnergyCores,
     RareMinerals,
     TechComponents,
+    Ammunition,  // Ammo crates - fed straight into weapon reserves, never stored
 }
 
 /// Loot component for collectible resources
@@ -33,6 +34,8 @@ impl Inventory {
             ResourceType::EnergyCores => self.energy_cores += amount,
             ResourceType::RareMinerals => self.rare_minerals += amount,
             ResourceType::TechComponents => self.tech_components += amount,
+            // Ammunition is applied directly to weapon reserves on pickup
+            ResourceType::Ammunition => {}
         }
     }
 
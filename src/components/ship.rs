@@ -29,6 +29,8 @@ pub enum ShipPieceType {
     WeaponMount,
     ArmorPlating,
     ShieldEmitter,
+    Turret, // Carrier turret mount
+    Hangar, // Carrier fighter bay
 }
 
 /// Marker component for individual ship pieces
@@ -38,6 +40,121 @@ pub struct ShipPiece {
     pub parent_ship: Entity,
 }
 
+impl ShipPieceType {
+    /// Whether this piece is a functional subsystem that can be targeted and destroyed
+    pub fn is_subsystem(&self) -> bool {
+        matches!(self, Self::Engine | Self::WeaponMount | Self::ShieldEmitter | Self::Turret | Self::Hangar)
+    }
+    
+    pub fn subsystem_name(&self) -> &'static str {
+        match self {
+            Self::Engine => "ENGINES",
+            Self::WeaponMount => "WEAPONS",
+            Self::ShieldEmitter => "SHIELDS",
+            Self::Turret => "TURRETS",
+            Self::Hangar => "HANGAR",
+            _ => "HULL",
+        }
+    }
+}
+
+/// Health of a functional ship piece (engine, weapon mount, shield emitter, turret, hangar)
+#[derive(Component, Clone)]
+pub struct SubsystemHealth {
+    pub current: f32,
+}
+
+/// Online/total counts of a ship's subsystems, updated as pieces are destroyed
+#[derive(Component, Clone, Default)]
+pub struct SubsystemStatus {
+    pub engines_total: u32,
+    pub engines_online: u32,
+    pub weapon_mounts_total: u32,
+    pub weapon_mounts_online: u32,
+    pub shield_emitters_total: u32,
+    pub shield_emitters_online: u32,
+}
+
+impl SubsystemStatus {
+    pub fn register(&mut self, piece_type: ShipPieceType) {
+        match piece_type {
+            ShipPieceType::Engine => {
+                self.engines_total += 1;
+                self.engines_online += 1;
+            }
+            ShipPieceType::WeaponMount => {
+                self.weapon_mounts_total += 1;
+                self.weapon_mounts_online += 1;
+            }
+            ShipPieceType::ShieldEmitter => {
+                self.shield_emitters_total += 1;
+                self.shield_emitters_online += 1;
+            }
+            _ => {}
+        }
+    }
+    
+    pub fn mark_destroyed(&mut self, piece_type: ShipPieceType) {
+        match piece_type {
+            ShipPieceType::Engine => self.engines_online = self.engines_online.saturating_sub(1),
+            ShipPieceType::WeaponMount => self.weapon_mounts_online = self.weapon_mounts_online.saturating_sub(1),
+            ShipPieceType::ShieldEmitter => self.shield_emitters_online = self.shield_emitters_online.saturating_sub(1),
+            _ => {}
+        }
+    }
+    
+    /// Speed multiplier from surviving engines (ships limp along at 30% with none left)
+    pub fn engine_factor(&self) -> f32 {
+        if self.engines_total == 0 {
+            return 1.0;
+        }
+        0.3 + 0.7 * self.engines_online as f32 / self.engines_total as f32
+    }
+    
+    /// Weapon slots go offline from the back of the mount as weapon mounts are destroyed
+    pub fn weapon_slot_online(&self, slot: usize, slot_count: usize) -> bool {
+        if self.weapon_mounts_total == 0 {
+            return true;
+        }
+        let online_slots = (slot_count as f32 * self.weapon_mounts_online as f32
+            / self.weapon_mounts_total as f32).ceil() as usize;
+        slot < online_slots
+    }
+    
+    /// Shields only recharge while at least one emitter survives
+    pub fn shields_online(&self) -> bool {
+        self.shield_emitters_total == 0 || self.shield_emitters_online > 0
+    }
+}
+
+/// Player's currently targeted subsystem type (None = no focus, hits land wherever they land)
+#[derive(Resource, Default)]
+pub struct SubsystemTargeting {
+    pub target: Option<ShipPieceType>,
+}
+
+impl SubsystemTargeting {
+    /// Cycle: none -> engines -> weapons -> shields -> turrets -> hangar -> none
+    pub fn cycle(&mut self) {
+        self.target = match self.target {
+            None => Some(ShipPieceType::Engine),
+            Some(ShipPieceType::Engine) => Some(ShipPieceType::WeaponMount),
+            Some(ShipPieceType::WeaponMount) => Some(ShipPieceType::ShieldEmitter),
+            Some(ShipPieceType::ShieldEmitter) => Some(ShipPieceType::Turret),
+            Some(ShipPieceType::Turret) => Some(ShipPieceType::Hangar),
+            _ => None,
+        };
+    }
+}
+
+/// Wreckage of a destroyed ship piece drifting away from its ship
+#[derive(Component)]
+pub struct DetachedPiece {
+    pub velocity: Vec3,
+    pub angular_velocity: Vec3,
+    pub lifetime: f32,
+}
+
 /// Tracks visual pieces that represent upgrades
 #[derive(Component, Default, Clone)]
 pub struct UpgradeVisuals {
@@ -92,6 +209,26 @@ pub struct Velocity(pub Vec3);
 #[derive(Component, Default)]
 pub struct AngularVelocity(pub Vec3);
 
+/// Simulation poses either side of the current frame, for smoothing fixed-step motion.
+/// `rendered` is what was last drawn - if the transform no longer matches it, something outside
+/// the simulation moved the entity and it snaps instead of sliding.
+#[derive(Component, Clone, Copy)]
+pub struct TransformInterpolation {
+    pub previous: Transform,
+    pub current: Transform,
+    pub rendered: Transform,
+}
+
+impl TransformInterpolation {
+    pub fn new(transform: Transform) -> Self {
+        Self {
+            previous: transform,
+            current: transform,
+            rendered: transform,
+        }
+    }
+}
+
 impl Ship {
     pub fn fighter() -> Self {
         Self {
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use crate::components::ai::EnemyType;
+
+/// Formation a squadron flies in while closing on its target
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormationType {
+    Wedge,  // V behind the leader
+    Line,   // Abreast of the leader
+    Escort, // Ring around a capital ship
+}
+
+impl FormationType {
+    /// Leader-local slot offset for wingman `index` of `count` (-Z is the leader's forward)
+    pub fn slot_offset(&self, index: usize, count: usize) -> Vec3 {
+        let rank = (index / 2 + 1) as f32;
+        let side = if index % 2 == 0 { -1.0 } else { 1.0 };
+        match self {
+            FormationType::Wedge => Vec3::new(side * rank * 8.0, 0.0, rank * 8.0),
+            FormationType::Line => Vec3::new(side * rank * 10.0, 0.0, 0.0),
+            FormationType::Escort => {
+                let angle = index as f32 / count.max(1) as f32 * std::f32::consts::TAU;
+                Vec3::new(angle.cos() * 25.0, (index % 2) as f32 * 6.0 - 3.0, angle.sin() * 25.0)
+            }
+        }
+    }
+
+    /// Formation and wingmen that fly with a leader of the given type
+    pub fn squadron_for(leader: EnemyType) -> (FormationType, Vec<EnemyType>) {
+        match leader {
+            EnemyType::Fighter => (FormationType::Wedge, vec![EnemyType::Fighter; 2]),
+            EnemyType::Corvette => (FormationType::Line, vec![EnemyType::Fighter; 2]),
+            EnemyType::Frigate => (FormationType::Wedge, vec![EnemyType::Corvette, EnemyType::Fighter, EnemyType::Fighter]),
+            EnemyType::CapitalShip => (FormationType::Escort, vec![EnemyType::Corvette, EnemyType::Fighter, EnemyType::Corvette, EnemyType::Fighter]),
+        }
+    }
+}
+
+/// Squadron leader - owns the formation and the squad's morale
+#[derive(Component, Clone, Debug)]
+pub struct Squadron {
+    pub formation: FormationType,
+    pub members: Vec<Entity>, // Surviving wingmen
+    pub morale: f32,          // 0 (broken) to 1 (steady)
+}
+
+impl Squadron {
+    /// Morale lost for each wingman shot down
+    pub const WINGMAN_LOSS_MORALE: f32 = 0.15;
+    /// Extra morale hit a wingman takes when the leader goes down
+    pub const LEADER_LOSS_MORALE: f32 = 0.5;
+
+    pub fn new(formation: FormationType, members: Vec<Entity>) -> Self {
+        Self {
+            formation,
+            members,
+            morale: 1.0,
+        }
+    }
+}
+
+/// Wingman flying in a leader's squadron
+#[derive(Component, Clone, Debug)]
+pub struct SquadronMember {
+    pub leader: Entity,
+    pub slot: Vec3,  // Leader-local formation offset
+    pub morale: f32, // Mirrors the squadron's morale while the leader lives
+}
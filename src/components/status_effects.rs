@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+use crate::components::combat::WeaponType;
+
+/// Kinds of status effect any ship can suffer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatusEffectKind {
+    Burning,  // Hull damage over time, stacks
+    Emp,      // Shields stop recharging and energy drains
+    Slowed,   // Top speed reduced (magnitude = speed multiplier)
+    Disabled, // Weapons and engines offline
+}
+
+/// How a re-application interacts with an effect that's already active
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StackRule {
+    Intensity(u32), // Adds a stack (up to the cap) and refreshes duration
+    Refresh,        // Keeps the longer duration and the stronger magnitude
+}
+
+impl StatusEffectKind {
+    pub const ALL: [StatusEffectKind; 4] = [
+        StatusEffectKind::Burning,
+        StatusEffectKind::Emp,
+        StatusEffectKind::Slowed,
+        StatusEffectKind::Disabled,
+    ];
+
+    pub fn index(&self) -> usize {
+        match self {
+            StatusEffectKind::Burning => 0,
+            StatusEffectKind::Emp => 1,
+            StatusEffectKind::Slowed => 2,
+            StatusEffectKind::Disabled => 3,
+        }
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            StatusEffectKind::Burning => "BURN",
+            StatusEffectKind::Emp => "EMP",
+            StatusEffectKind::Slowed => "SLOW",
+            StatusEffectKind::Disabled => "DISABLED",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            StatusEffectKind::Burning => Color::srgb(1.0, 0.45, 0.0),
+            StatusEffectKind::Emp => Color::srgb(0.3, 0.6, 1.0),
+            StatusEffectKind::Slowed => Color::srgb(0.6, 0.9, 0.9),
+            StatusEffectKind::Disabled => Color::srgb(1.0, 0.9, 0.1),
+        }
+    }
+
+    pub fn stack_rule(&self) -> StackRule {
+        match self {
+            StatusEffectKind::Burning => StackRule::Intensity(5),
+            StatusEffectKind::Emp | StatusEffectKind::Slowed | StatusEffectKind::Disabled => StackRule::Refresh,
+        }
+    }
+
+    /// Status effect a weapon's direct hit applies: (kind, duration, magnitude).
+    /// `shields_down` - the hit landed on bare hull.
+    pub fn from_weapon(weapon_type: WeaponType, shields_down: bool) -> Option<(StatusEffectKind, f32, f32)> {
+        match weapon_type {
+            WeaponType::Plasma => Some((StatusEffectKind::Burning, 4.0, 3.0)),   // 3 hull dmg/s per stack
+            WeaponType::IonCannon if shields_down => Some((StatusEffectKind::Disabled, 1.5, 1.0)), // Shorts out weapons and engines
+            WeaponType::IonCannon => Some((StatusEffectKind::Emp, 2.5, 10.0)),  // 10 energy/s drain
+            WeaponType::FlakCannon => Some((StatusEffectKind::Slowed, 2.0, 0.6)), // 60% top speed
+            _ => None,
+        }
+    }
+}
+
+/// One active status effect
+#[derive(Clone, Debug)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    pub remaining: f32,
+    pub magnitude: f32,
+    pub stacks: u32,
+    pub stack_cooldown: f32, // New stacks can only be added once this reaches zero
+}
+
+/// Active status effects on a ship (player or AI)
+#[derive(Component, Clone, Debug, Default)]
+pub struct StatusEffects {
+    pub effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Minimum time between stacks, so continuous sources (hazards, beams) don't max out instantly
+    pub const STACK_INTERVAL: f32 = 0.5;
+
+    /// Apply (or re-apply) an effect following its stacking rule
+    pub fn apply(&mut self, kind: StatusEffectKind, duration: f32, magnitude: f32) {
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            match kind.stack_rule() {
+                StackRule::Intensity(max_stacks) => {
+                    if effect.stack_cooldown <= 0.0 && effect.stacks < max_stacks {
+                        effect.stacks += 1;
+                        effect.stack_cooldown = Self::STACK_INTERVAL;
+                    }
+                    effect.remaining = effect.remaining.max(duration);
+                    effect.magnitude = effect.magnitude.max(magnitude);
+                }
+                StackRule::Refresh => {
+                    effect.remaining = effect.remaining.max(duration);
+                    // Slowed is a speed multiplier - lower is stronger
+                    effect.magnitude = if kind == StatusEffectKind::Slowed {
+                        effect.magnitude.min(magnitude)
+                    } else {
+                        effect.magnitude.max(magnitude)
+                    };
+                }
+            }
+            return;
+        }
+
+        self.effects.push(StatusEffect {
+            kind,
+            remaining: duration,
+            magnitude,
+            stacks: 1,
+            stack_cooldown: Self::STACK_INTERVAL,
+        });
+    }
+
+    pub fn get(&self, kind: StatusEffectKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|e| e.kind == kind)
+    }
+
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    /// Count down durations, drop expired effects and return damage-over-time dealt this tick
+    pub fn tick(&mut self, dt: f32) -> f32 {
+        let mut damage = 0.0;
+        for effect in self.effects.iter_mut() {
+            let active = effect.remaining.min(dt);
+            effect.remaining -= dt;
+            effect.stack_cooldown = (effect.stack_cooldown - dt).max(0.0);
+            if effect.kind == StatusEffectKind::Burning {
+                damage += effect.magnitude * effect.stacks as f32 * active;
+            }
+        }
+        self.effects.retain(|e| e.remaining > 0.0);
+        damage
+    }
+
+    /// Top speed multiplier from slows and disables
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.has(StatusEffectKind::Disabled) {
+            return 0.1; // Drifting on thrusters only
+        }
+        self.get(StatusEffectKind::Slowed).map_or(1.0, |e| e.magnitude)
+    }
+
+    pub fn weapons_disabled(&self) -> bool {
+        self.has(StatusEffectKind::Disabled)
+    }
+
+    /// Energy drained per second
+    pub fn energy_drain(&self) -> f32 {
+        self.get(StatusEffectKind::Emp).map_or(0.0, |e| e.magnitude)
+    }
+
+    pub fn shields_suppressed(&self) -> bool {
+        self.has(StatusEffectKind::Emp)
+    }
+}
+
+/// Area that applies a status effect to any ship inside it
+#[derive(Component, Clone, Debug)]
+pub struct HazardZone {
+    pub radius: f32,
+    pub kind: StatusEffectKind,
+    pub duration: f32,
+    pub magnitude: f32,
+}
@@ -49,3 +49,25 @@ pub struct JumpGateGlow {
     pub pulse_offset: f32,
 }
 
+
+/// Ship dropping out of warp - arrives fast and sheds speed before its AI takes the stick
+#[derive(Component)]
+pub struct WarpIn {
+    pub elapsed: f32,
+    pub duration: f32,
+    pub entry_speed: f32,
+}
+
+impl WarpIn {
+    pub fn new() -> Self {
+        Self {
+            elapsed: 0.0,
+            duration: 0.8,
+            entry_speed: 140.0,
+        }
+    }
+    
+    pub fn progress(&self) -> f32 {
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+}
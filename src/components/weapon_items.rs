@@ -0,0 +1,388 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::components::ai::EnemyType;
+use crate::components::combat::{Weapon, WeaponMount, WeaponType};
+
+/// Maximum number of unequipped weapon items the player can carry
+pub const MAX_STASH_SIZE: usize = 12;
+
+/// Rarity tier of a dropped weapon item
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ItemRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl ItemRarity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Uncommon => "Uncommon",
+            Self::Rare => "Rare",
+            Self::Epic => "Epic",
+            Self::Legendary => "Legendary",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Common => Color::srgb(0.8, 0.8, 0.8),
+            Self::Uncommon => Color::srgb(0.3, 1.0, 0.3),
+            Self::Rare => Color::srgb(0.2, 0.6, 1.0),
+            Self::Epic => Color::srgb(0.8, 0.3, 1.0),
+            Self::Legendary => Color::srgb(1.0, 0.6, 0.1),
+        }
+    }
+
+    /// Number of affixes rolled for this tier
+    pub fn affix_count(&self) -> usize {
+        match self {
+            Self::Common => 0,
+            Self::Uncommon => 1,
+            Self::Rare => 2,
+            Self::Epic => 3,
+            Self::Legendary => 4,
+        }
+    }
+
+    /// Flat base damage bonus for this tier
+    pub fn quality_multiplier(&self) -> f32 {
+        match self {
+            Self::Common => 1.0,
+            Self::Uncommon => 1.04,
+            Self::Rare => 1.08,
+            Self::Epic => 1.12,
+            Self::Legendary => 1.2,
+        }
+    }
+
+    /// Scrap metal recovered when salvaging an item of this tier
+    pub fn salvage_value(&self) -> u32 {
+        match self {
+            Self::Common => 5,
+            Self::Uncommon => 10,
+            Self::Rare => 20,
+            Self::Epic => 40,
+            Self::Legendary => 80,
+        }
+    }
+}
+
+/// Rolled stat modifier on a weapon item (values are fractions, 0.15 = 15%)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WeaponAffix {
+    Damage(f32),
+    ShieldDamage(f32),
+    HullDamage(f32),
+    FireRate(f32),
+    HeatReduction(f32),
+    ProjectileSpeed(f32),
+    ExtendedMagazine(f32),
+    EnergyEfficiency(f32),
+    Piercing,
+}
+
+impl WeaponAffix {
+    pub fn description(&self) -> String {
+        match self {
+            Self::Damage(v) => format!("+{:.0}% damage", v * 100.0),
+            Self::ShieldDamage(v) => format!("+{:.0}% shield damage", v * 100.0),
+            Self::HullDamage(v) => format!("+{:.0}% hull damage", v * 100.0),
+            Self::FireRate(v) => format!("+{:.0}% fire rate", v * 100.0),
+            Self::HeatReduction(v) => format!("-{:.0}% heat per shot", v * 100.0),
+            Self::ProjectileSpeed(v) => format!("+{:.0}% projectile speed", v * 100.0),
+            Self::ExtendedMagazine(v) => format!("+{:.0}% magazine size", v * 100.0),
+            Self::EnergyEfficiency(v) => format!("-{:.0}% energy cost", v * 100.0),
+            Self::Piercing => "piercing".to_string(),
+        }
+    }
+
+    /// Whether this affix does anything on the given base weapon
+    fn applies_to(&self, weapon: &Weapon) -> bool {
+        match self {
+            Self::HeatReduction(_) => weapon.max_heat > 0.0,
+            Self::ExtendedMagazine(_) => weapon.uses_ammo(),
+            Self::EnergyEfficiency(_) => weapon.energy_cost > 0.0,
+            Self::Piercing => weapon.weapon_type != WeaponType::Railgun,
+            _ => true,
+        }
+    }
+
+    /// Same affix kind regardless of rolled value
+    fn same_kind(&self, other: &WeaponAffix) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Roll a random affix, magnitude scaled by item level (star system difficulty)
+    fn roll(item_level: u32, rng: &mut impl Rng) -> Self {
+        let magnitude = 0.05 + rng.gen::<f32>() * 0.1 + item_level as f32 * 0.01;
+        match rng.gen_range(0..9) {
+            0 => Self::Damage(magnitude),
+            1 => Self::ShieldDamage(magnitude * 1.5),
+            2 => Self::HullDamage(magnitude * 1.5),
+            3 => Self::FireRate(magnitude),
+            4 => Self::HeatReduction((magnitude * 1.5).min(0.6)),
+            5 => Self::ProjectileSpeed(magnitude * 1.5),
+            6 => Self::ExtendedMagazine(magnitude * 3.0),
+            7 => Self::EnergyEfficiency((magnitude * 1.5).min(0.6)),
+            _ => Self::Piercing,
+        }
+    }
+}
+
+/// Serializable weapon item with rolled stats
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WeaponItem {
+    pub name: String,
+    pub weapon_type: WeaponType,
+    pub rarity: ItemRarity,
+    pub item_level: u32,
+    pub affixes: Vec<WeaponAffix>,
+    /// Magazine and reserve rounds left when it was unequipped (None = fresh, fully loaded)
+    #[serde(default)]
+    pub ammo: Option<(u32, u32)>,
+}
+
+impl WeaponItem {
+    /// Plain item matching a stock weapon (used when unequipping starter weapons)
+    pub fn standard(weapon_type: WeaponType) -> Self {
+        Self {
+            name: format!("Standard {}", weapon_type_name(weapon_type)),
+            weapon_type,
+            rarity: ItemRarity::Common,
+            item_level: 1,
+            affixes: Vec::new(),
+            ammo: None,
+        }
+    }
+
+    /// Chance that a destroyed enemy drops a weapon item
+    pub fn drop_chance(enemy_type: EnemyType, difficulty: u32) -> f32 {
+        let base = match enemy_type {
+            EnemyType::Fighter => 0.05,
+            EnemyType::Corvette => 0.1,
+            EnemyType::Frigate => 0.2,
+            EnemyType::CapitalShip => 0.4,
+        };
+        (base + difficulty as f32 * 0.01).min(1.0)
+    }
+
+    /// Roll a random weapon item - bigger ships and harder systems roll better rarities
+    pub fn roll(enemy_type: EnemyType, difficulty: u32, rng: &mut impl Rng) -> Self {
+        let enemy_bonus = match enemy_type {
+            EnemyType::Fighter => 0.0,
+            EnemyType::Corvette => 0.05,
+            EnemyType::Frigate => 0.1,
+            EnemyType::CapitalShip => 0.2,
+        };
+        let quality = rng.gen::<f32>() + enemy_bonus + difficulty as f32 * 0.03;
+        Self::roll_with_quality(quality, difficulty, rng)
+    }
+
+    /// Boss reward - always Rare or better
+    pub fn roll_boss_reward(difficulty: u32, rng: &mut impl Rng) -> Self {
+        let quality = 0.8 + rng.gen::<f32>() * 0.2 + difficulty as f32 * 0.02;
+        Self::roll_with_quality(quality, difficulty, rng)
+    }
+
+    /// Roll type and affixes for a rarity picked by `quality` (0.55+ Uncommon, 0.8+ Rare, 0.93+ Epic, 1.05+ Legendary)
+    fn roll_with_quality(quality: f32, difficulty: u32, rng: &mut impl Rng) -> Self {
+        let rarity = if quality < 0.55 {
+            ItemRarity::Common
+        } else if quality < 0.8 {
+            ItemRarity::Uncommon
+        } else if quality < 0.93 {
+            ItemRarity::Rare
+        } else if quality < 1.05 {
+            ItemRarity::Epic
+        } else {
+            ItemRarity::Legendary
+        };
+
+        let weapon_type = match rng.gen_range(0..8) {
+            0 => WeaponType::Laser,
+            1 => WeaponType::Plasma,
+            2 => WeaponType::Missile,
+            3 => WeaponType::Railgun,
+            4 => WeaponType::Autocannon,
+            5 => WeaponType::IonCannon,
+            6 => WeaponType::FlakCannon,
+            _ => WeaponType::BeamLaser,
+        };
+
+        // Roll unique affixes that make sense for this weapon
+        let base = base_weapon(weapon_type);
+        let mut affixes: Vec<WeaponAffix> = Vec::new();
+        let mut attempts = 0;
+        while affixes.len() < rarity.affix_count() && attempts < 50 {
+            attempts += 1;
+            let affix = WeaponAffix::roll(difficulty, rng);
+            if affix.applies_to(&base) && !affixes.iter().any(|a| a.same_kind(&affix)) {
+                affixes.push(affix);
+            }
+        }
+
+        Self {
+            name: format!("{} {} Mk.{}", rarity.name(), weapon_type_name(weapon_type), difficulty),
+            weapon_type,
+            rarity,
+            item_level: difficulty,
+            affixes,
+            ammo: None,
+        }
+    }
+
+    /// Build the equippable weapon with all affixes applied
+    pub fn to_weapon(&self) -> Weapon {
+        let mut weapon = base_weapon(self.weapon_type);
+        weapon.damage *= self.rarity.quality_multiplier();
+
+        for affix in &self.affixes {
+            match *affix {
+                WeaponAffix::Damage(v) => weapon.damage *= 1.0 + v,
+                WeaponAffix::ShieldDamage(v) => weapon.shield_damage_multiplier *= 1.0 + v,
+                WeaponAffix::HullDamage(v) => weapon.hull_damage_multiplier *= 1.0 + v,
+                WeaponAffix::FireRate(v) => weapon.fire_rate *= 1.0 + v,
+                WeaponAffix::HeatReduction(v) => weapon.heat_per_shot *= 1.0 - v,
+                WeaponAffix::ProjectileSpeed(v) => weapon.projectile_speed *= 1.0 + v,
+                WeaponAffix::ExtendedMagazine(v) => {
+                    weapon.max_ammo = (weapon.max_ammo as f32 * (1.0 + v)).round() as u32;
+                    weapon.current_ammo = weapon.max_ammo;
+                    weapon.max_reserve_ammo = (weapon.max_reserve_ammo as f32 * (1.0 + v)).round() as u32;
+                }
+                WeaponAffix::EnergyEfficiency(v) => weapon.energy_cost *= 1.0 - v,
+                WeaponAffix::Piercing => weapon.piercing = true,
+            }
+        }
+
+        // A stashed weapon comes back with the rounds it went in with, not a free refill
+        if let Some((magazine, reserve)) = self.ammo {
+            weapon.current_ammo = magazine.min(weapon.max_ammo);
+            weapon.reserve_ammo = reserve.min(weapon.max_reserve_ammo);
+        }
+
+        weapon
+    }
+}
+
+/// Stock weapon for a weapon type
+pub fn base_weapon(weapon_type: WeaponType) -> Weapon {
+    match weapon_type {
+        WeaponType::Laser => Weapon::laser(),
+        WeaponType::Plasma => Weapon::plasma(),
+        WeaponType::Missile => Weapon::missile(),
+        WeaponType::Railgun => Weapon::railgun(),
+        WeaponType::Autocannon => Weapon::autocannon(),
+        WeaponType::IonCannon => Weapon::ion_cannon(),
+        WeaponType::FlakCannon => Weapon::flak_cannon(),
+        WeaponType::BeamLaser => Weapon::beam_laser(),
+    }
+}
+
+/// Display name for a weapon type
+pub fn weapon_type_name(weapon_type: WeaponType) -> &'static str {
+    match weapon_type {
+        WeaponType::Laser => "Laser",
+        WeaponType::Plasma => "Plasma",
+        WeaponType::Missile => "Missile",
+        WeaponType::Railgun => "Railgun",
+        WeaponType::Autocannon => "Autocannon",
+        WeaponType::IonCannon => "Ion Cannon",
+        WeaponType::FlakCannon => "Flak Cannon",
+        WeaponType::BeamLaser => "Beam Laser",
+    }
+}
+
+/// Weapon item floating in space, waiting to be picked up
+#[derive(Component)]
+pub struct WeaponDrop {
+    pub item: WeaponItem,
+}
+
+/// Player's collected weapon items and what is equipped in each mount slot
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct WeaponStash {
+    pub items: Vec<WeaponItem>,
+    pub selected: usize,
+    /// Item equipped per WeaponMount slot (None = stock starter weapon)
+    pub equipped: Vec<Option<WeaponItem>>,
+}
+
+impl WeaponStash {
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= MAX_STASH_SIZE
+    }
+
+    pub fn selected_item(&self) -> Option<&WeaponItem> {
+        self.items.get(self.selected)
+    }
+
+    /// Move the selection forward/backward, wrapping around
+    pub fn cycle(&mut self, forward: bool) {
+        if self.items.is_empty() {
+            self.selected = 0;
+            return;
+        }
+        let len = self.items.len();
+        self.selected = if forward {
+            (self.selected + 1) % len
+        } else {
+            (self.selected + len - 1) % len
+        };
+    }
+
+    /// Equip the selected item into the mount's current slot.
+    /// The replaced weapon goes back into the stash. Returns the equipped item.
+    pub fn equip_selected(&mut self, mount: &mut WeaponMount) -> Option<WeaponItem> {
+        if self.selected >= self.items.len() {
+            return None;
+        }
+        let slot = mount.current_weapon;
+        let current = mount.weapons.get(slot)?;
+        let (current_type, current_ammo) = (current.weapon_type, (current.current_ammo, current.reserve_ammo));
+
+        if self.equipped.len() < mount.weapons.len() {
+            self.equipped.resize(mount.weapons.len(), None);
+        }
+
+        let item = self.items.remove(self.selected);
+        let mut replaced = self.equipped[slot]
+            .take()
+            .unwrap_or_else(|| WeaponItem::standard(current_type));
+        replaced.ammo = Some(current_ammo);
+
+        mount.weapons[slot] = item.to_weapon();
+        self.equipped[slot] = Some(item.clone());
+        self.items.insert(self.selected, replaced);
+
+        Some(item)
+    }
+
+    /// Remove the selected item (for salvage)
+    pub fn take_selected(&mut self) -> Option<WeaponItem> {
+        if self.selected >= self.items.len() {
+            return None;
+        }
+        let item = self.items.remove(self.selected);
+        if self.selected >= self.items.len() && self.selected > 0 {
+            self.selected -= 1;
+        }
+        Some(item)
+    }
+
+    /// Rebuild mount weapons from equipped items, falling back to the given stock loadout
+    pub fn build_loadout(&self, stock: Vec<Weapon>) -> Vec<Weapon> {
+        stock
+            .into_iter()
+            .enumerate()
+            .map(|(slot, weapon)| match self.equipped.get(slot) {
+                Some(Some(item)) => item.to_weapon(),
+                _ => weapon,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+/// Standing order for the player's wing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WingmanOrder {
+    AttackMyTarget, // Engage whatever the player is aiming at
+    DefendMe,       // Engage anything closing on the player
+    FormUp,         // Break off and fly formation
+    HoldPosition,   // Stay put and only engage what comes close
+}
+
+impl WingmanOrder {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WingmanOrder::AttackMyTarget => "ATTACK MY TARGET",
+            WingmanOrder::DefendMe => "DEFEND ME",
+            WingmanOrder::FormUp => "FORM UP",
+            WingmanOrder::HoldPosition => "HOLD POSITION",
+        }
+    }
+}
+
+/// Friendly AI pilot flying with the player
+#[derive(Component, Clone, Debug)]
+pub struct Wingman {
+    pub callsign: &'static str,
+    pub slot: Vec3,       // Player-local formation offset
+    pub hold_point: Vec3, // Where to stay while holding position
+}
+
+/// The player's wing - current order and hiring terms
+#[derive(Resource, Clone, Debug)]
+pub struct WingCommand {
+    pub order: WingmanOrder,
+    pub max_wingmen: usize,
+    pub hire_scrap_cost: u32,
+    pub hire_tech_cost: u32,
+}
+
+impl WingCommand {
+    /// Callsigns handed out to hired pilots, in order
+    pub const CALLSIGNS: [&'static str; 3] = ["RAVEN", "HALO", "KESTREL"];
+    /// Range (from the player) inside which defending wingmen engage
+    pub const DEFEND_RANGE: f32 = 120.0;
+    /// Range (from the hold point) inside which holding wingmen engage
+    pub const HOLD_RANGE: f32 = 80.0;
+}
+
+impl Default for WingCommand {
+    fn default() -> Self {
+        Self {
+            order: WingmanOrder::DefendMe,
+            max_wingmen: Self::CALLSIGNS.len(),
+            hire_scrap_cost: 80,
+            hire_tech_cost: 3,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use bevy::core_pipeline::bloom::BloomSettings;
+use bevy::input::InputSystem;
+use bevy::transform::TransformSystem;
 
 mod components;
 mod resources;
@@ -14,65 +16,52 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .init_state::<GameState>()
-        .add_event::<systems::progression::EnemyKillEvent>()
-        .add_event::<systems::combat_feedback::HitEvent>()
-        .add_event::<systems::combat_feedback::KillEvent>()
-        .add_event::<systems::combat_feedback::PlayerDamagedEvent>()
+        // Simulation runs in FixedUpdate at a steady 60 Hz; rendering interpolates between ticks
+        .add_plugins(simulation::SimulationPlugin)
         .add_systems(Startup, (
             setup_game,
             systems::visuals::setup_starfield,
             systems::visuals::setup_planets,
         ))
-        .add_systems(Update, (
+        .add_systems(PreUpdate, input::sample_player_input_system.after(InputSystem))
+        // Input recording and deterministic playback (F9 / F10)
+        .add_systems(Update, replay::replay_control_system.run_if(in_state(GameState::InGame)))
+        .add_systems(Update, replay::restore_live_config_system)
+        .add_systems(OnEnter(GameState::MainMenu), replay::stop_replay_on_exit_system)
+        .add_systems(OnEnter(GameState::GameOver), replay::stop_replay_on_exit_system)
+        .add_systems(PostUpdate, (
+            movement::interpolate_transforms_system,
             camera::camera_follow_system,
             camera::camera_free_look_system,
-        ).run_if(in_state(GameState::InGame)))
-        .add_systems(Update, (
-            movement::ship_movement_system,
-            movement::mouse_flight_system,
-            movement::apply_velocity_system,
-            movement::apply_angular_velocity_system,
-        ).run_if(in_state(GameState::InGame)))
-        .init_resource::<systems::movement::MouseFlightSettings>()
-        .init_resource::<systems::movement::MouseFlightState>()
+            ui::update_enemy_health_bars,
+        ).chain().before(TransformSystem::TransformPropagate).run_if(in_state(GameState::InGame)))
+        .add_systems(Update, combat::update_turret_visual_system.run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
-            combat::autofire_toggle_system,
-            combat::autofire_targeting_system,
-            combat::autofire_aiming_system,
-            combat::update_turret_visual_system,
-            combat::autofire_firing_system,
-            combat::turret_weapon_state_system,
-            combat::weapon_state_system,
-            combat::weapon_firing_system,
-            combat::projectile_movement_system,
-            combat::homing_projectile_system,
-            combat::projectile_lifetime_system,
-            combat::projectile_collision_system,
-            combat::damage_system,
-            combat::shield_recharge_system,
-            combat::ship_death_system,
+            ui::update_shield_facing_hud_system,
+            ui::update_power_hud_system,
+            ui::update_status_effects_hud_system,
+            ui::update_enemy_status_icons_system,
         ).run_if(in_state(GameState::InGame)))
+        .add_systems(Update, ui::update_subsystem_hud_system.run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
-            ai::ai_controller_system,
-            ai::ai_target_acquisition_system,
-            ai::ai_weapon_selection_system,
-            ai::ai_combat_system,
+            ai::ai_debug_overlay_toggle_system,
+            ui::update_ai_debug_overlay_system,
+            ui::update_director_debug_system,
         ).run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
-            spawning::enemy_spawner_system,
+            ui::update_wingmen_hud_system,
+            ui::update_reputation_hud_system,
+            ui::update_boss_health_bar_system,
+            ui::update_replay_bar_system,
         ).run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
-            resources_system::loot_collection_system,
-            resources_system::spawn_loot_system,
-            resources_system::animate_loot_system,
             resources_system::update_collection_particles,
-        ).run_if(in_state(GameState::InGame)))
-        .add_systems(Update, (
-            combat::energy_recharge_system,
+            ui::update_weapon_tooltip_system,
         ).run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
             systems::effects::update_explosions,
             systems::effects::update_shield_effects,
+            systems::effects::update_warp_flashes,
             systems::effects::update_hull_spark_effects,
         ).run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
@@ -87,13 +76,7 @@ fn main() {
             progression::track_resource_collection_system,
             progression::display_skill_point_gain_system,
         ).run_if(in_state(GameState::InGame)))
-        .add_systems(Update, (
-            abilities::ability_activation_system,
-            abilities::update_ability_cooldowns_system,
-            abilities::apply_ability_effects_system,
-            abilities::cleanup_ability_visuals_system,
-            abilities::devastation_effect_system,
-        ).run_if(in_state(GameState::InGame)))
+        .add_systems(Update, abilities::cleanup_ability_visuals_system.run_if(in_state(GameState::InGame)))
         .add_systems(Update, (
             ship_visuals::apply_class_visuals_system,
             ship_visuals::spawn_ship_attachments_system,
@@ -111,7 +94,6 @@ fn main() {
             ui::check_upgrade_availability_system,
             ui::update_upgrade_notification_pulse,
             ui::setup_enemy_health_bars,
-            ui::update_enemy_health_bars,
             ui::apply_upgrades_to_player,
             // UI Animation systems
             ui_animations::update_pulse_animations,
@@ -207,9 +189,15 @@ fn main() {
             ui_animations::update_glitch_text_effects,
         ).run_if(in_state(GameState::GameOver)))
         .add_systems(OnEnter(GameState::InGame), (
+            replay::stop_replay_on_external_restart_system
+                .before(spawning::handle_restart_game)
+                .before(spawning::handle_load_game),
             spawning::handle_restart_game,
             spawning::handle_load_game,
         ))
+        // Replays restart the run without leaving the game
+        .add_systems(Update, spawning::handle_restart_game
+            .run_if(in_state(GameState::InGame).and_then(resource_exists::<ui::RestartGameFlag>)))
         .add_systems(OnEnter(GameState::GalaxyMap), (
             galaxy_ui::setup_galaxy_map_ui,
             movement::release_cursor_lock,
@@ -227,12 +215,8 @@ fn main() {
             ui_animations::update_pulse_backgrounds,
         ).run_if(in_state(GameState::GalaxyMap)))
         .add_systems(Update, (
-            travel::check_jump_gate_proximity,
-            travel::hyperspace_animation_system,
-            travel::handle_system_transition,
-            spawning::handle_respawn_system_content,
-            galaxy::spawn_system_content,
-            galaxy::update_planet_orbits,
+            // A restart spawns the new system here, before the clock starts again
+            galaxy::spawn_system_content.after(spawning::handle_restart_game),
             galaxy::animate_jump_gate_rings,
             galaxy::animate_jump_gate_glow,
         ).run_if(in_state(GameState::InGame)))
@@ -331,6 +315,8 @@ fn setup_game(
         components::ship_classes::ShipVisualConfig::default(),
         components::ship_classes::ClassBonuses::new(),
         components::combat::AutoTurret::default(),
+        components::combat::MissileLock::new(2.0),
+        components::combat::Countermeasures::new(6),
     )).id();
 
     // Build modular ship visuals
@@ -343,43 +329,45 @@ fn setup_game(
         Color::srgb(0.2, 0.5, 0.8),
     );
     
-    // Spawn turret visual as child entity (mounted on back of ship)
-    let turret_visual = commands.spawn((
-        PbrBundle {
-            mesh: meshes.add(Sphere::new(0.8)), // Spherical turret base
-            material: materials.add(StandardMaterial {
-                base_color: Color::srgb(0.3, 0.6, 0.9),
-                metallic: 0.8,
-                perceptual_roughness: 0.3,
-                emissive: LinearRgba::from(Color::srgb(0.1, 0.3, 0.6)) * 2.0,
+    // Spawn a turret visual per hardpoint as child entities
+    for (hardpoint_index, hardpoint) in components::combat::AutoTurret::default().hardpoints.iter().enumerate() {
+        let turret_visual = commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Sphere::new(0.8)), // Spherical turret base
+                material: materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.3, 0.6, 0.9),
+                    metallic: 0.8,
+                    perceptual_roughness: 0.3,
+                    emissive: LinearRgba::from(Color::srgb(0.1, 0.3, 0.6)) * 2.0,
+                    ..default()
+                }),
+                transform: Transform::from_translation(hardpoint.offset),
                 ..default()
-            }),
-            transform: Transform::from_xyz(0.0, 2.0, -3.0), // Behind and above ship center
-            ..default()
-        },
-        components::combat::TurretVisual,
-    )).id();
-    
-    // Add turret barrel as child of turret base
-    commands.entity(turret_visual).with_children(|parent| {
-        parent.spawn(PbrBundle {
-            mesh: meshes.add(Capsule3d::new(0.15, 1.2)),
-            material: materials.add(StandardMaterial {
-                base_color: Color::srgb(0.4, 0.7, 1.0),
-                metallic: 0.9,
-                perceptual_roughness: 0.2,
-                emissive: LinearRgba::from(Color::srgb(0.2, 0.4, 0.8)) * 3.0,
+            },
+            components::combat::TurretVisual { hardpoint: hardpoint_index },
+        )).id();
+        
+        // Add turret barrel as child of turret base
+        commands.entity(turret_visual).with_children(|parent| {
+            parent.spawn(PbrBundle {
+                mesh: meshes.add(Capsule3d::new(0.15, 1.2)),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.4, 0.7, 1.0),
+                    metallic: 0.9,
+                    perceptual_roughness: 0.2,
+                    emissive: LinearRgba::from(Color::srgb(0.2, 0.4, 0.8)) * 3.0,
+                    ..default()
+                }),
+                // Barrel extends along Z-axis (which is the turret's forward direction)
+                transform: Transform::from_xyz(0.0, 0.0, 1.0)
+                    .with_rotation(Quat::IDENTITY),
                 ..default()
-            }),
-            // Barrel extends along Z-axis (which is the turret's forward direction)
-            transform: Transform::from_xyz(0.0, 0.0, 1.0)
-                .with_rotation(Quat::IDENTITY),
-            ..default()
+            });
         });
-    });
-    
-    // Attach turret to ship
-    commands.entity(player_ship).add_child(turret_visual);
+        
+        // Attach turret to ship
+        commands.entity(player_ship).add_child(turret_visual);
+    }
 
     // Add weapon mounts to player - starting with Laser, Autocannon, and Plasma
     commands.entity(player_ship).insert(components::combat::WeaponMount {
@@ -390,6 +378,10 @@ fn setup_game(
         ],
         current_weapon: 0,
     });
+    commands.entity(player_ship).insert((
+        components::combat::PowerDistribution::default(),
+        components::status_effects::StatusEffects::default(),
+    ));
 
     // Initialize player inventory
     commands.insert_resource(components::resources::Inventory {
@@ -402,6 +394,21 @@ fn setup_game(
     // Initialize player upgrades
     commands.insert_resource(components::upgrades::PlayerUpgrades::default());
     
+    // Initialize subsystem targeting (G to cycle)
+    commands.insert_resource(components::ship::SubsystemTargeting::default());
+    
+    // Initialize AI decision debug overlay (F12 to toggle)
+    commands.insert_resource(components::ai::AIDebugOverlay::default());
+    
+    // Initialize the player's wing (F5-F8 orders, H to hire)
+    commands.insert_resource(components::wingman::WingCommand::default());
+    
+    // Initialize faction standings (drives who shoots at whom)
+    commands.insert_resource(components::faction::Reputation::default());
+    
+    // Initialize weapon item stash (dropped weapons)
+    commands.insert_resource(components::weapon_items::WeaponStash::default());
+    
     // Initialize class progression and skill points
     commands.insert_resource(components::ship_classes::ClassProgression::new());
     
@@ -417,11 +424,13 @@ fn setup_game(
     // Initialize screen shake resource
     commands.insert_resource(systems::ui_effects::ScreenShake::default());
 
-    // Initialize game resources (reduced spawn time from 5s to 3s for more action)
-    commands.insert_resource(resources::SpawnTimer(Timer::from_seconds(3.0, TimerMode::Repeating)));
+    // Initialize game resources
+    commands.insert_resource(components::encounter::EncounterDirector::default());
+    commands.insert_resource(resources::TrafficTimer(Timer::from_seconds(8.0, TimerMode::Repeating)));
     
-    // Initialize galaxy
+    // Initialize galaxy - its seed is the run seed, which also seeds gameplay randomness
     let galaxy = resources::Galaxy::new(rand::random());
+    commands.insert_resource(resources::GameRng::new(galaxy.seed));
     commands.insert_resource(galaxy);
     
     // Trigger initial system content spawn
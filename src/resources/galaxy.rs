@@ -145,6 +145,23 @@ impl Galaxy {
             false
         }
     }
+
+    /// Record that a system's boss has been destroyed - it won't return
+    pub fn mark_boss_defeated(&mut self, system_id: SystemId) {
+        if let Some(system) = self.systems.get_mut(&system_id) {
+            system.boss_defeated = true;
+        }
+    }
+
+    /// IDs of every system whose boss has been destroyed
+    pub fn defeated_bosses(&self) -> Vec<SystemId> {
+        let mut defeated: Vec<SystemId> = self.systems.values()
+            .filter(|s| s.boss_defeated)
+            .map(|s| s.id)
+            .collect();
+        defeated.sort();
+        defeated
+    }
 }
 
 impl Default for Galaxy {
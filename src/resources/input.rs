@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+/// Player controls for the next simulation tick. The keyboard and mouse are latched here every
+/// frame and the fixed-step systems read from it, so a press isn't lost on a frame that runs no
+/// tick or repeated on a frame that runs several.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct PlayerInput {
+    pub keys_held: HashSet<KeyCode>,
+    pub keys_pressed: HashSet<KeyCode>,
+    pub buttons_held: HashSet<MouseButton>,
+    pub buttons_pressed: HashSet<MouseButton>,
+    pub buttons_released: HashSet<MouseButton>,
+    pub mouse_delta: Vec2,
+}
+
+impl PlayerInput {
+    pub fn pressed(&self, key: KeyCode) -> bool {
+        self.keys_held.contains(&key)
+    }
+
+    pub fn just_pressed(&self, key: KeyCode) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    pub fn mouse_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_held.contains(&button)
+    }
+
+    pub fn mouse_just_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    pub fn mouse_just_released(&self, button: MouseButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+
+    /// Drop the one-shot parts (presses, releases, mouse motion) once a tick has seen them
+    pub fn consume_edges(&mut self) {
+        self.keys_pressed.clear();
+        self.buttons_pressed.clear();
+        self.buttons_released.clear();
+        self.mouse_delta = Vec2::ZERO;
+    }
+}
@@ -1,12 +1,24 @@
 pub mod game_state;
 pub mod galaxy;
+pub mod rng;
+pub mod input;
+pub mod replay;
 
 pub use game_state::*;
 pub use galaxy::*;
+pub use rng::*;
+pub use input::*;
+pub use replay::*;
 
 use bevy::prelude::*;
 
-/// Timer for spawning enemies
+/// Timer for civilian traffic arriving through the gates
 #[derive(Resource)]
-pub struct SpawnTimer(pub Timer);
+pub struct TrafficTimer(pub Timer);
 
+/// Simulation time since the current run started - advances once per fixed tick and resets on
+/// restart and load, so anything keyed to it repeats exactly for the same tick
+#[derive(Resource, Default)]
+pub struct RunClock {
+    pub elapsed: f32,
+}
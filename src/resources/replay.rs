@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::ship_classes::ClassProgression;
+use crate::resources::PlayerInput;
+
+/// Ticks between world checksums while recording or playing
+pub const CHECKSUM_INTERVAL: u64 = 60;
+/// Playback speeds the -/= keys step through
+pub const PLAYBACK_SPEEDS: [f64; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+/// Simulation speed while fast-forwarding to a seek target
+pub const SEEK_SPEED: f64 = 16.0;
+/// How far one seek press jumps (10 seconds at 60 Hz)
+pub const SEEK_STEP_TICKS: u64 = 600;
+/// Frames the clock stays paused after a replay restarts the run, so the fresh world is fully
+/// spawned before the first tick
+pub const SETTLE_FRAMES: u32 = 3;
+
+/// Every key a fixed-step system reads must be listed here, or replays won't see it.
+/// A key's position is its bit in `TickInput` - only ever append.
+pub const RECORDED_KEYS: [KeyCode; 45] = [
+    // Flight
+    KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD,
+    KeyCode::Space, KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyQ, KeyCode::KeyE,
+    // Weapons, reload and flares
+    KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4, KeyCode::KeyR, KeyCode::KeyF,
+    // Abilities
+    KeyCode::KeyZ, KeyCode::KeyX, KeyCode::KeyC, KeyCode::KeyV, KeyCode::KeyB, KeyCode::KeyN,
+    // Autofire turret and subsystem targeting
+    KeyCode::KeyK, KeyCode::KeyP, KeyCode::KeyO, KeyCode::KeyG,
+    // Shield facings and power
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::Home,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4,
+    // Wing orders and hiring
+    KeyCode::F5, KeyCode::F6, KeyCode::F7, KeyCode::F8, KeyCode::KeyH,
+    // Ammo fabrication and weapon stash
+    KeyCode::KeyT, KeyCode::BracketLeft, KeyCode::BracketRight, KeyCode::KeyY, KeyCode::Delete,
+    // Jump gates
+    KeyCode::KeyJ,
+];
+
+/// Mouse buttons a fixed-step system reads, same rules as `RECORDED_KEYS`
+pub const RECORDED_BUTTONS: [MouseButton; 3] = [
+    MouseButton::Left,
+    MouseButton::Right,
+    MouseButton::Middle,
+];
+
+/// One simulation tick of player input, packed to bitmasks over the recorded keys and buttons
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct TickInput {
+    pub keys_held: u64,
+    pub keys_pressed: u64,
+    pub buttons_held: u8,
+    pub buttons_pressed: u8,
+    pub buttons_released: u8,
+    pub mouse_delta: [f32; 2],
+}
+
+impl TickInput {
+    pub fn capture(input: &PlayerInput) -> Self {
+        let key_mask = |keys: &bevy::utils::HashSet<KeyCode>| {
+            RECORDED_KEYS.iter().enumerate()
+                .filter(|(_, key)| keys.contains(*key))
+                .fold(0u64, |mask, (bit, _)| mask | 1 << bit)
+        };
+        let button_mask = |buttons: &bevy::utils::HashSet<MouseButton>| {
+            RECORDED_BUTTONS.iter().enumerate()
+                .filter(|(_, button)| buttons.contains(*button))
+                .fold(0u8, |mask, (bit, _)| mask | 1 << bit)
+        };
+
+        Self {
+            keys_held: key_mask(&input.keys_held),
+            keys_pressed: key_mask(&input.keys_pressed),
+            buttons_held: button_mask(&input.buttons_held),
+            buttons_pressed: button_mask(&input.buttons_pressed),
+            buttons_released: button_mask(&input.buttons_released),
+            mouse_delta: input.mouse_delta.to_array(),
+        }
+    }
+
+    pub fn to_player_input(&self) -> PlayerInput {
+        let keys = |mask: u64| {
+            RECORDED_KEYS.iter().enumerate()
+                .filter(|(bit, _)| mask & 1 << bit != 0)
+                .map(|(_, key)| *key)
+                .collect()
+        };
+        let buttons = |mask: u8| {
+            RECORDED_BUTTONS.iter().enumerate()
+                .filter(|(bit, _)| mask & 1 << bit != 0)
+                .map(|(_, button)| *button)
+                .collect()
+        };
+
+        PlayerInput {
+            keys_held: keys(self.keys_held),
+            keys_pressed: keys(self.keys_pressed),
+            buttons_held: buttons(self.buttons_held),
+            buttons_pressed: buttons(self.buttons_pressed),
+            buttons_released: buttons(self.buttons_released),
+            mouse_delta: Vec2::from_array(self.mouse_delta),
+        }
+    }
+}
+
+/// Settings a run depends on beyond its seed and inputs - playback puts them back first
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReplayConfig {
+    pub tick_rate: f64,
+    pub checksum_interval: u64,
+    pub mouse_sensitivity: f32,
+    pub mouse_smoothing: f32,
+    pub class_progression: ClassProgression,
+    pub game_version: String,
+}
+
+/// A recorded run: seed, settings, one input per tick and a world checksum every
+/// `checksum_interval` ticks
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReplayData {
+    pub seed: u64,
+    pub config: ReplayConfig,
+    pub inputs: Vec<TickInput>,
+    pub checksums: Vec<u64>,
+}
+
+impl ReplayData {
+    pub fn tick_count(&self) -> u64 {
+        self.inputs.len() as u64
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplayMode {
+    #[default]
+    Idle,
+    Recording,
+    Playing,
+}
+
+/// Recorder / player state
+#[derive(Resource)]
+pub struct Replay {
+    pub mode: ReplayMode,
+    pub data: Option<ReplayData>,
+    pub tick: u64,
+    /// Frames left before the clock starts after a restart
+    pub settle_frames: u32,
+    /// Index into `PLAYBACK_SPEEDS`
+    pub speed: usize,
+    /// Tick playback is fast-forwarding to
+    pub seek_target: Option<u64>,
+    /// First tick whose checksum didn't match the recording
+    pub diverged_at: Option<u64>,
+    /// The player's own settings, put back once playback ends
+    pub live_config: Option<ReplayConfig>,
+}
+
+impl Default for Replay {
+    fn default() -> Self {
+        Self {
+            mode: ReplayMode::Idle,
+            data: None,
+            tick: 0,
+            settle_frames: 0,
+            speed: 2, // 1x
+            seek_target: None,
+            diverged_at: None,
+            live_config: None,
+        }
+    }
+}
+
+impl Replay {
+    pub fn is_active(&self) -> bool {
+        self.mode != ReplayMode::Idle
+    }
+
+    /// Simulation speed to run at this frame
+    pub fn time_scale(&self) -> f64 {
+        match self.mode {
+            ReplayMode::Playing if self.seek_target.is_some() => SEEK_SPEED,
+            ReplayMode::Playing => PLAYBACK_SPEEDS[self.speed],
+            _ => 1.0,
+        }
+    }
+}
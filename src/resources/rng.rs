@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Independent random streams - each gameplay subsystem draws from its own so that extra
+/// rolls in one (say, a new loot table) don't shift the outcomes of another
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RngStream {
+    Spawning, // Encounter picks, arrival points, reinforcements
+    Combat,   // Weapon spread, crits, evasion, countermeasures
+    Ai,       // Think jitter, distress calls, morale, aim scatter
+    Loot,     // Drops, rarity and affix rolls
+    Traffic,  // Civilian arrivals and routes
+}
+
+impl RngStream {
+    pub const ALL: [RngStream; 5] = [
+        RngStream::Spawning,
+        RngStream::Combat,
+        RngStream::Ai,
+        RngStream::Loot,
+        RngStream::Traffic,
+    ];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Seeded source for all gameplay randomness. Seeded from the run (galaxy) seed, so a seed
+/// plus the same inputs plays out the same way. Cosmetic effects don't draw from it.
+#[derive(Resource, Clone)]
+pub struct GameRng {
+    streams: [StdRng; 5],
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        // Spread the stream seeds apart so neighbouring run seeds don't share streams
+        let streams = RngStream::ALL.map(|stream| {
+            StdRng::seed_from_u64(seed ^ (stream.index() as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        });
+        Self { streams }
+    }
+
+    /// The random stream for one subsystem
+    pub fn stream(&mut self, stream: RngStream) -> &mut StdRng {
+        &mut self.streams[stream.index()]
+    }
+
+    /// Where every stream has got to - equal only after the same draws. Peeks with copies, so
+    /// the streams themselves don't advance.
+    pub fn fingerprint(&self) -> [u64; 5] {
+        self.streams.clone().map(|mut stream| stream.next_u64())
+    }
+}
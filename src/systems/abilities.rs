@@ -1,19 +1,24 @@
 use bevy::prelude::*;
 use crate::components::{
-    ship::{Player, Velocity},
-    abilities::{AbilityController, SpecialAbility, ActiveEffect, AbilityEffectType, AbilityVisualEffect},
-    combat::{Health, Shield, WeaponMount},
+    ship::{Player, Ship, Velocity},
+    ai::{AIController, Enemy},
+    abilities::{AbilityController, SpecialAbility, ActiveEffect, AbilityEffectType, AbilityVisualEffect, QuantumDashMotion},
+    combat::{Faction, Health, Projectile, Shield, Weapon, WeaponMount},
+    galaxy::Obstacle,
+    status_effects::{StatusEffects, StatusEffectKind},
 };
+use crate::resources::PlayerInput;
+use crate::utils::{spawn_placement, steering};
 
 /// System to handle ability key presses
 pub fn ability_activation_system(
     mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut query: Query<(Entity, &Transform, &mut AbilityController), With<Player>>,
+    input: Res<PlayerInput>,
+    mut query: Query<(Entity, &Transform, &mut AbilityController, Option<&mut StatusEffects>), With<Player>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    if let Ok((player_entity, transform, mut ability_controller)) = query.get_single_mut() {
+    if let Ok((player_entity, transform, mut ability_controller, mut status_effects)) = query.get_single_mut() {
         for ability in [
             SpecialAbility::QuantumDash,
             SpecialAbility::FortressMode,
@@ -22,9 +27,10 @@ pub fn ability_activation_system(
             SpecialAbility::PerfectShot,
             SpecialAbility::MissileStorm,
         ] {
-            if keyboard.just_pressed(ability.activation_key()) {
+            if input.just_pressed(ability.activation_key()) {
                 if ability_controller.activate(ability) {
-                    activate_ability(&mut commands, player_entity, transform, &mut ability_controller, ability, &mut meshes, &mut materials);
+                    println!("[Abilities] Activated: {}", ability.name());
+                    activate_ability(&mut commands, player_entity, transform, &mut ability_controller, status_effects.as_deref_mut(), ability, &mut meshes, &mut materials);
                 } else if !ability_controller.is_unlocked(ability) {
                     println!("[Abilities] {} is not unlocked yet!", ability.name());
                 } else {
@@ -42,6 +48,7 @@ fn activate_ability(
     player_entity: Entity,
     transform: &Transform,
     ability_controller: &mut AbilityController,
+    status_effects: Option<&mut StatusEffects>,
     ability: SpecialAbility,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
@@ -51,7 +58,7 @@ fn activate_ability(
             activate_quantum_dash(commands, player_entity, transform, meshes, materials);
         }
         SpecialAbility::FortressMode => {
-            activate_fortress_mode(ability_controller);
+            activate_fortress_mode(ability_controller, status_effects);
         }
         SpecialAbility::Devastation => {
             activate_devastation(ability_controller);
@@ -88,16 +95,16 @@ fn activate_quantum_dash(
 }
 
 /// Fortress Mode - invulnerable but immobile
-fn activate_fortress_mode(ability_controller: &mut AbilityController) {
+pub fn activate_fortress_mode(ability_controller: &mut AbilityController, status_effects: Option<&mut StatusEffects>) {
     println!("[Abilities] 🛡️ FORTRESS MODE!");
     ability_controller.add_active_effect(ActiveEffect {
         effect_type: AbilityEffectType::Invulnerable,
         remaining_duration: 3.0,
     });
-    ability_controller.add_active_effect(ActiveEffect {
-        effect_type: AbilityEffectType::Immobile,
-        remaining_duration: 3.0,
-    });
+    // Immobile - a full-strength slow for the duration
+    if let Some(status_effects) = status_effects {
+        status_effects.apply(StatusEffectKind::Slowed, 3.0, 0.0);
+    }
 }
 
 /// Devastation - all weapons fire
@@ -149,7 +156,7 @@ fn activate_missile_storm(
 }
 
 /// Spawn visual effect for ability activation
-fn spawn_ability_visual(
+pub fn spawn_ability_visual(
     commands: &mut Commands,
     position: Vec3,
     ability: SpecialAbility,
@@ -183,21 +190,26 @@ fn spawn_ability_visual(
     ));
 }
 
-/// Update ability cooldowns
+/// Update ability cooldowns (player and ability-carrying enemies)
 pub fn update_ability_cooldowns_system(
     time: Res<Time>,
-    mut query: Query<&mut AbilityController, With<Player>>,
+    mut query: Query<(&mut AbilityController, Has<Player>)>,
 ) {
-    if let Ok(mut ability_controller) = query.get_single_mut() {
-        ability_controller.update_cooldowns(time.delta_seconds());
+    for (mut ability_controller, is_player) in query.iter_mut() {
+        let ready = ability_controller.update_cooldowns(time.delta_seconds());
+        if is_player {
+            for ability in ready {
+                println!("[Abilities] {} ready!", ability.name());
+            }
+        }
     }
 }
 
 /// Apply active ability effects
 pub fn apply_ability_effects_system(
-    mut query: Query<(&AbilityController, &mut Health, &mut Shield, &mut Velocity), With<Player>>,
+    mut query: Query<(&AbilityController, &mut Health, &mut Shield)>,
 ) {
-    if let Ok((ability_controller, mut health, mut shield, mut velocity)) = query.get_single_mut() {
+    for (ability_controller, mut health, mut shield) in query.iter_mut() {
         // Invulnerable - prevent damage (would need to modify damage system)
         if ability_controller.has_active_effect(AbilityEffectType::Invulnerable) {
             // Mark as invulnerable
@@ -205,12 +217,152 @@ pub fn apply_ability_effects_system(
             shield.current = shield.max;
         }
         
-        // Immobile - stop movement
-        if ability_controller.has_active_effect(AbilityEffectType::Immobile) {
-            velocity.0 = Vec3::ZERO;
+        // Invisible - reduce visibility (would affect rendering)
+    }
+}
+
+/// Enemy ability AI - elites dash in from range, fortress up when shields fail
+/// and fire missile storms into clustered targets
+pub fn enemy_ability_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut enemies: Query<(Entity, &mut Transform, &mut AbilityController, &AIController, &Shield, &Faction, &Enemy, Option<&mut StatusEffects>), Without<Player>>,
+    targets: Query<(Entity, &Transform, &Faction), (With<Ship>, Without<Enemy>)>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+    reputation: Res<crate::components::Reputation>,
+) {
+    let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+        .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+        .collect();
+    
+    for (entity, mut transform, mut controller, ai, shield, faction, enemy, status_effects) in enemies.iter_mut() {
+        let Some(target_entity) = ai.target else {
+            continue;
+        };
+        let Ok((_, target_transform, _)) = targets.get(target_entity) else {
+            continue;
+        };
+        let to_target = target_transform.translation - transform.translation;
+        let distance = to_target.length();
+        
+        // FortressMode when shields are nearly gone
+        if shield.current < shield.max * 0.25
+            && !controller.has_active_effect(AbilityEffectType::Invulnerable)
+            && controller.activate(SpecialAbility::FortressMode)
+        {
+            debug!("{:?} uses {}", enemy.enemy_type, SpecialAbility::FortressMode.name());
+            activate_fortress_mode(&mut controller, status_effects.map(|s| s.into_inner()));
+            spawn_ability_visual(&mut commands, transform.translation, SpecialAbility::FortressMode, &mut meshes, &mut materials);
+            continue;
         }
         
-        // Invisible - reduce visibility (would affect rendering)
+        // QuantumDash to close distance on a target that's kiting out of range
+        if distance > 80.0 && distance < 250.0 && controller.activate(SpecialAbility::QuantumDash) {
+            debug!("{:?} uses {}", enemy.enemy_type, SpecialAbility::QuantumDash.name());
+            let dash = to_target.normalize() * (distance - 30.0).min(50.0);
+            // Never come out of the dash inside a planet or gate
+            let destination = spawn_placement::clear_of_obstacles(transform.translation + dash, &obstacle_volumes);
+            spawn_ability_visual(&mut commands, transform.translation, SpecialAbility::QuantumDash, &mut meshes, &mut materials);
+            spawn_ability_visual(&mut commands, destination, SpecialAbility::QuantumDash, &mut meshes, &mut materials);
+            transform.look_at(target_transform.translation, Vec3::Y);
+            commands.entity(entity).insert(QuantumDashMotion {
+                destination,
+                remaining: QuantumDashMotion::DURATION,
+            });
+            continue;
+        }
+        
+        // MissileStorm when several hostiles are bunched up around the target
+        let cluster: Vec<Entity> = targets.iter()
+            .filter(|(_, t, f)| reputation.is_hostile(*faction, **f) && t.translation.distance(target_transform.translation) < 40.0)
+            .map(|(e, _, _)| e)
+            .collect();
+        if cluster.len() >= 2 && distance < 200.0 && controller.activate(SpecialAbility::MissileStorm) {
+            debug!("{:?} uses {} on {} clustered targets", enemy.enemy_type, SpecialAbility::MissileStorm.name(), cluster.len());
+            spawn_ability_visual(&mut commands, transform.translation, SpecialAbility::MissileStorm, &mut meshes, &mut materials);
+            launch_missile_storm(&mut commands, &mut meshes, &mut materials, entity, &transform, *faction, &cluster);
+        }
+    }
+}
+
+/// Carry dashing ships to their destination a step per tick, keeping clear of obstacles on the way
+pub fn quantum_dash_motion_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut QuantumDashMotion)>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+) {
+    let dt = time.delta_seconds();
+    let ship_clearance = 6.0;
+    
+    let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+        .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+        .collect();
+    
+    for (entity, mut transform, mut dash) in query.iter_mut() {
+        let fraction = (dt / dash.remaining.max(dt)).min(1.0);
+        let mut position = transform.translation.lerp(dash.destination, fraction);
+        let mut step = Vec3::ZERO;
+        steering::enforce_clearance(&mut position, &mut step, &obstacle_volumes, ship_clearance, dt);
+        transform.translation = position;
+        
+        dash.remaining -= dt;
+        if dash.remaining <= 0.0 {
+            commands.entity(entity).remove::<QuantumDashMotion>();
+        }
+    }
+}
+
+/// Ring of homing missiles spread across the given targets
+fn launch_missile_storm(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    owner: Entity,
+    transform: &Transform,
+    faction: Faction,
+    targets: &[Entity],
+) {
+    let missile = Weapon::missile();
+    let missile_count = 12;
+    let color = Color::srgb(1.0, 0.5, 0.0);
+    
+    for i in 0..missile_count {
+        let angle = i as f32 / missile_count as f32 * std::f32::consts::TAU;
+        // Fan out around the ship before the homing turns them onto their targets
+        let direction = (transform.rotation * Vec3::new(angle.cos(), angle.sin(), -1.0)).normalize();
+        let position = transform.translation + direction * 4.0;
+        
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Capsule3d::new(0.2, 0.8)),
+                material: materials.add(StandardMaterial {
+                    base_color: color,
+                    emissive: LinearRgba::from(color) * 4.0,
+                    ..default()
+                }),
+                transform: Transform::from_translation(position)
+                    .with_rotation(Quat::from_rotation_arc(Vec3::Y, direction)),
+                ..default()
+            },
+            Projectile {
+                damage: missile.damage * 0.5,
+                lifetime: 6.0,
+                owner,
+                weapon_type: missile.weapon_type,
+                shield_damage_multiplier: missile.shield_damage_multiplier,
+                hull_damage_multiplier: missile.hull_damage_multiplier,
+                piercing: false,
+                area_damage: 6.0,
+                homing_strength: 10.0,
+                homing_target: targets.get(i % targets.len()).copied(),
+                initial_direction: direction,
+                is_critical: false,
+            },
+            Velocity(direction * missile.projectile_speed),
+            faction,
+        ));
     }
 }
 
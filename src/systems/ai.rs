@@ -1,63 +1,198 @@
 use bevy::prelude::*;
+use rand::Rng;
 use crate::components::ship::*;
 use crate::components::combat::*;
 use crate::components::ai::*;
+use crate::components::{Squadron, SquadronMember};
+use crate::components::galaxy::Obstacle;
+use crate::components::travel::JumpGate;
+use crate::resources::{GameRng, RngStream};
+use crate::utils::{math, steering};
 
-/// AI controller system - manages behavior state machine
+/// AI controller system - utility AI. Each ship gathers perception inputs, scores
+/// attack/flank/evade/retreat/regroup with its type's profile and flies the winner
 pub fn ai_controller_system(
     time: Res<Time>,
-    mut query: Query<(&mut AIController, &Health, &Shield)>,
+    mut query: Query<(Entity, &mut AIController, &Transform, &Health, &Shield, &Faction, Option<&Squadron>, Option<&SquadronMember>), Without<Player>>,
+    ships: Query<(Entity, &Transform, &Faction), With<AIController>>,
+    target_query: Query<(&Transform, &Health, &Shield), With<Ship>>,
+    projectiles: Query<(&Transform, &Velocity, &Faction), With<Projectile>>,
+    reputation: Res<crate::components::Reputation>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let dt = time.delta_seconds();
+    let rng = game_rng.stream(RngStream::Ai);
+    let think_interval = 0.5;
+    let support_range = 50.0;
+    let regroup_range = 300.0;
+    let threat_range = 50.0;
     
-    for (mut ai, health, shield) in query.iter_mut() {
+    for (entity, mut ai, transform, health, shield, faction, squadron, squad_member) in query.iter_mut() {
         ai.state_timer += dt;
+        ai.think_timer -= dt;
         
-        // Check health for retreat
-        let health_percent = health.current / health.max;
-        let shield_percent = shield.current / shield.max;
-        
-        if health_percent < 0.2 || (health_percent < 0.5 && shield_percent < 0.1) {
-            ai.state = AIBehaviorState::Retreat;
-        }
+        let Some((target_transform, target_health, target_shield)) = ai.target.and_then(|t| target_query.get(t).ok()) else {
+            if ai.state != AIBehaviorState::Patrol {
+                ai.state = AIBehaviorState::Patrol;
+                ai.state_timer = 0.0;
+            }
+            ai.scores = [0.0; 5];
+            continue;
+        };
+        let distance = transform.translation.distance(target_transform.translation);
         
-        // State transitions based on timer
-        if ai.state_timer > 5.0 {
-            ai.state_timer = 0.0;
+        if ai.think_timer <= 0.0 {
+            // Stagger re-evaluation so a wave doesn't all change its mind on the same frame
+            ai.think_timer = think_interval + rng.gen::<f32>() * 0.2;
             
-            match ai.state {
-                AIBehaviorState::Patrol => {
-                    if ai.target.is_some() {
-                        ai.state = AIBehaviorState::Pursue;
-                    }
+            // Allies: who's close enough to support us, and where the group is
+            let mut allies_nearby = 0;
+            let mut ally_sum = Vec3::ZERO;
+            let mut ally_count = 0;
+            for (other, other_transform, other_faction) in ships.iter() {
+                if other == entity || other_faction != faction {
+                    continue;
                 }
-                AIBehaviorState::Pursue => {
-                    if ai.target.is_some() {
-                        ai.state = AIBehaviorState::Attack;
-                    }
+                let ally_distance = other_transform.translation.distance(transform.translation);
+                if ally_distance < support_range {
+                    allies_nearby += 1;
                 }
-                AIBehaviorState::Attack => {
-                    if rand::random::<f32>() < ai.evasion_threshold {
-                        ai.state = AIBehaviorState::Evade;
-                    }
+                if ally_distance < regroup_range {
+                    ally_sum += other_transform.translation;
+                    ally_count += 1;
                 }
-                AIBehaviorState::Evade => {
-                    ai.state = AIBehaviorState::Attack;
+            }
+            if ally_count > 0 {
+                ai.regroup_point = ally_sum / ally_count as f32;
+            }
+            
+            // Incoming fire: hostile shots whose path passes close to us
+            let mut incoming = 0;
+            for (proj_transform, proj_velocity, proj_faction) in projectiles.iter() {
+                if !reputation.is_hostile(*proj_faction, *faction) {
+                    continue;
                 }
-                AIBehaviorState::Retreat => {
-                    if health_percent > 0.6 {
-                        ai.state = AIBehaviorState::Patrol;
-                    }
+                let to_ship = transform.translation - proj_transform.translation;
+                if to_ship.length() > threat_range {
+                    continue;
+                }
+                let heading = proj_velocity.0.normalize_or_zero();
+                let along = to_ship.dot(heading);
+                if along > 0.0 && (to_ship - heading * along).length() < 5.0 {
+                    incoming += 1;
                 }
             }
+            
+            let perception = AIPerception {
+                distance,
+                own_health: health_fraction(health, shield),
+                target_health: health_fraction(target_health, target_shield),
+                incoming_fire: (incoming as f32 / 3.0).min(1.0),
+                allies_nearby: (allies_nearby as f32 / 3.0).min(1.0),
+                has_allies: ally_count > 0,
+                morale: squad_member.map(|m| m.morale)
+                    .or(squadron.map(|s| s.morale))
+                    .unwrap_or(1.0),
+            };
+            ai.decide(&perception);
+        }
+        
+        let desired = ai.behavior_for_action(distance);
+        if ai.state != desired {
+            ai.state = desired;
+            ai.state_timer = 0.0;
         }
     }
 }
 
-/// AI target acquisition system
+/// Distress call from a retreating ship - schedules a reinforcement wave in the spawner
+#[derive(Event)]
+pub struct DistressCallEvent {
+    pub caller_type: EnemyType,
+    pub faction: Faction,
+    pub position: Vec3,
+}
+
+/// Retreating ships call for help once they've been running long enough to get a signal out,
+/// and escape the fight entirely if they make it to a jump gate
+pub fn ai_retreat_system(
+    mut commands: Commands,
+    mut ai_query: Query<(Entity, &Transform, &mut AIController, &Enemy, &Faction), Without<Player>>,
+    gates: Query<(&GlobalTransform, &JumpGate)>,
+    mut distress_events: EventWriter<DistressCallEvent>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    for (entity, transform, mut ai, enemy, faction) in ai_query.iter_mut() {
+        if ai.state != AIBehaviorState::Retreat {
+            continue;
+        }
+        
+        let at_gate = gates.iter()
+            .any(|(gate_transform, gate)| gate_transform.translation().distance(transform.translation) < gate.activation_range);
+        if at_gate {
+            println!("[AI] {:?} escaped through a jump gate", enemy.enemy_type);
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        
+        if !ai.distress_sent && ai.state_timer >= AIController::DISTRESS_TRANSMIT_TIME {
+            ai.distress_sent = true;
+            if game_rng.stream(RngStream::Ai).gen::<f32>() < enemy.enemy_type.distress_chance() {
+                println!("[AI] {:?} broadcasts a distress call", enemy.enemy_type);
+                distress_events.send(DistressCallEvent {
+                    caller_type: enemy.enemy_type,
+                    faction: *faction,
+                    position: transform.translation,
+                });
+            }
+        }
+    }
+}
+
+/// Combined hull/shield fraction - hull counts for more since it doesn't regenerate
+fn health_fraction(health: &Health, shield: &Shield) -> f32 {
+    let hull = (health.current / health.max).clamp(0.0, 1.0);
+    let shields = if shield.max > 0.0 { (shield.current / shield.max).clamp(0.0, 1.0) } else { 0.0 };
+    hull * 0.7 + shields * 0.3
+}
+
+/// Toggle the AI decision debug overlay (F12)
+pub fn ai_debug_overlay_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<AIDebugOverlay>,
+) {
+    if keyboard.just_pressed(KeyCode::F12) {
+        overlay.enabled = !overlay.enabled;
+        println!("[AI] Debug overlay {}", if overlay.enabled { "on" } else { "off" });
+    }
+}
+
+/// AI power management - shift reactor pips to match the current behavior
+pub fn ai_power_management_system(
+    mut query: Query<(&AIController, &mut PowerDistribution), Without<Player>>,
+) {
+    for (ai, mut power) in query.iter_mut() {
+        let desired = match ai.state {
+            AIBehaviorState::Patrol => PowerDistribution::balanced(),
+            AIBehaviorState::Pursue => PowerDistribution::preset(2, 1, 3),  // Close the distance
+            AIBehaviorState::Attack => PowerDistribution::preset(3, 2, 1),  // Firepower
+            AIBehaviorState::Flank => PowerDistribution::preset(3, 1, 2),   // Guns and speed to swing around
+            AIBehaviorState::Evade => PowerDistribution::preset(1, 4, 1),   // Soak incoming fire
+            AIBehaviorState::Retreat => PowerDistribution::preset(0, 2, 4), // Run for it
+            AIBehaviorState::Regroup => PowerDistribution::preset(1, 3, 2), // Fall back on the group
+        };
+        
+        if power.weapons != desired.weapons || power.shields != desired.shields || power.engines != desired.engines {
+            *power = desired;
+        }
+    }
+}
+
+/// AI target acquisition system - any ship of a hostile faction is fair game
 pub fn ai_target_acquisition_system(
     mut ai_query: Query<(&mut AIController, &Transform, &Faction), Without<Player>>,
-    target_query: Query<(Entity, &Transform, &Faction), With<Player>>,
+    target_query: Query<(Entity, &Transform, &Faction), With<Ship>>,
+    reputation: Res<crate::components::Reputation>,
 ) {
     for (mut ai, ai_transform, ai_faction) in ai_query.iter_mut() {
         // Find closest target of opposing faction
@@ -65,7 +200,7 @@ pub fn ai_target_acquisition_system(
         let mut closest_target = None;
         
         for (target_entity, target_transform, target_faction) in target_query.iter() {
-            if ai_faction != target_faction {
+            if reputation.is_hostile(*ai_faction, *target_faction) {
                 let distance = ai_transform.translation.distance(target_transform.translation);
                 if distance < closest_distance {
                     closest_distance = distance;
@@ -80,15 +215,30 @@ pub fn ai_target_acquisition_system(
 
 /// AI weapon selection system - chooses optimal weapon based on target's shield/hull status
 pub fn ai_weapon_selection_system(
-    mut ai_query: Query<(&AIController, &mut WeaponMount), Without<Player>>,
-    target_query: Query<(&Shield, &Health), With<Player>>,
+    mut ai_query: Query<(&AIController, &mut WeaponMount, Option<&MissileLock>, Option<&SubsystemStatus>), Without<Player>>,
+    target_query: Query<(&Shield, &Health), With<Ship>>,
 ) {
-    for (ai, mut weapon_mount) in ai_query.iter_mut() {
+    for (ai, mut weapon_mount, missile_lock, subsystems) in ai_query.iter_mut() {
         // Only switch weapons if AI has multiple weapons
         if weapon_mount.weapons.len() <= 1 {
             continue;
         }
         
+        // Missile lock on our target - switch to missiles while the launcher is ready
+        if let Some(lock) = missile_lock {
+            if lock.is_locked() && lock.target == ai.target {
+                let slot_count = weapon_mount.weapons.len();
+                if let Some(idx) = weapon_mount.weapons.iter().enumerate()
+                    .position(|(slot, w)| w.weapon_type == WeaponType::Missile
+                        && w.cooldown_timer <= 0.0
+                        && subsystems.map_or(true, |s| s.weapon_slot_online(slot, slot_count)))
+                {
+                    weapon_mount.current_weapon = idx;
+                    continue;
+                }
+            }
+        }
+        
         if let Some(target_entity) = ai.target {
             if let Ok((shield, health)) = target_query.get(target_entity) {
                 let shield_percent = shield.current / shield.max;
@@ -108,9 +258,10 @@ pub fn ai_weapon_selection_system(
                         .map(|(idx, _)| idx)
                         .unwrap_or(0)
                 } else if health_percent < 0.5 {
-                    // Target low on health - use missiles for finishing blow if available
+                    // Target low on health - use missiles for finishing blow if available (locked missiles only)
+                    let has_lock = missile_lock.map_or(false, |l| l.is_locked());
                     weapon_mount.weapons.iter().enumerate()
-                        .find(|(_, w)| w.weapon_type == WeaponType::Missile)
+                        .find(|(_, w)| w.weapon_type == WeaponType::Missile && has_lock)
                         .map(|(idx, _)| idx)
                         .unwrap_or_else(|| {
                             // Otherwise use best hull weapon
@@ -153,57 +304,78 @@ pub fn ai_combat_system(
         &mut WeaponMount,
         &mut Energy,
         &Faction,
-        &Enemy,
+        Option<&Enemy>,
+        &mut AISkill,
+        Option<&PowerDistribution>,
+        Option<&SubsystemStatus>,
+        Option<&crate::components::status_effects::StatusEffects>,
     ), Without<Player>>,
-    target_query: Query<(&Transform, &Velocity), With<Player>>,
+    player_query: Query<(Entity, &Transform, &Velocity), With<Player>>,
+    gates: Query<&GlobalTransform, With<JumpGate>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let dt = time.delta_seconds();
+    let rng = game_rng.stream(RngStream::Ai);
+    
+    // Targets can be the player or other AI ships, so snapshot every ship's motion up front
+    let ship_motion: std::collections::HashMap<Entity, (Vec3, Vec3)> = ai_query.iter()
+        .map(|(entity, _, _, transform, velocity, ..)| (entity, (transform.translation, velocity.0)))
+        .chain(player_query.iter().map(|(entity, transform, velocity)| (entity, (transform.translation, velocity.0))))
+        .collect();
     
-    for (entity, ai, ship, mut transform, mut velocity, mut angular_velocity, mut weapon_mount, mut energy, faction, enemy) in ai_query.iter_mut() {
+    for (entity, ai, ship, mut transform, mut velocity, mut angular_velocity, mut weapon_mount, mut energy, faction, enemy, mut skill, power, subsystems, status_effects) in ai_query.iter_mut() {
+        // Engine power scales top speed and boost
+        let max_speed = ship.max_speed * power.map_or(1.0, |p| p.multiplier(PowerSystem::Engines));
+        
         // Update weapon cooldowns
         for weapon in weapon_mount.weapons.iter_mut() {
             weapon.cooldown_timer = (weapon.cooldown_timer - dt).max(0.0);
         }
         
         // Determine if this is an elite AI (Frigate or Capital Ship)
-        let is_elite = matches!(enemy.enemy_type, EnemyType::Frigate | EnemyType::CapitalShip);
+        let is_elite = enemy.map_or(false, |e| matches!(e.enemy_type, EnemyType::Frigate | EnemyType::CapitalShip));
         
         if let Some(target_entity) = ai.target {
-            if let Ok((target_transform, target_velocity)) = target_query.get(target_entity) {
-                let to_target = target_transform.translation - transform.translation;
+            if let Some(&(target_position, target_velocity)) = ship_motion.get(&target_entity) {
+                let to_target = target_position - transform.translation;
                 let distance = to_target.length();
                 
-                // Lead targeting for elite AI
-                let target_pos = if is_elite {
-                    // Predict where target will be
-                    let time_to_hit = distance / 100.0; // Assume projectile speed
-                    target_transform.translation + target_velocity.0 * time_to_hit
-                } else {
-                    target_transform.translation
-                };
+                // Lead with the current weapon's real projectile speed - pilot skill decides
+                // how well the target's motion is read and how much of the lead is taken
+                skill.track(target_entity, target_velocity, dt);
+                let projectile_speed = weapon_mount.weapons.get(weapon_mount.current_weapon)
+                    .map_or(150.0, |w| w.projectile_speed);
+                let intercept = math::calculate_lead_point(
+                    transform.translation,
+                    target_position,
+                    skill.perceived_velocity,
+                    projectile_speed,
+                );
+                let target_pos = skill.aim_point(target_position, intercept);
                 
                 let to_target_predicted = target_pos - transform.translation;
                 let desired_direction = to_target_predicted.normalize();
                 
                 // AI Movement and rotation
                 match ai.state {
-                    AIBehaviorState::Pursue | AIBehaviorState::Attack => {
-                        // Rotate to face target
-                        let current_forward = transform.forward().as_vec3();
+                    AIBehaviorState::Pursue if ai.formation_slot.is_some() => {
+                        // Wingmen hold their formation slot while the squadron closes in
+                        let slot = ai.formation_slot.unwrap_or(transform.translation);
+                        turn_toward(&mut transform, desired_direction, ship.turn_rate * dt * 2.0);
                         
-                        // Smooth rotation using cross product
-                        let rotation_axis = current_forward.cross(desired_direction);
-                        let rotation_angle = current_forward.dot(desired_direction).acos();
+                        // Arrive at the slot rather than overshooting it
+                        let desired_velocity = steering::arrival(transform.translation, slot, max_speed * ship.boost_multiplier, 20.0);
+                        let steer = (desired_velocity - velocity.0).clamp_length_max(ship.acceleration * 1.5 * dt);
+                        velocity.0 += steer;
                         
-                        if rotation_axis.length() > 0.001 && !rotation_angle.is_nan() {
-                            let rotation_speed = ship.turn_rate * dt * 3.0; // Increased rotation speed
-                            let actual_rotation_angle = rotation_angle.min(rotation_speed);
-                            let rotation = Quat::from_axis_angle(
-                                rotation_axis.normalize(),
-                                actual_rotation_angle
-                            );
-                            transform.rotation = rotation * transform.rotation;
+                        if velocity.0.length() > max_speed * ship.boost_multiplier {
+                            velocity.0 = velocity.0.normalize() * max_speed * ship.boost_multiplier;
                         }
+                    }
+                    AIBehaviorState::Pursue | AIBehaviorState::Attack => {
+                        // Rotate to face target
+                        turn_toward(&mut transform, desired_direction, ship.turn_rate * dt * 3.0);
+                        let current_forward = transform.forward().as_vec3();
                         
                         // Elite AI: Tactical positioning (flanking)
                         if is_elite {
@@ -218,9 +390,15 @@ pub fn ai_combat_system(
                                 velocity.0 += flank_offset.normalize() * ship.acceleration * dt;
                             }
                         } else {
-                            // Basic AI: Simple aggressive pursuit
+                            // Basic AI: Simple aggressive pursuit - close in on where the target is heading
                             if distance > 35.0 {
-                                let thrust = current_forward * ship.acceleration * 1.5 * dt;
+                                let thrust_direction = if ai.state == AIBehaviorState::Pursue {
+                                    steering::pursuit(transform.translation, target_position, target_velocity, max_speed)
+                                        .normalize_or_zero()
+                                } else {
+                                    current_forward
+                                };
+                                let thrust = thrust_direction * ship.acceleration * 1.5 * dt;
                                 velocity.0 += thrust;
                             } else if distance < 15.0 {
                                 // Back away if too close
@@ -229,12 +407,47 @@ pub fn ai_combat_system(
                         }
                         
                         // Speed limit
-                        if velocity.0.length() > ship.max_speed {
-                            velocity.0 = velocity.0.normalize() * ship.max_speed;
+                        if velocity.0.length() > max_speed {
+                            velocity.0 = velocity.0.normalize() * max_speed;
                         }
                         
                         // Apply drag
-                        velocity.0 *= 0.97;
+                        velocity.0 *= math::damping(0.97, dt);
+                    }
+                    AIBehaviorState::Flank => {
+                        // Keep the guns on the target while circling to its side at our preferred range
+                        // (squadron wings circle opposite ways for a pincer)
+                        turn_toward(&mut transform, desired_direction, ship.turn_rate * dt * 3.0);
+                        
+                        let radial = to_target / distance.max(0.001);
+                        let mut tangent = radial.cross(Vec3::Y).normalize_or_zero() * ai.flank_side;
+                        if tangent == Vec3::ZERO {
+                            tangent = transform.right().as_vec3();
+                        }
+                        let range_error = (distance - ai.profile.preferred_range) / ai.profile.preferred_range;
+                        let flank_direction = (tangent + radial * range_error.clamp(-1.0, 1.0)).normalize_or_zero();
+                        velocity.0 += flank_direction * ship.acceleration * 1.2 * dt;
+                        
+                        if velocity.0.length() > max_speed {
+                            velocity.0 = velocity.0.normalize() * max_speed;
+                        }
+                        
+                        velocity.0 *= math::damping(0.97, dt);
+                    }
+                    AIBehaviorState::Regroup => {
+                        // Fall back toward the centre of nearby friendlies, easing in on arrival
+                        let desired_velocity = steering::arrival(transform.translation, ai.regroup_point, max_speed, 30.0);
+                        if desired_velocity.length() > 1.0 {
+                            turn_toward(&mut transform, desired_velocity.normalize(), ship.turn_rate * dt * 2.0);
+                        }
+                        let steer = (desired_velocity - velocity.0).clamp_length_max(ship.acceleration * dt);
+                        velocity.0 += steer;
+                        
+                        if velocity.0.length() > max_speed {
+                            velocity.0 = velocity.0.normalize() * max_speed;
+                        }
+                        
+                        velocity.0 *= math::damping(0.97, dt);
                     }
                     AIBehaviorState::Evade => {
                         // Elite AI: Strategic evasion
@@ -252,42 +465,55 @@ pub fn ai_combat_system(
                         } else {
                             // Basic AI: Random evasion
                             let evasion_direction = Vec3::new(
-                                (rand::random::<f32>() - 0.5) * 2.0,
-                                (rand::random::<f32>() - 0.5) * 2.0,
-                                (rand::random::<f32>() - 0.5) * 2.0,
+                                (rng.gen::<f32>() - 0.5) * 2.0,
+                                (rng.gen::<f32>() - 0.5) * 2.0,
+                                (rng.gen::<f32>() - 0.5) * 2.0,
                             ).normalize();
                             
                             velocity.0 += evasion_direction * ship.acceleration * dt * 1.2;
                             
                             // Random roll
-                            angular_velocity.0.z = ship.turn_rate * (rand::random::<f32>() - 0.5) * dt;
+                            angular_velocity.0.z = ship.turn_rate * (rng.gen::<f32>() - 0.5) * dt;
                         }
                         
-                        if velocity.0.length() > ship.max_speed * ship.boost_multiplier {
-                            velocity.0 = velocity.0.normalize() * ship.max_speed * ship.boost_multiplier;
+                        if velocity.0.length() > max_speed * ship.boost_multiplier {
+                            velocity.0 = velocity.0.normalize() * max_speed * ship.boost_multiplier;
                         }
                         
-                        velocity.0 *= 0.95;
+                        velocity.0 *= math::damping(0.95, dt);
                     }
                     AIBehaviorState::Retreat => {
-                        // Run away from target
-                        let escape_direction = -to_target.normalize();
+                        // Enemies run for the nearest jump gate - wingmen (and anyone in a gateless system)
+                        // just get away from the target
+                        let nearest_gate = gates.iter()
+                            .filter(|_| enemy.is_some())
+                            .map(|gate| gate.translation())
+                            .min_by(|a, b| {
+                                a.distance_squared(transform.translation)
+                                    .partial_cmp(&b.distance_squared(transform.translation))
+                                    .unwrap()
+                            });
+                        let escape_direction = match nearest_gate {
+                            Some(gate) => (gate - transform.translation).normalize_or_zero(),
+                            None => -to_target.normalize(),
+                        };
+                        turn_toward(&mut transform, escape_direction, ship.turn_rate * dt * 2.0);
                         velocity.0 += escape_direction * ship.acceleration * ship.boost_multiplier * dt;
                         
-                        if velocity.0.length() > ship.max_speed * ship.boost_multiplier {
-                            velocity.0 = velocity.0.normalize() * ship.max_speed * ship.boost_multiplier;
+                        if velocity.0.length() > max_speed * ship.boost_multiplier {
+                            velocity.0 = velocity.0.normalize() * max_speed * ship.boost_multiplier;
                         }
                         
-                        velocity.0 *= 0.99;
+                        velocity.0 *= math::damping(0.99, dt);
                     }
                     AIBehaviorState::Patrol => {
                         // Slow down and drift
-                        velocity.0 *= 0.95;
+                        velocity.0 *= math::damping(0.95, dt);
                     }
                 }
                 
                 match ai.state {
-                    AIBehaviorState::Attack => {
+                    AIBehaviorState::Attack | AIBehaviorState::Flank => {
                         // Fire at target if in range and facing them
                         // Elite AI: Longer range and better accuracy threshold
                         let max_range = if is_elite { 120.0 } else { 100.0 };
@@ -298,19 +524,23 @@ pub fn ai_combat_system(
                             let forward = transform.forward();
                             let angle_to_target = forward.as_vec3().dot(to_target_predicted.normalize());
                             
-                            if angle_to_target > accuracy_threshold {
+                            if angle_to_target > accuracy_threshold && skill.ready_to_fire() {
                                 // Fire weapon
                                 let current_weapon_idx = weapon_mount.current_weapon;
-                                if let Some(weapon) = weapon_mount.weapons.get_mut(current_weapon_idx) {
+                                let slot_online = subsystems.map_or(true, |s| s.weapon_slot_online(current_weapon_idx, weapon_mount.weapons.len()))
+                                    && !status_effects.map_or(false, |s| s.weapons_disabled());
+                                if let Some(weapon) = weapon_mount.weapons.get_mut(current_weapon_idx).filter(|_| slot_online) {
                                     if weapon.cooldown_timer <= 0.0 && energy.current >= weapon.energy_cost {
                                         weapon.cooldown_timer = 1.0 / weapon.fire_rate;
                                         energy.current -= weapon.energy_cost;
+                                        skill.register_shot();
                                         
                                         let forward = transform.forward();
                                         let projectile_pos = transform.translation + forward.as_vec3() * 3.0;
-                                        let projectile_direction = forward.as_vec3().normalize();
+                                        // Shots scatter inside the pilot's aim error cone
+                                        let projectile_direction = scatter_in_cone(forward.as_vec3().normalize(), skill.aim_error, rng);
                                         // Projectiles do NOT inherit momentum - they travel at fixed speed relative to world
-                                        let projectile_velocity = forward.as_vec3() * weapon.projectile_speed;
+                                        let projectile_velocity = projectile_direction * weapon.projectile_speed;
                                         
                                         // Calculate laser color based on damage (enemies use base damage)
                                         let base_damage = weapon.damage;
@@ -407,6 +637,7 @@ pub fn ai_combat_system(
                                                 homing_strength,
                                                 homing_target: None,
                                                 initial_direction: projectile_direction,
+                                                is_critical: false,
                                             },
                                             Velocity(projectile_velocity),
                                             *faction,
@@ -423,3 +654,161 @@ pub fn ai_combat_system(
     }
 }
 
+/// Random direction within `half_angle` radians of `direction`
+fn scatter_in_cone(direction: Vec3, half_angle: f32, rng: &mut impl Rng) -> Vec3 {
+    if half_angle <= 0.0 {
+        return direction;
+    }
+    let spin = Quat::from_axis_angle(direction, rng.gen::<f32>() * std::f32::consts::TAU);
+    let tilt_axis = spin * direction.any_orthonormal_vector();
+    let tilt = half_angle * rng.gen::<f32>().sqrt();
+    (Quat::from_axis_angle(tilt_axis, tilt) * direction).normalize()
+}
+
+/// Rotate toward a direction, turning at most `max_angle` radians
+fn turn_toward(transform: &mut Transform, direction: Vec3, max_angle: f32) {
+    let current_forward = transform.forward().as_vec3();
+    
+    // Smooth rotation using cross product
+    let rotation_axis = current_forward.cross(direction);
+    let rotation_angle = current_forward.dot(direction).clamp(-1.0, 1.0).acos();
+    
+    if rotation_axis.length() > 0.001 && !rotation_angle.is_nan() {
+        let rotation = Quat::from_axis_angle(rotation_axis.normalize(), rotation_angle.min(max_angle));
+        transform.rotation = rotation * transform.rotation;
+    }
+}
+
+/// AI steering - blends separation from nearby ships and avoidance of planets/gates into
+/// whatever the behavior asked for, then guarantees nothing flies into an obstacle
+pub fn ai_steering_system(
+    time: Res<Time>,
+    mut ai_query: Query<(Entity, &mut Transform, &mut Velocity, &Ship), (With<AIController>, Without<Player>)>,
+    other_ships: Query<&Transform, (With<Ship>, Without<AIController>)>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+) {
+    let dt = time.delta_seconds();
+    let separation_radius = 12.0;
+    let ship_clearance = 6.0;
+    let look_ahead_time = 2.0;
+    
+    let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+        .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+        .collect();
+    let ship_positions: Vec<(Entity, Vec3)> = ai_query.iter()
+        .map(|(entity, transform, _, _)| (entity, transform.translation))
+        .chain(other_ships.iter().map(|transform| (Entity::PLACEHOLDER, transform.translation)))
+        .collect();
+    
+    for (entity, mut transform, mut velocity, ship) in ai_query.iter_mut() {
+        let position = transform.translation;
+        
+        let separation = steering::separation(
+            position,
+            ship_positions.iter().filter(|(other, _)| *other != entity).map(|(_, p)| *p),
+            separation_radius,
+        );
+        let avoidance = steering::obstacle_avoidance(position, velocity.0, &obstacle_volumes, ship_clearance, look_ahead_time);
+        
+        velocity.0 += (separation * 1.5 + avoidance * 4.0) * ship.acceleration * dt;
+        
+        let max_speed = ship.max_speed * ship.boost_multiplier;
+        if velocity.0.length() > max_speed {
+            velocity.0 = velocity.0.normalize() * max_speed;
+        }
+        
+        let mut position = transform.translation;
+        steering::enforce_clearance(&mut position, &mut velocity.0, &obstacle_volumes, ship_clearance, dt);
+        transform.translation = position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::transform::systems::{propagate_transforms, sync_simple_transforms};
+    use crate::components::galaxy::Planet;
+    use crate::systems::movement;
+
+    const PLANET_RADIUS: f32 = 30.0;
+
+    /// Where a test ship is headed
+    #[derive(Component)]
+    struct Destination(Vec3);
+
+    /// Stand-in for the AI controller: head straight for the destination, whatever is in the way
+    fn seek_system(
+        time: Res<Time>,
+        mut ships: Query<(&Transform, &mut Velocity, &Ship, &Destination)>,
+    ) {
+        let dt = time.delta_seconds();
+        for (transform, mut velocity, ship, destination) in ships.iter_mut() {
+            let desired = steering::arrival(transform.translation, destination.0, ship.max_speed, 30.0);
+            let change = (desired - velocity.0).clamp_length_max(ship.acceleration * dt);
+            velocity.0 += change;
+        }
+    }
+
+    fn check_clearance_system(
+        ships: Query<&Transform, With<AIController>>,
+        planets: Query<&Transform, With<Planet>>,
+    ) {
+        let planet = planets.single();
+        for transform in ships.iter() {
+            let distance = transform.translation.distance(planet.translation);
+            assert!(distance >= PLANET_RADIUS, "ship entered the planet ({:.2} < {:.2})", distance, PLANET_RADIUS);
+        }
+    }
+
+    /// Fly a handful of AI ships straight at a planet through the real steering and movement
+    /// systems on a fixed step - nobody may ever end up inside it
+    #[test]
+    fn ai_ships_route_around_planet() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / 60.0)))
+            .add_systems(FixedUpdate, (
+                seek_system,
+                ai_steering_system,
+                movement::apply_velocity_system,
+                sync_simple_transforms,
+                propagate_transforms,
+                check_clearance_system,
+            ).chain());
+
+        app.world_mut().spawn((
+            SpatialBundle::default(),
+            Planet { orbit_radius: 0.0, orbit_speed: 0.0, orbit_offset: 0.0, center: Vec3::ZERO },
+            Obstacle { radius: PLANET_RADIUS },
+        ));
+
+        // Start on one side, aimed straight through the planet (or close to it) at the other
+        let mut ships = Vec::new();
+        for (i, offset) in [-20.0, -5.0, 0.0, 0.0, 5.0, 20.0].into_iter().enumerate() {
+            let y = if i == 3 { 2.0 } else { 0.0 };
+            let destination = Vec3::new(120.0, -y, -offset);
+            ships.push(app.world_mut().spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(-120.0, y, offset)),
+                Velocity(Vec3::X * 30.0),
+                Ship::fighter(),
+                AIController::new(),
+                Destination(destination),
+            )).id());
+        }
+
+        for _ in 0..30 * 60 {
+            app.update();
+        }
+
+        // And they still get where they were going
+        for ship in ships {
+            let world = app.world();
+            let position = world.get::<Transform>(ship).unwrap().translation;
+            let destination = world.get::<Destination>(ship).unwrap().0;
+            assert!(position.distance(destination) < 10.0, "ship stalled at {:?}", position);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+use crate::components::ship::*;
+use crate::components::combat::*;
+use crate::components::ai::*;
+use crate::components::abilities::{AbilityController, SpecialAbility};
+use crate::components::boss::{Boss, BossDefinition};
+use crate::components::camera::CameraController;
+use crate::components::carrier::Carrier;
+use crate::components::galaxy::{Obstacle, SystemId};
+use crate::components::travel::JumpGate;
+use crate::components::status_effects::StatusEffects;
+use crate::components::weapon_items::base_weapon;
+use crate::resources::{Galaxy, GameRng, RngStream};
+use crate::systems::{abilities, spawning};
+use crate::utils::{ship_builder, spawn_placement};
+
+/// Seconds in a guarded system before its boss shows up
+const BOSS_ARRIVAL_DELAY: f32 = 45.0;
+/// How far from the player the boss arrives (min, max)
+const BOSS_ARRIVAL_DISTANCE: (f32, f32) = (200.0, 260.0);
+
+/// Tracks time spent in the current system so the boss arrives after the opening skirmishes
+#[derive(Resource, Default)]
+pub struct BossEncounter {
+    pub system_id: Option<SystemId>,
+    pub timer: f32,
+}
+
+/// Bring in the current system's boss once the player has been here a while (and it's still alive)
+pub fn boss_spawn_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    galaxy: Option<Res<Galaxy>>,
+    mut encounter: ResMut<BossEncounter>,
+    bosses: Query<(), With<Boss>>,
+    player_query: Query<&Transform, With<Player>>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+    gates: Query<&GlobalTransform, With<JumpGate>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<CameraController>>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let Some(system) = galaxy.as_ref().and_then(|g| g.current_system()) else {
+        return;
+    };
+
+    // New system - restart the clock
+    if encounter.system_id != Some(system.id) {
+        encounter.system_id = Some(system.id);
+        encounter.timer = 0.0;
+    }
+
+    let Some(definition) = system.boss.as_ref() else {
+        return;
+    };
+    if system.boss_defeated || !bosses.is_empty() {
+        return;
+    }
+
+    encounter.timer += time.delta_seconds();
+    if encounter.timer < BOSS_ARRIVAL_DELAY {
+        return;
+    }
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    // Same arrival rules as any other wave, just further out
+    let player_pos = player_transform.translation;
+    let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+        .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+        .collect();
+    let gate_positions: Vec<Vec3> = gates.iter().map(|t| t.translation()).collect();
+    let camera = camera_query.get_single().ok();
+    let spawn_pos = spawn_placement::find_spawn_position(
+        player_pos,
+        BOSS_ARRIVAL_DISTANCE.0,
+        BOSS_ARRIVAL_DISTANCE.1,
+        &obstacle_volumes,
+        &gate_positions,
+        |position| camera.map_or(false, |(camera, transform)| spawn_placement::in_view(camera, transform, position)),
+        game_rng.stream(RngStream::Spawning),
+    );
+    let boss = spawn_boss(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        definition,
+        system.id,
+        system.difficulty,
+        spawn_pos,
+        player_pos,
+    );
+    spawning::warp_in(&mut commands, &mut meshes, &mut materials, boss, spawn_pos, player_pos - spawn_pos);
+}
+
+/// Spawn a boss flagship from its definition, starting in its opening phase
+pub fn spawn_boss(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    definition: &BossDefinition,
+    system_id: SystemId,
+    difficulty: u32,
+    spawn_pos: Vec3,
+    look_at: Vec3,
+) -> Entity {
+    let opening_weapons = definition.phases.first()
+        .map(|phase| phase.weapons.iter().map(|w| base_weapon(*w)).collect())
+        .unwrap_or_else(|| vec![Weapon::laser()]);
+
+    let boss = commands.spawn((
+        SpatialBundle {
+            transform: Transform::from_translation(spawn_pos).looking_at(look_at, Vec3::Y),
+            ..default()
+        },
+        Ship {
+            max_speed: 24.0,
+            ..Ship::capital_ship()
+        },
+        AIController::capital_ship(),
+        AISkill::for_enemy(EnemyType::CapitalShip, difficulty),
+        Health { current: definition.health, max: definition.health },
+        Shield {
+            current: definition.shield,
+            max: definition.shield,
+            recharge_rate: 25.0,
+            recharge_delay: 5.0,
+            time_since_last_hit: 10.0,
+        },
+        WeaponMount {
+            weapons: opening_weapons,
+            current_weapon: 0,
+        },
+        Energy {
+            current: 200.0,
+            max: 200.0,
+            recharge_rate: 30.0,
+        },
+        Velocity(Vec3::ZERO),
+        AngularVelocity(Vec3::ZERO),
+        Enemy { enemy_type: EnemyType::CapitalShip },
+        Faction::Enemy,
+        StatusEffects::default(),
+        Boss {
+            name: definition.name.clone(),
+            system_id,
+            phases: definition.phases.clone(),
+            phase: 0,
+        },
+    )).id();
+
+    // Hull turrets stay, but the hangar is sealed - escorts come from the fight's phases instead
+    commands.entity(boss).insert((
+        PowerDistribution::default(),
+        MissileLock::new(3.0),
+        Countermeasures::new(6),
+        AbilityController::new(),
+        AutoTurret::carrier(),
+        Carrier {
+            hangar_online: false,
+            ..Carrier::new(difficulty)
+        },
+    ));
+
+    let [r, g, b] = definition.color;
+    ship_builder::build_ship(
+        commands,
+        meshes,
+        materials,
+        ShipType::CapitalShip,
+        boss,
+        Color::srgb(r, g, b),
+    );
+
+    println!("[Boss] {} has entered the system!", definition.name);
+    boss
+}
+
+/// Advance bosses through their phases as their hull drops - new weapons, escorts and abilities
+pub fn boss_phase_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    galaxy: Option<Res<Galaxy>>,
+    mut bosses: Query<(&Transform, &Health, &Faction, &mut Boss, &mut WeaponMount, &mut AbilityController, Option<&mut StatusEffects>)>,
+) {
+    let difficulty = galaxy.as_ref()
+        .and_then(|g| g.current_system())
+        .map_or(1, |s| s.difficulty);
+
+    for (transform, health, faction, mut boss, mut weapon_mount, mut controller, status_effects) in bosses.iter_mut() {
+        let hull_fraction = (health.current / health.max).clamp(0.0, 1.0);
+        let Some(next) = boss.pending_phase(hull_fraction) else {
+            continue;
+        };
+        boss.phase = next;
+        let phase = boss.phases[next].clone();
+        println!("[Boss] {} enters phase {}/{}", boss.name, next + 1, boss.phases.len());
+
+        // Swap to the phase's loadout
+        if !phase.weapons.is_empty() {
+            weapon_mount.weapons = phase.weapons.iter().map(|w| base_weapon(*w)).collect();
+            weapon_mount.current_weapon = 0;
+        }
+
+        // Unlock the phase's abilities off cooldown, and dig in while the escorts launch
+        for ability in &phase.abilities {
+            controller.unlock_ability(*ability);
+            controller.cooldowns.retain(|(a, _)| a != ability);
+        }
+        if controller.activate(SpecialAbility::FortressMode) {
+            abilities::activate_fortress_mode(&mut controller, status_effects.map(|s| s.into_inner()));
+            abilities::spawn_ability_visual(&mut commands, transform.translation, SpecialAbility::FortressMode, &mut meshes, &mut materials);
+        }
+
+        // Escorts launch from around the flagship
+        let difficulty_scale = 1.0 + difficulty as f32 * 0.1;
+        let count = phase.adds.len();
+        for (index, enemy_type) in phase.adds.iter().enumerate() {
+            let angle = index as f32 / count as f32 * std::f32::consts::TAU;
+            let offset = transform.rotation * Vec3::new(angle.cos() * 20.0, 0.0, angle.sin() * 20.0);
+            let position = transform.translation + offset;
+            spawning::spawn_enemy_ship(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                *enemy_type,
+                *faction,
+                position,
+                position + offset,
+                difficulty,
+                difficulty_scale,
+            );
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::ai::{AIController, Enemy, EnemyType};
+use crate::components::carrier::Carrier;
+use crate::components::combat::*;
+use crate::components::ship::*;
+use crate::components::squadron::{FormationType, Squadron, SquadronMember};
+use crate::components::status_effects::StatusEffects;
+use crate::resources::{GameRng, RngStream};
+
+/// Carrier turrets fire at a fraction of their weapon's normal rate - four mounts add up fast
+const CARRIER_TURRET_RATE_SCALE: f32 = 0.25;
+/// Extra scatter on carrier turret shots (radians)
+const CARRIER_TURRET_SPREAD: f32 = 0.03;
+
+/// Track which carrier subsystems survive - destroyed turret mounts go silent and a
+/// destroyed hangar bay stops fighter launches
+pub fn carrier_subsystem_system(
+    mut carriers: Query<(Entity, &mut Carrier, &mut AutoTurret)>,
+    pieces: Query<(&Transform, &ShipPiece), With<SubsystemHealth>>,
+) {
+    for (carrier_entity, mut carrier, mut turret) in carriers.iter_mut() {
+        let mut hangar_alive = false;
+        let mut turret_mounts: Vec<Vec3> = Vec::new();
+        for (piece_transform, piece) in pieces.iter() {
+            if piece.parent_ship != carrier_entity {
+                continue;
+            }
+            match piece.piece_type {
+                ShipPieceType::Hangar => hangar_alive = true,
+                ShipPieceType::Turret => turret_mounts.push(piece_transform.translation),
+                _ => {}
+            }
+        }
+
+        for hardpoint in turret.hardpoints.iter_mut() {
+            if hardpoint.destroyed {
+                continue;
+            }
+            if !turret_mounts.iter().any(|mount| mount.distance(hardpoint.offset) < 0.1) {
+                hardpoint.destroyed = true;
+                hardpoint.current_target = None;
+                println!("[Carrier] {} turret destroyed", hardpoint.name);
+            }
+        }
+        carrier.turrets_online = turret.hardpoints.iter().filter(|h| !h.destroyed).count();
+
+        if carrier.hangar_online && !hangar_alive {
+            carrier.hangar_online = false;
+            println!("[Carrier] Hangar bay destroyed - fighter launches halted");
+        }
+    }
+}
+
+/// Carrier turret battery - each surviving hardpoint tracks the nearest hostile in its arc and fires on it
+pub fn carrier_turret_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut carriers: Query<(Entity, &Transform, &mut AutoTurret, &Faction, Option<&StatusEffects>), With<Carrier>>,
+    targets: Query<(Entity, &Transform, &Velocity, &Shield, &Faction), (With<Ship>, Without<Carrier>)>,
+    reputation: Res<crate::components::Reputation>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let dt = time.delta_seconds();
+    let rng = game_rng.stream(RngStream::Combat);
+
+    for (carrier_entity, ship_transform, mut turret, faction, status_effects) in carriers.iter_mut() {
+        let weapons_jammed = status_effects.map_or(false, |s| s.weapons_disabled());
+        let max_lock_range = turret.max_lock_range;
+        let max_fire_range = turret.max_fire_range;
+        let max_turn = turret.turn_rate * dt;
+        let fire_cone_angle = turret.fire_cone_angle;
+        let AutoTurret { hardpoints, weapons, .. } = &mut *turret;
+
+        for hardpoint in hardpoints.iter_mut() {
+            hardpoint.firing_cooldown = (hardpoint.firing_cooldown - dt).max(0.0);
+            if hardpoint.destroyed {
+                continue;
+            }
+
+            // Nearest hostile inside this mount's arc
+            let mount_pos = hardpoint.world_position(ship_transform);
+            let target = targets.iter()
+                .filter(|(_, t, _, _, f)| reputation.is_hostile(*faction, **f) && hardpoint.in_arc(ship_transform, t.translation))
+                .map(|(e, t, v, s, _)| (e, t, v, s, mount_pos.distance(t.translation)))
+                .filter(|(_, _, _, _, d)| *d <= max_lock_range)
+                .min_by(|a, b| a.4.partial_cmp(&b.4).unwrap());
+
+            let Some((target_entity, target_transform, target_velocity, target_shield, distance)) = target else {
+                hardpoint.current_target = None;
+                continue;
+            };
+            hardpoint.current_target = Some(target_entity);
+
+            let shield_percentage = if target_shield.max > 0.0 { target_shield.current / target_shield.max } else { 0.0 };
+            hardpoint.current_weapon = crate::systems::combat::select_best_turret_weapon(weapons, shield_percentage, distance);
+            let Some(weapon) = weapons.get(hardpoint.current_weapon) else {
+                continue;
+            };
+
+            // Lead the target and swing the barrel toward the intercept point
+            let intercept = crate::utils::math::calculate_lead_point(mount_pos, target_transform.translation, target_velocity.0, weapon.projectile_speed);
+            let to_intercept = hardpoint.clamp_to_arc(ship_transform, (intercept - mount_pos).normalize_or_zero());
+            let barrel = (hardpoint.current_rotation * Vec3::Z).normalize();
+            let angle = barrel.dot(to_intercept).clamp(-1.0, 1.0).acos();
+            let axis = barrel.cross(to_intercept);
+            if angle > 0.01 && axis.length() > 0.001 {
+                let rotation = Quat::from_axis_angle(axis.normalize(), angle.min(max_turn));
+                hardpoint.current_rotation = (rotation * hardpoint.current_rotation).normalize();
+            }
+
+            if weapons_jammed || distance > max_fire_range || angle > fire_cone_angle || hardpoint.firing_cooldown > 0.0 {
+                continue;
+            }
+
+            let spread = Quat::from_euler(
+                EulerRot::XYZ,
+                (rng.gen::<f32>() - 0.5) * (weapon.spread + CARRIER_TURRET_SPREAD),
+                (rng.gen::<f32>() - 0.5) * (weapon.spread + CARRIER_TURRET_SPREAD),
+                0.0,
+            );
+            let direction = (spread * (hardpoint.current_rotation * Vec3::Z)).normalize();
+            fire_carrier_turret_shot(&mut commands, &mut meshes, &mut materials, carrier_entity, *faction, mount_pos, direction, weapon);
+            hardpoint.firing_cooldown = 1.0 / (weapon.fire_rate * CARRIER_TURRET_RATE_SCALE);
+        }
+    }
+}
+
+/// Spawn a single carrier turret projectile (carrier turrets run off the ship's own reactor tap - no energy or ammo)
+fn fire_carrier_turret_shot(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    owner: Entity,
+    faction: Faction,
+    position: Vec3,
+    direction: Vec3,
+    weapon: &Weapon,
+) {
+    let color = Color::srgb(1.0, 0.35, 0.1);
+    let area_damage = if weapon.weapon_type == WeaponType::FlakCannon { 5.0 } else { 0.0 };
+
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Capsule3d::new(0.08, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive: LinearRgba::from(color) * 4.0,
+                ..default()
+            }),
+            transform: Transform::from_translation(position + direction * 1.5)
+                .with_rotation(Quat::from_rotation_arc(Vec3::Y, direction)),
+            ..default()
+        },
+        Projectile {
+            damage: weapon.damage,
+            lifetime: 3.0,
+            owner,
+            weapon_type: weapon.weapon_type,
+            shield_damage_multiplier: weapon.shield_damage_multiplier,
+            hull_damage_multiplier: weapon.hull_damage_multiplier,
+            piercing: false,
+            area_damage,
+            homing_strength: 0.0,
+            homing_target: None,
+            initial_direction: direction,
+            is_critical: false,
+        },
+        Velocity(direction * weapon.projectile_speed),
+        faction,
+    ));
+}
+
+/// Carrier hangar - launches wedge wings of fighters at intervals, up to the carrier's cap
+pub fn carrier_launch_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut carriers: Query<(Entity, &Transform, &mut Carrier, &AIController, &Faction)>,
+    enemies: Query<Entity, With<Enemy>>,
+) {
+    let enemy_count = enemies.iter().count();
+    let mut launched_this_frame = 0;
+
+    for (carrier_entity, transform, mut carrier, ai, faction) in carriers.iter_mut() {
+        carrier.launched.retain(|fighter| enemies.contains(*fighter));
+
+        if !carrier.hangar_online || ai.target.is_none() {
+            continue;
+        }
+
+        carrier.launch_timer -= time.delta_seconds();
+        if carrier.launch_timer > 0.0 {
+            continue;
+        }
+        carrier.launch_timer = carrier.launch_interval;
+
+        // Respect both the carrier's own cap and the global enemy limit
+        let room = 15usize.saturating_sub(enemy_count + launched_this_frame);
+        let wing_size = carrier.wing_size
+            .min(carrier.max_fighters.saturating_sub(carrier.launched.len()))
+            .min(room);
+        if wing_size == 0 {
+            continue;
+        }
+
+        let difficulty_scale = 1.0 + carrier.difficulty as f32 * 0.1;
+        let bay = transform.translation + transform.rotation * Carrier::HANGAR_OFFSET;
+        let forward = transform.forward().as_vec3();
+        let rotation = Transform::from_translation(bay).looking_at(bay + forward, Vec3::Y).rotation;
+
+        let mut wing = Vec::with_capacity(wing_size);
+        for index in 0..wing_size {
+            // Leader drops out first, wingmen launch straight into their wedge slots
+            let slot = if index == 0 { Vec3::ZERO } else { FormationType::Wedge.slot_offset(index - 1, wing_size - 1) };
+            let position = bay + Vec3::NEG_Y * 3.0 + rotation * slot;
+            let fighter = crate::systems::spawning::spawn_enemy_ship(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                EnemyType::Fighter,
+                *faction,
+                position,
+                position + forward * 50.0,
+                carrier.difficulty,
+                difficulty_scale,
+            );
+            commands.entity(fighter).insert(crate::components::CarrierFighter { carrier: carrier_entity });
+            if index > 0 {
+                commands.entity(fighter).insert(SquadronMember {
+                    leader: wing[0],
+                    slot,
+                    morale: 1.0,
+                });
+            }
+            wing.push(fighter);
+        }
+
+        let leader = wing[0];
+        commands.entity(leader).insert(Squadron::new(FormationType::Wedge, wing[1..].to_vec()));
+        carrier.launched.extend(wing.iter().copied());
+        launched_this_frame += wing_size;
+        println!("[Carrier] Launched a wing of {} fighters ({}/{} deployed)", wing_size, carrier.launched.len(), carrier.max_fighters);
+    }
+}
+
+/// Fighters whose carrier goes down lose their nerve - the squad's morale takes the hit
+pub fn carrier_fighter_orphan_system(
+    mut commands: Commands,
+    carriers: Query<(), With<Carrier>>,
+    mut fighters: Query<(Entity, &crate::components::CarrierFighter, Option<&mut Squadron>, Option<&mut SquadronMember>)>,
+) {
+    for (entity, fighter, squadron, member) in fighters.iter_mut() {
+        if carriers.contains(fighter.carrier) {
+            continue;
+        }
+
+        if let Some(mut squadron) = squadron {
+            squadron.morale = (squadron.morale - Squadron::LEADER_LOSS_MORALE).max(0.0);
+        }
+        if let Some(mut member) = member {
+            member.morale = (member.morale - Squadron::LEADER_LOSS_MORALE).max(0.0);
+        }
+        commands.entity(entity).remove::<crate::components::CarrierFighter>();
+    }
+}
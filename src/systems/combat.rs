@@ -1,185 +1,323 @@
 use bevy::prelude::*;
+use rand::Rng;
 use crate::components::ship::*;
 use crate::components::combat::*;
 use crate::components::ai::Enemy;
-use crate::resources::GameState;
+use crate::resources::{GameRng, GameState, PlayerInput, RngStream};
 
-/// Turret toggle system - handles enabling/disabling turret with K key
+/// Turret toggle system - K enables/disables the turret, P switches mode, O cycles targeting policy
 pub fn autofire_toggle_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: Res<PlayerInput>,
     mut query: Query<&mut AutoTurret, With<Player>>,
 ) {
+    // Switch between offensive and point-defense mode with P
+    if input.just_pressed(KeyCode::KeyP) {
+        for mut turret in query.iter_mut() {
+            turret.mode = match turret.mode {
+                TurretMode::Offensive => TurretMode::PointDefense,
+                TurretMode::PointDefense => TurretMode::Offensive,
+            };
+            for hardpoint in turret.hardpoints.iter_mut() {
+                hardpoint.current_target = None;
+            }
+            println!("[Turret] Mode: {:?}", turret.mode);
+        }
+    }
+    
+    // Cycle targeting policy with O
+    if input.just_pressed(KeyCode::KeyO) {
+        for mut turret in query.iter_mut() {
+            turret.policy = turret.policy.next();
+            for hardpoint in turret.hardpoints.iter_mut() {
+                hardpoint.current_target = None;
+            }
+            println!("[Turret] Targeting policy: {:?}", turret.policy);
+        }
+    }
+    
     // Toggle turret on/off with K
-    if keyboard.just_pressed(KeyCode::KeyK) {
+    if input.just_pressed(KeyCode::KeyK) {
         for mut turret in query.iter_mut() {
             turret.enabled = !turret.enabled;
             if turret.enabled {
-                println!("[Turret] AUTO-TURRET ENABLED - Smart weapon selection active");
+                println!("[Turret] AUTO-TURRET ENABLED - Smart weapon selection active ({} hardpoints)", turret.hardpoints.len());
             } else {
                 println!("[Turret] AUTO-TURRET DISABLED");
-                turret.current_target = None;
+                for hardpoint in turret.hardpoints.iter_mut() {
+                    hardpoint.current_target = None;
+                }
             }
         }
     }
 }
 
-/// Turret targeting system - finds and tracks nearest enemy
+/// Score an enemy ship under a turret targeting policy - higher is better
+fn turret_target_score(
+    policy: TurretTargetPolicy,
+    distance: f32,
+    max_range: f32,
+    health: &Health,
+    shield: &Shield,
+    enemy: &Enemy,
+    attacking_player: bool,
+) -> f32 {
+    match policy {
+        TurretTargetPolicy::Nearest | TurretTargetPolicy::PlayerTarget => -distance,
+        TurretTargetPolicy::LowestHealth => -(health.current + shield.current),
+        TurretTargetPolicy::HighestThreat => {
+            let size_threat = match enemy.enemy_type {
+                crate::components::ai::EnemyType::Fighter => 1.0,
+                crate::components::ai::EnemyType::Corvette => 2.0,
+                crate::components::ai::EnemyType::Frigate => 3.0,
+                crate::components::ai::EnemyType::CapitalShip => 4.0,
+            };
+            let aggression = if attacking_player { 2.0 } else { 1.0 };
+            // Closer ships are more dangerous, but size and aggression dominate
+            size_threat * aggression * (1.5 - distance / max_range)
+        }
+    }
+}
+
+/// Turret targeting system - each hardpoint picks a target inside its own arc according to the turret policy
+/// In point-defense mode, incoming interceptable projectiles take priority
 pub fn autofire_targeting_system(
-    mut player_query: Query<(&Transform, &mut AutoTurret), With<Player>>,
-    enemy_query: Query<(Entity, &Transform, &Health, &Enemy), (With<Enemy>, Without<Player>)>,
+    mut player_query: Query<(Entity, &Transform, &mut AutoTurret, Option<&MissileLock>), With<Player>>,
+    enemy_query: Query<(Entity, &Transform, &Health, &Shield, &Enemy, Option<&crate::components::ai::AIController>, &Faction), Without<Player>>,
+    threat_query: Query<(Entity, &Transform, &Velocity, &Faction), (With<ProjectileHealth>, Without<Player>)>,
+    reputation: Res<crate::components::Reputation>,
 ) {
-    for (player_transform, mut turret) in player_query.iter_mut() {
+    for (player_entity, player_transform, mut turret, missile_lock) in player_query.iter_mut() {
         if !turret.enabled {
-            turret.current_target = None;
+            for hardpoint in turret.hardpoints.iter_mut() {
+                hardpoint.current_target = None;
+            }
             continue;
         }
         
-        // Find closest enemy within lock range
-        let mut closest_distance = turret.max_lock_range;
-        let mut closest_enemy = None;
+        let max_lock_range = turret.max_lock_range;
+        let point_defense_range = turret.point_defense_range;
+        let mode = turret.mode;
+        let policy = turret.policy;
         
-        for (enemy_entity, enemy_transform, _health, _enemy_type) in enemy_query.iter() {
-            let distance = player_transform.translation.distance(enemy_transform.translation);
-            if distance < closest_distance {
-                closest_distance = distance;
-                closest_enemy = Some(enemy_entity);
-            }
-        }
+        // The player's target is whatever the missile lock is on (or working toward). The lock
+        // only runs with missiles selected, so otherwise it's the hostile nearest the crosshair.
+        let player_target = missile_lock.and_then(|lock| lock.target.or(lock.candidate)).or_else(|| {
+            let forward = player_transform.forward().as_vec3();
+            let cone_angle = missile_lock.map_or(12.0_f32.to_radians(), |lock| lock.cone_angle);
+            enemy_query.iter()
+                .filter(|(_, _, _, _, _, _, faction)| reputation.is_hostile(Faction::Player, **faction))
+                .filter_map(|(entity, transform, ..)| {
+                    let to_target = transform.translation - player_transform.translation;
+                    let distance = to_target.length();
+                    let angle = forward.angle_between(to_target / distance.max(0.001));
+                    (distance <= max_lock_range && angle < cone_angle).then_some((entity, angle))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(entity, _)| entity)
+        });
         
-        // Update target
-        let old_target = turret.current_target;
-        if let Some(old_target_entity) = old_target {
-            // Check if old target still exists and is in range
-            if let Ok((_, target_transform, _, enemy_type)) = enemy_query.get(old_target_entity) {
-                let distance = player_transform.translation.distance(target_transform.translation);
-                if distance > turret.max_lock_range {
-                    println!("[Turret Targeting] Target {:?} out of range ({:.1} > {:.1}), switching targets",
-                        enemy_type.enemy_type, distance, turret.max_lock_range);
-                    turret.current_target = closest_enemy;
-                } else {
-                    // Keep tracking current target
-                    turret.current_target = Some(old_target_entity);
+        // Threats already claimed by an earlier hardpoint - spread point defense fire when possible
+        let mut claimed_threats: Vec<Entity> = Vec::new();
+        
+        for hardpoint in turret.hardpoints.iter_mut() {
+            let mount_pos = hardpoint.world_position(player_transform);
+            
+            if mode == TurretMode::PointDefense {
+                // Prioritize the incoming threat that will arrive soonest
+                let mut best_threat = None;
+                let mut best_time = f32::MAX;
+                for (threat_entity, threat_transform, threat_velocity, threat_faction) in threat_query.iter() {
+                    if *threat_faction == Faction::Player {
+                        continue;
+                    }
+                    let to_player = player_transform.translation - threat_transform.translation;
+                    let distance = to_player.length();
+                    if distance > point_defense_range || !hardpoint.in_arc(player_transform, threat_transform.translation) {
+                        continue;
+                    }
+                    // Closing speed - ignore projectiles moving away
+                    let closing_speed = threat_velocity.0.dot(to_player / distance.max(0.01));
+                    if closing_speed <= 0.0 {
+                        continue;
+                    }
+                    let mut time_to_impact = distance / closing_speed;
+                    if claimed_threats.contains(&threat_entity) {
+                        time_to_impact *= 2.0;
+                    }
+                    if time_to_impact < best_time {
+                        best_time = time_to_impact;
+                        best_threat = Some(threat_entity);
+                    }
+                }
+                
+                if let Some(threat) = best_threat {
+                    hardpoint.current_target = Some(threat);
+                    claimed_threats.push(threat);
+                    continue;
+                }
+                
+                // No threats - drop a stale projectile target before falling back to ships
+                if hardpoint.current_target.map_or(false, |t| !enemy_query.contains(t)) {
+                    hardpoint.current_target = None;
                 }
-            } else {
-                // Old target destroyed, find new one
-                println!("[Turret Targeting] ⚠️ TARGET VANISHED - Old target no longer in query, acquiring new target");
-                turret.current_target = closest_enemy;
             }
-        } else {
-            // No current target, acquire new one
-            if closest_enemy.is_some() {
-                if let Some(new_target) = closest_enemy {
-                    if let Ok((_, _, _, enemy_type)) = enemy_query.get(new_target) {
-                        println!("[Turret Targeting] 🎯 New target acquired: {:?} at {:.1} units",
-                            enemy_type.enemy_type, closest_distance);
+            
+            // Keep tracking the current target while it stays valid, unless assisting a new player target
+            if let Some(current) = hardpoint.current_target {
+                if let Ok((_, target_transform, _, _, enemy, _, _)) = enemy_query.get(current) {
+                    let distance = mount_pos.distance(target_transform.translation);
+                    let following_player = policy != TurretTargetPolicy::PlayerTarget
+                        || player_target.map_or(true, |t| t == current || !enemy_query.contains(t));
+                    if distance <= max_lock_range && hardpoint.in_arc(player_transform, target_transform.translation) && following_player {
+                        continue;
                     }
+                    if distance > max_lock_range {
+                        println!("[Turret Targeting] {} target {:?} out of range ({:.1} > {:.1}), switching targets",
+                            hardpoint.name, enemy.enemy_type, distance, max_lock_range);
+                    }
+                } else {
+                    println!("[Turret Targeting] ⚠️ TARGET VANISHED - {} target no longer in query, acquiring new target", hardpoint.name);
+                }
+            }
+            
+            // Pick the best ship inside this hardpoint's arc
+            let mut best_score = f32::MIN;
+            let mut best_target = None;
+            
+            for (enemy_entity, enemy_transform, health, shield, enemy, ai, faction) in enemy_query.iter() {
+                let distance = mount_pos.distance(enemy_transform.translation);
+                if distance > max_lock_range || !hardpoint.in_arc(player_transform, enemy_transform.translation) {
+                    continue;
+                }
+                // Friendly and neutral ships are only engaged when the player picks them out
+                if !reputation.is_hostile(Faction::Player, *faction) && player_target != Some(enemy_entity) {
+                    continue;
+                }
+                
+                let score = if policy == TurretTargetPolicy::PlayerTarget && player_target == Some(enemy_entity) {
+                    f32::MAX
+                } else {
+                    let attacking_player = ai.map_or(false, |ai| ai.target == Some(player_entity));
+                    turret_target_score(policy, distance, max_lock_range, health, shield, enemy, attacking_player)
+                };
+                
+                if score > best_score {
+                    best_score = score;
+                    best_target = Some((enemy_entity, enemy.enemy_type, distance));
+                }
+            }
+            
+            if let Some((new_target, enemy_type, distance)) = best_target {
+                if hardpoint.current_target != Some(new_target) {
+                    println!("[Turret Targeting] 🎯 {} acquired {:?} at {:.1} units ({:?})",
+                        hardpoint.name, enemy_type, distance, policy);
                 }
             }
-            turret.current_target = closest_enemy;
+            hardpoint.current_target = best_target.map(|(entity, _, _)| entity);
         }
     }
 }
 
-/// Turret rotation system - rotates turret independently to track targets
+/// Turret rotation system - rotates each hardpoint independently to track its target, within its arc
 pub fn autofire_aiming_system(
     time: Res<Time>,
     mut player_query: Query<(&Transform, &mut AutoTurret), With<Player>>,
     enemy_query: Query<(&Transform, &Velocity), (With<Enemy>, Without<Player>)>,
+    threat_query: Query<(&Transform, &Velocity), (With<ProjectileHealth>, Without<Player>)>,
 ) {
     let dt = time.delta_seconds();
     
     for (player_transform, mut turret) in player_query.iter_mut() {
-        if !turret.enabled || turret.current_target.is_none() {
+        if !turret.enabled {
             continue;
         }
         
-        let target_entity = turret.current_target.unwrap();
-        if let Ok((target_transform, target_velocity)) = enemy_query.get(target_entity) {
-            // Turret position (mounted on back of ship, elevated)
-            let turret_world_pos = player_transform.translation 
-                + player_transform.up().as_vec3() * 2.0  // 2 units above
-                - player_transform.forward().as_vec3() * 3.0; // 3 units behind
-            
-            // Get turret's current weapon projectile speed
-            let projectile_speed = turret.weapons.get(turret.current_weapon)
-                .map(|w| w.projectile_speed)
-                .unwrap_or(150.0); // Default speed if no weapon
-            
-            // Calculate relative position and velocity from turret position
-            let relative_pos = target_transform.translation - turret_world_pos;
-            let distance = relative_pos.length();
-            
-            // Target velocity relative to player (since projectiles don't inherit momentum in this game)
-            let relative_velocity = target_velocity.0;
-            let target_speed = relative_velocity.length();
-            
-            // Iterative lead calculation for more accuracy
-            // Start with initial time estimate
-            let mut time_to_impact = distance / projectile_speed;
-            
-            // Refine the estimate (2 iterations is usually enough)
-            for _ in 0..2 {
-                let predicted_pos = target_transform.translation + relative_velocity * time_to_impact;
-                let new_distance = (predicted_pos - turret_world_pos).length();
-                time_to_impact = new_distance / projectile_speed;
-            }
-            
-            // Final predicted intercept point
-            let intercept_point = target_transform.translation + relative_velocity * time_to_impact;
-            let lead_distance = (intercept_point - target_transform.translation).length();
-            
-            // Debug: Print tracking info (throttled to avoid spam)
-            static mut DEBUG_TIMER: f32 = 0.0;
-            unsafe {
-                DEBUG_TIMER += dt;
-                if DEBUG_TIMER > 0.5 { // Print every 0.5 seconds
-                    println!("[Turret Track] Target pos: ({:.1}, {:.1}, {:.1}) | Vel: ({:.1}, {:.1}, {:.1}) speed: {:.1} | Dist: {:.1} | Lead: {:.1} units",
-                        target_transform.translation.x, target_transform.translation.y, target_transform.translation.z,
-                        relative_velocity.x, relative_velocity.y, relative_velocity.z, target_speed,
-                        distance, lead_distance
-                    );
-                    DEBUG_TIMER = 0.0;
-                }
-            }
-            
-            // Calculate turret's current forward direction in world space
-            // Turret rotation is independent of ship rotation
-            let turret_forward = (turret.current_rotation * Vec3::Z).normalize();
-            let to_intercept = (intercept_point - turret_world_pos).normalize();
-            
-            // Calculate rotation needed
-            let cross = turret_forward.cross(to_intercept);
-            let dot = turret_forward.dot(to_intercept).clamp(-1.0, 1.0);
-            let angle_to_target = dot.acos();
-            
-            // Debug: Print turret aiming info
-            unsafe {
-                if DEBUG_TIMER == 0.0 {
-                    println!("[Turret Aim] Turret fwd: ({:.2}, {:.2}, {:.2}) | To-intercept: ({:.2}, {:.2}, {:.2}) | Angle: {:.3}° | Status: {}",
-                        turret_forward.x, turret_forward.y, turret_forward.z,
-                        to_intercept.x, to_intercept.y, to_intercept.z,
-                        angle_to_target.to_degrees(),
-                        if angle_to_target <= 0.03 { "LOCKED" } else { "TRACKING" }
-                    );
+        let turn_rate = turret.turn_rate;
+        let default_speed = 150.0; // Default speed if no weapon
+        let weapon_speeds: Vec<f32> = turret.weapons.iter().map(|w| w.projectile_speed).collect();
+        
+        for hardpoint in turret.hardpoints.iter_mut() {
+            if let Some(target_entity) = hardpoint.current_target {
+                if let Ok((target_transform, target_velocity)) = enemy_query.get(target_entity).or_else(|_| threat_query.get(target_entity)) {
+                    let turret_world_pos = hardpoint.world_position(player_transform);
+                    
+                    // Get hardpoint's current weapon projectile speed
+                    let projectile_speed = weapon_speeds.get(hardpoint.current_weapon)
+                        .copied()
+                        .unwrap_or(default_speed);
+                    
+                    // Calculate relative position and velocity from turret position
+                    let relative_pos = target_transform.translation - turret_world_pos;
+                    let distance = relative_pos.length();
+                    
+                    // Target velocity relative to player (since projectiles don't inherit momentum in this game)
+                    let relative_velocity = target_velocity.0;
+                    let target_speed = relative_velocity.length();
+                    
+                    // Iterative lead calculation for more accuracy
+                    // Start with initial time estimate
+                    let mut time_to_impact = distance / projectile_speed;
+                    
+                    // Refine the estimate (2 iterations is usually enough)
+                    for _ in 0..2 {
+                        let predicted_pos = target_transform.translation + relative_velocity * time_to_impact;
+                        let new_distance = (predicted_pos - turret_world_pos).length();
+                        time_to_impact = new_distance / projectile_speed;
+                    }
+                    
+                    // Final predicted intercept point
+                    let intercept_point = target_transform.translation + relative_velocity * time_to_impact;
+                    let lead_distance = (intercept_point - target_transform.translation).length();
+                    
+                    // Debug: Print tracking info (throttled to avoid spam)
+                    static mut DEBUG_TIMER: f32 = 0.0;
+                    unsafe {
+                        DEBUG_TIMER += dt;
+                        if DEBUG_TIMER > 0.5 { // Print every 0.5 seconds
+                            println!("[Turret Track] {} | Target pos: ({:.1}, {:.1}, {:.1}) | Vel: ({:.1}, {:.1}, {:.1}) speed: {:.1} | Dist: {:.1} | Lead: {:.1} units",
+                                hardpoint.name,
+                                target_transform.translation.x, target_transform.translation.y, target_transform.translation.z,
+                                relative_velocity.x, relative_velocity.y, relative_velocity.z, target_speed,
+                                distance, lead_distance
+                            );
+                            DEBUG_TIMER = 0.0;
+                        }
+                    }
+                    
+                    // Calculate hardpoint's current forward direction in world space
+                    // Turret rotation is independent of ship rotation
+                    let turret_forward = (hardpoint.current_rotation * Vec3::Z).normalize();
+                    let to_intercept = hardpoint.clamp_to_arc(player_transform, (intercept_point - turret_world_pos).normalize());
+                    
+                    // Calculate rotation needed
+                    let cross = turret_forward.cross(to_intercept);
+                    let dot = turret_forward.dot(to_intercept).clamp(-1.0, 1.0);
+                    let angle_to_target = dot.acos();
+                    
+                    // Rotate turret toward intercept point
+                    if angle_to_target > 0.03 && cross.length() > 0.001 {
+                        // Calculate rotation for this frame
+                        let rotation_axis = cross.normalize();
+                        let max_rotation = turn_rate * dt;
+                        let rotation_amount = angle_to_target.min(max_rotation);
+                        
+                        // Apply rotation to turret (independent of ship!)
+                        let rotation = Quat::from_axis_angle(rotation_axis, rotation_amount);
+                        hardpoint.current_rotation = (rotation * hardpoint.current_rotation).normalize();
+                    }
+                } else {
+                    // Target no longer exists (died, despawned, or otherwise removed)
+                    println!("[Turret Track] ⚠️ TARGET LOST - {} target no longer exists, clearing target", hardpoint.name);
+                    hardpoint.current_target = None;
                 }
             }
             
-            // Rotate turret toward intercept point
-            if angle_to_target > 0.03 && cross.length() > 0.001 {
-                // Calculate rotation for this frame
-                let rotation_axis = cross.normalize();
-                let max_rotation = turret.turn_rate * dt;
-                let rotation_amount = angle_to_target.min(max_rotation);
-                
-                // Apply rotation to turret (independent of ship!)
-                let rotation = Quat::from_axis_angle(rotation_axis, rotation_amount);
-                turret.current_rotation = rotation * turret.current_rotation;
-                turret.current_rotation = turret.current_rotation.normalize();
+            // Ship maneuvers can carry the barrel past its mechanical limit - push it back inside the arc
+            let barrel = (hardpoint.current_rotation * Vec3::Z).normalize();
+            let clamped = hardpoint.clamp_to_arc(player_transform, barrel);
+            if clamped != barrel {
+                hardpoint.current_rotation = Quat::from_rotation_arc(Vec3::Z, clamped.normalize());
             }
-        } else {
-            // Target no longer exists (died, despawned, or otherwise removed)
-            println!("[Turret Track] ⚠️ TARGET LOST - Entity no longer exists, clearing target");
-            turret.current_target = None;
         }
     }
 }
@@ -187,26 +325,29 @@ pub fn autofire_aiming_system(
 /// Update turret visual rotation system
 pub fn update_turret_visual_system(
     player_query: Query<(&AutoTurret, &Transform), With<Player>>,
-    mut turret_visual_query: Query<&mut Transform, (With<TurretVisual>, Without<Player>)>,
+    mut turret_visual_query: Query<(&TurretVisual, &mut Transform), Without<Player>>,
 ) {
     for (turret, ship_transform) in player_query.iter() {
-        for mut turret_transform in turret_visual_query.iter_mut() {
-            // Convert turret's world-space rotation to local-space rotation
+        for (visual, mut turret_transform) in turret_visual_query.iter_mut() {
+            let Some(hardpoint) = turret.hardpoints.get(visual.hardpoint) else {
+                continue;
+            };
+            // Convert hardpoint's world-space rotation to local-space rotation
             // Turret rotation is in world space, but as a child entity it needs local rotation
             let ship_rotation_inverse = ship_transform.rotation.inverse();
-            turret_transform.rotation = ship_rotation_inverse * turret.current_rotation;
+            turret_transform.rotation = ship_rotation_inverse * hardpoint.current_rotation;
         }
     }
 }
 
 /// Intelligent weapon selection for turret based on target state
 /// Returns the index of the best weapon to use
-fn select_best_turret_weapon(weapons: &[Weapon], shield_percentage: f32, distance: f32) -> usize {
+pub fn select_best_turret_weapon(weapons: &[Weapon], shield_percentage: f32, distance: f32) -> usize {
     let mut best_idx = 0;
     let mut best_score = f32::MIN;
     
     for (idx, weapon) in weapons.iter().enumerate() {
-        let mut score = 0.0;
+        let mut score;
         
         match weapon.weapon_type {
             WeaponType::Laser => {
@@ -245,10 +386,43 @@ fn select_best_turret_weapon(weapons: &[Weapon], shield_percentage: f32, distanc
                     }
                 }
             },
-            _ => {
-                // Other weapons get neutral score
-                score = 30.0;
-            }
+            WeaponType::Missile => {
+                // Missiles are slow - only worth it at medium range against weakened shields
+                if distance > 60.0 && distance < 200.0 {
+                    score = 40.0 + (1.0 - shield_percentage) * 30.0;
+                } else {
+                    score = 10.0;
+                }
+            },
+            WeaponType::Railgun => {
+                // Railgun is a long range hull breaker
+                score = (1.0 - shield_percentage) * 70.0;
+                if distance > 160.0 {
+                    score += 40.0;
+                }
+            },
+            WeaponType::IonCannon => {
+                // Ion cannon strips shields fast but barely scratches hull
+                if shield_percentage < 0.1 {
+                    score = 0.0;
+                } else {
+                    score = shield_percentage * 120.0;
+                }
+            },
+            WeaponType::FlakCannon => {
+                // Flak is a close range anti-hull area weapon
+                score = (1.0 - shield_percentage) * 60.0;
+                if distance < 80.0 {
+                    score += 50.0;
+                }
+            },
+            WeaponType::BeamLaser => {
+                // Beam laser is a sustained anti-shield weapon at medium range
+                score = shield_percentage * 80.0;
+                if distance < 200.0 {
+                    score += 30.0;
+                }
+            },
         }
         
         if score > best_score {
@@ -260,7 +434,18 @@ fn select_best_turret_weapon(weapons: &[Weapon], shield_percentage: f32, distanc
     best_idx
 }
 
-/// Turret firing system - fires from turret when target is in sights
+/// Point-defense weapon selection - fastest firing, fastest projectile weapon wins
+fn select_point_defense_weapon(weapons: &[Weapon]) -> usize {
+    weapons.iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| {
+            (a.fire_rate * a.projectile_speed).partial_cmp(&(b.fire_rate * b.projectile_speed)).unwrap()
+        })
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+/// Turret firing system - each hardpoint fires when its target is in its sights
 pub fn autofire_firing_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -273,148 +458,165 @@ pub fn autofire_firing_system(
         &mut AutoTurret,
         &mut Energy,
         &crate::components::ship_classes::ClassBonuses,
+        Option<&crate::components::status_effects::StatusEffects>,
     ), With<Player>>,
     enemy_query: Query<(&Transform, &Shield, &Health), (With<Enemy>, Without<Player>)>,
+    threat_query: Query<&Transform, (With<ProjectileHealth>, Without<Player>)>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let dt = time.delta_seconds();
+    let rng = game_rng.stream(RngStream::Combat);
     
-    for (owner_entity, ship_transform, _ship_velocity, mut turret, mut energy, bonuses) in player_query.iter_mut() {
-        if !turret.enabled || turret.current_target.is_none() {
+    for (owner_entity, ship_transform, _ship_velocity, mut turret, mut energy, bonuses, status_effects) in player_query.iter_mut() {
+        if !turret.enabled {
             continue;
         }
+        let weapons_jammed = status_effects.map_or(false, |s| s.weapons_disabled());
         
-        // Update turret weapon cooldown
-        turret.firing_cooldown = (turret.firing_cooldown - dt).max(0.0);
-        
-        let target_entity = turret.current_target.unwrap();
-        let (target_transform, target_shield, target_health) = if let Ok(data) = enemy_query.get(target_entity) {
-            data
-        } else {
-            continue; // Target doesn't exist
-        };
-        
-        // Turret world position
-        let turret_world_pos = ship_transform.translation 
-            + ship_transform.up().as_vec3() * 2.0
-            - ship_transform.forward().as_vec3() * 3.0;
-        
-        // Check if target is in range
-        let to_target = target_transform.translation - turret_world_pos;
-        let distance = to_target.length();
-        
-        if distance > turret.max_fire_range {
-            continue; // Out of range
-        }
-        
-        // Turret's forward direction (independent of ship)
-        let turret_forward = (turret.current_rotation * Vec3::Z).normalize();
-        let to_target_normalized = to_target.normalize();
-        let dot = turret_forward.dot(to_target_normalized).clamp(-1.0, 1.0);
-        let angle = dot.acos();
+        let max_fire_range = turret.max_fire_range;
+        let fire_cone_angle = turret.fire_cone_angle;
+        let AutoTurret { hardpoints, weapons, .. } = &mut *turret;
         
-        // Debug: Print firing check
-        static mut FIRE_DEBUG_TIMER: f32 = 0.0;
-        unsafe {
-            FIRE_DEBUG_TIMER += dt;
-            if FIRE_DEBUG_TIMER > 1.0 {
-                println!("[Turret Fire] Distance: {:.1}/{:.1} | Angle: {:.3}°/{:.3}° | In cone: {}",
-                    distance, turret.max_fire_range,
-                    angle.to_degrees(), turret.fire_cone_angle.to_degrees(),
-                    if angle <= turret.fire_cone_angle { "YES" } else { "NO" }
-                );
-                FIRE_DEBUG_TIMER = 0.0;
-            }
-        }
-        
-        if angle > turret.fire_cone_angle {
-            continue; // Not in firing cone
-        }
-        
-        // Smart weapon selection based on target state and distance
-        let shield_percentage = target_shield.current / target_shield.max;
-        let best_weapon_idx = select_best_turret_weapon(&turret.weapons, shield_percentage, distance);
-        
-        // Only switch weapons if we found a better one and it's ready to fire
-        if best_weapon_idx != turret.current_weapon {
-            // Check if the new weapon is usable (without holding a borrow)
-            let can_use = if let Some(weapon) = turret.weapons.get(best_weapon_idx) {
-                !weapon.is_reloading
-                    && (weapon.max_heat == 0.0 || weapon.heat < weapon.max_heat)
-                    && (weapon.max_ammo == 0 || weapon.current_ammo > 0)
-                    && energy.current >= weapon.energy_cost
+        for hardpoint in hardpoints.iter_mut() {
+            // Update hardpoint cooldown
+            hardpoint.firing_cooldown = (hardpoint.firing_cooldown - dt).max(0.0);
+            
+            let Some(target_entity) = hardpoint.current_target else {
+                continue;
+            };
+            
+            // Target is either an enemy ship or an incoming projectile (point defense)
+            let (target_transform, shield_percentage, is_threat) = if let Ok((transform, shield, _health)) = enemy_query.get(target_entity) {
+                (transform, shield.current / shield.max, false)
+            } else if let Ok(transform) = threat_query.get(target_entity) {
+                (transform, 0.0, true)
             } else {
-                false
+                continue; // Target doesn't exist
             };
             
-            if can_use {
-                let weapon_type = turret.weapons[best_weapon_idx].weapon_type;
-                turret.current_weapon = best_weapon_idx;
-                println!("[Turret AI] Switched to {:?} - Shields: {:.0}% | Distance: {:.1}",
-                    weapon_type, shield_percentage * 100.0, distance);
+            // Hardpoint world position
+            let turret_world_pos = hardpoint.world_position(ship_transform);
+            
+            // Check if target is in range
+            let to_target = target_transform.translation - turret_world_pos;
+            let distance = to_target.length();
+            
+            if distance > max_fire_range {
+                continue; // Out of range
             }
-        }
-        
-        // Extract values we need before mutable borrow
-        let current_weapon_idx = turret.current_weapon;
-        let firing_cooldown = turret.firing_cooldown;
-        let turret_rotation = turret.current_rotation;
-        
-        if let Some(weapon) = turret.weapons.get_mut(current_weapon_idx) {
-            // Check if turret can fire
-            let can_fire = firing_cooldown <= 0.0
-                && energy.current >= weapon.energy_cost
-                && !weapon.is_reloading
-                && (weapon.max_heat == 0.0 || weapon.heat < weapon.max_heat)
-                && (weapon.max_ammo == 0 || weapon.current_ammo > 0);
             
-            // Debug: Why can't fire?
-            if !can_fire {
-                static mut CANT_FIRE_TIMER: f32 = 0.0;
-                unsafe {
-                    CANT_FIRE_TIMER += dt;
-                    if CANT_FIRE_TIMER > 2.0 {
-                        println!("[Turret Fire] CAN'T FIRE - Cooldown: {:.2} | Energy: {:.1}/{:.1} | Heat: {:.1}/{:.1} | Ammo: {}/{} | Reloading: {}",
-                            firing_cooldown,
-                            energy.current, weapon.energy_cost,
-                            weapon.heat, weapon.max_heat,
-                            weapon.current_ammo, weapon.max_ammo,
-                            weapon.is_reloading
-                        );
-                        CANT_FIRE_TIMER = 0.0;
-                    }
+            // Never fire through our own hull - target must be inside this hardpoint's arc
+            if !hardpoint.in_arc(ship_transform, target_transform.translation) {
+                continue;
+            }
+            
+            // Hardpoint's forward direction (independent of ship)
+            let turret_forward = (hardpoint.current_rotation * Vec3::Z).normalize();
+            let to_target_normalized = to_target.normalize();
+            let dot = turret_forward.dot(to_target_normalized).clamp(-1.0, 1.0);
+            let angle = dot.acos();
+            
+            // Debug: Print firing check
+            static mut FIRE_DEBUG_TIMER: f32 = 0.0;
+            unsafe {
+                FIRE_DEBUG_TIMER += dt;
+                if FIRE_DEBUG_TIMER > 1.0 {
+                    println!("[Turret Fire] {} | Distance: {:.1}/{:.1} | Angle: {:.3}°/{:.3}° | In cone: {}",
+                        hardpoint.name,
+                        distance, max_fire_range,
+                        angle.to_degrees(), fire_cone_angle.to_degrees(),
+                        if angle <= fire_cone_angle { "YES" } else { "NO" }
+                    );
+                    FIRE_DEBUG_TIMER = 0.0;
                 }
             }
             
-            if can_fire {
-                println!("[Turret Fire] 🎯 TURRET FIRING | Weapon: {:?} | Speed: {:.0} | Turret pos: ({:.1}, {:.1}, {:.1})",
-                    weapon.weapon_type,
-                    weapon.projectile_speed,
-                    turret_world_pos.x, turret_world_pos.y, turret_world_pos.z
-                );
+            if angle > fire_cone_angle {
+                continue; // Not in firing cone
+            }
+            
+            // Smart weapon selection based on target state and distance
+            let best_weapon_idx = if is_threat {
+                select_point_defense_weapon(weapons)
+            } else {
+                select_best_turret_weapon(weapons, shield_percentage, distance)
+            };
+            
+            // Only switch weapons if we found a better one and it's ready to fire
+            if best_weapon_idx != hardpoint.current_weapon {
+                let can_use = if let Some(weapon) = weapons.get(best_weapon_idx) {
+                    !weapon.is_reloading
+                        && (weapon.max_heat == 0.0 || weapon.heat < weapon.max_heat)
+                        && (weapon.max_ammo == 0 || weapon.current_ammo > 0)
+                        && energy.current >= weapon.energy_cost
+                } else {
+                    false
+                };
                 
-                let fire_rate = weapon.fire_rate; // Store before calling fire_turret_weapon
+                if can_use {
+                    hardpoint.current_weapon = best_weapon_idx;
+                    println!("[Turret AI] {} switched to {:?} - Shields: {:.0}% | Distance: {:.1}",
+                        hardpoint.name, weapons[best_weapon_idx].weapon_type, shield_percentage * 100.0, distance);
+                }
+            }
+            
+            if let Some(weapon) = weapons.get_mut(hardpoint.current_weapon) {
+                // Check if hardpoint can fire
+                let can_fire = !weapons_jammed
+                    && hardpoint.firing_cooldown <= 0.0
+                    && energy.current >= weapon.energy_cost
+                    && !weapon.is_reloading
+                    && (weapon.max_heat == 0.0 || weapon.heat < weapon.max_heat)
+                    && (weapon.max_ammo == 0 || weapon.current_ammo > 0);
                 
-                // Fire from turret position with turret's rotation
-                fire_turret_weapon(
-                    &mut commands,
-                    &mut meshes,
-                    &mut materials,
-                    owner_entity,
-                    turret_world_pos,
-                    turret_rotation,
-                    weapon,
-                    &mut energy,
-                    bonuses,
-                );
+                // Debug: Why can't fire?
+                if !can_fire {
+                    static mut CANT_FIRE_TIMER: f32 = 0.0;
+                    unsafe {
+                        CANT_FIRE_TIMER += dt;
+                        if CANT_FIRE_TIMER > 2.0 {
+                            println!("[Turret Fire] {} CAN'T FIRE - Cooldown: {:.2} | Energy: {:.1}/{:.1} | Heat: {:.1}/{:.1} | Ammo: {}/{} | Reloading: {}",
+                                hardpoint.name,
+                                hardpoint.firing_cooldown,
+                                energy.current, weapon.energy_cost,
+                                weapon.heat, weapon.max_heat,
+                                weapon.current_ammo, weapon.max_ammo,
+                                weapon.is_reloading
+                            );
+                            CANT_FIRE_TIMER = 0.0;
+                        }
+                    }
+                }
                 
-                // Drop the mutable borrow before setting firing_cooldown
-                drop(weapon);
-                turret.firing_cooldown = 1.0 / fire_rate;
-            } else if weapon.max_ammo > 0 && weapon.current_ammo == 0 && !weapon.is_reloading {
-                // Auto-reload
-                if weapon.reserve_ammo > 0 {
-                    weapon.is_reloading = true;
-                    weapon.reload_timer = 0.0;
+                if can_fire {
+                    println!("[Turret Fire] 🎯 {} FIRING | Weapon: {:?} | Speed: {:.0} | Turret pos: ({:.1}, {:.1}, {:.1})",
+                        hardpoint.name,
+                        weapon.weapon_type,
+                        weapon.projectile_speed,
+                        turret_world_pos.x, turret_world_pos.y, turret_world_pos.z
+                    );
+                    
+                    // Fire from hardpoint position with hardpoint's rotation
+                    fire_turret_weapon(
+                        &mut commands,
+                        &mut meshes,
+                        &mut materials,
+                        owner_entity,
+                        turret_world_pos,
+                        hardpoint.current_rotation,
+                        weapon,
+                        &mut energy,
+                        bonuses,
+                        rng,
+                    );
+                    
+                    hardpoint.firing_cooldown = 1.0 / weapon.fire_rate;
+                } else if weapon.max_ammo > 0 && weapon.current_ammo == 0 && !weapon.is_reloading {
+                    // Auto-reload
+                    if weapon.reserve_ammo > 0 {
+                        weapon.is_reloading = true;
+                        weapon.reload_timer = 0.0;
+                    }
                 }
             }
         }
@@ -457,7 +659,7 @@ pub fn turret_weapon_state_system(
 /// Weapon state management system (heat, ammo, reload)
 pub fn weapon_state_system(
     time: Res<Time>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: Res<PlayerInput>,
     mut query: Query<&mut WeaponMount, With<Player>>,
 ) {
     let dt = time.delta_seconds();
@@ -486,7 +688,7 @@ pub fn weapon_state_system(
         }
         
         // Manual reload with R key
-        if keyboard.just_pressed(KeyCode::KeyR) {
+        if input.just_pressed(KeyCode::KeyR) {
             let current_weapon_idx = weapon_mount.current_weapon;
             if let Some(weapon) = weapon_mount.weapons.get_mut(current_weapon_idx) {
                 // Only reload if weapon has ammo system and not already reloading
@@ -503,24 +705,25 @@ pub fn weapon_state_system(
 /// Weapon firing system
 pub fn weapon_firing_system(
     mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mouse: Res<ButtonInput<MouseButton>>,
+    input: Res<PlayerInput>,
     time: Res<Time>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut query: Query<(Entity, &Transform, &Velocity, &mut WeaponMount, &mut Energy, &crate::components::ship_classes::ClassBonuses), With<Player>>,
+    mut query: Query<(Entity, &Transform, &Velocity, &mut WeaponMount, &mut Energy, &crate::components::ship_classes::ClassBonuses, Option<&SubsystemStatus>, Option<&crate::components::status_effects::StatusEffects>), With<Player>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let dt = time.delta_seconds();
+    let rng = game_rng.stream(RngStream::Combat);
     
-    for (entity, transform, velocity, mut weapon_mount, mut energy, bonuses) in query.iter_mut() {
+    for (entity, transform, velocity, mut weapon_mount, mut energy, bonuses, subsystems, status_effects) in query.iter_mut() {
         // Update cooldown timers
         for weapon in weapon_mount.weapons.iter_mut() {
             weapon.cooldown_timer = (weapon.cooldown_timer - dt).max(0.0);
             
             // Update alt-fire charge for plasma
-            if weapon.weapon_type == WeaponType::Plasma && mouse.pressed(MouseButton::Right) {
+            if weapon.weapon_type == WeaponType::Plasma && input.mouse_pressed(MouseButton::Right) {
                 weapon.alt_fire_charge = (weapon.alt_fire_charge + dt).min(2.0);
-            } else if weapon.weapon_type == WeaponType::Plasma && mouse.just_released(MouseButton::Right) {
+            } else if weapon.weapon_type == WeaponType::Plasma && input.mouse_just_released(MouseButton::Right) {
                 // Will be handled in firing logic
             } else if weapon.weapon_type == WeaponType::Plasma {
                 weapon.alt_fire_charge = 0.0;
@@ -528,23 +731,27 @@ pub fn weapon_firing_system(
         }
         
         // Switch weapons
-        if keyboard.just_pressed(KeyCode::Digit1) && weapon_mount.weapons.len() > 0 {
+        if input.just_pressed(KeyCode::Digit1) && weapon_mount.weapons.len() > 0 {
             weapon_mount.current_weapon = 0;
         }
-        if keyboard.just_pressed(KeyCode::Digit2) && weapon_mount.weapons.len() > 1 {
+        if input.just_pressed(KeyCode::Digit2) && weapon_mount.weapons.len() > 1 {
             weapon_mount.current_weapon = 1;
         }
-        if keyboard.just_pressed(KeyCode::Digit3) && weapon_mount.weapons.len() > 2 {
+        if input.just_pressed(KeyCode::Digit3) && weapon_mount.weapons.len() > 2 {
             weapon_mount.current_weapon = 2;
         }
-        if keyboard.just_pressed(KeyCode::Digit4) && weapon_mount.weapons.len() > 3 {
+        if input.just_pressed(KeyCode::Digit4) && weapon_mount.weapons.len() > 3 {
             weapon_mount.current_weapon = 3;
         }
         
         let current_weapon_idx = weapon_mount.current_weapon;
         
+        // A slot whose weapon mount has been shot off can't fire at all, and nothing fires while disabled
+        let slot_online = subsystems.map_or(true, |s| s.weapon_slot_online(current_weapon_idx, weapon_mount.weapons.len()))
+            && !status_effects.map_or(false, |s| s.weapons_disabled());
+        
         // Primary fire (Left Mouse)
-        if mouse.pressed(MouseButton::Left) {
+        if slot_online && input.mouse_pressed(MouseButton::Left) {
             if let Some(weapon) = weapon_mount.weapons.get_mut(current_weapon_idx) {
                 // Check all firing conditions
                 let can_fire = weapon.cooldown_timer <= 0.0 
@@ -554,7 +761,7 @@ pub fn weapon_firing_system(
                     && (weapon.max_ammo == 0 || weapon.current_ammo > 0); // Has ammo or infinite
                 
                 if can_fire {
-                    fire_weapon(&mut commands, &mut meshes, &mut materials, entity, transform, velocity, weapon, &mut energy, bonuses, false);
+                    fire_weapon(&mut commands, &mut meshes, &mut materials, entity, transform, velocity, weapon, &mut energy, bonuses, false, rng);
                     // Apply fire rate multiplier to cooldown
                     weapon.cooldown_timer = (1.0 / weapon.fire_rate) / bonuses.fire_rate_multiplier;
                 } else if weapon.max_ammo > 0 && weapon.current_ammo == 0 && !weapon.is_reloading {
@@ -569,14 +776,14 @@ pub fn weapon_firing_system(
         }
         
         // Alt-fire (Right Mouse)
-        if mouse.just_pressed(MouseButton::Right) {
+        if slot_online && input.mouse_just_pressed(MouseButton::Right) {
             if let Some(weapon) = weapon_mount.weapons.get_mut(current_weapon_idx) {
                 // Burst fire for laser
                 if weapon.weapon_type == WeaponType::Laser && weapon.cooldown_timer <= 0.0 && energy.current >= weapon.energy_cost * 3.0 {
                     // Fire 3-shot burst
                     for _i in 0..3 {
                         // For simplicity, fire all 3 immediately with slight spread
-                        fire_weapon(&mut commands, &mut meshes, &mut materials, entity, transform, velocity, weapon, &mut energy, bonuses, true);
+                        fire_weapon(&mut commands, &mut meshes, &mut materials, entity, transform, velocity, weapon, &mut energy, bonuses, true, rng);
                     }
                     weapon.cooldown_timer = (1.0 / weapon.fire_rate) / bonuses.fire_rate_multiplier;
                 }
@@ -584,7 +791,7 @@ pub fn weapon_firing_system(
                 else if weapon.weapon_type == WeaponType::Autocannon && weapon.cooldown_timer <= 0.0 && energy.current >= weapon.energy_cost * 5.0 {
                     // Fire 5-shot spread (shotgun)
                     for _ in 0..5 {
-                        fire_weapon_spread(&mut commands, &mut meshes, &mut materials, entity, transform, velocity, weapon, &mut energy);
+                        fire_weapon_spread(&mut commands, &mut meshes, &mut materials, entity, transform, velocity, weapon, &mut energy, rng);
                     }
                     weapon.cooldown_timer = 1.0 / weapon.fire_rate;
                 }
@@ -605,7 +812,7 @@ pub fn weapon_firing_system(
         }
         
         // Plasma charged shot (release)
-        if mouse.just_released(MouseButton::Right) {
+        if slot_online && input.mouse_just_released(MouseButton::Right) {
             if let Some(weapon) = weapon_mount.weapons.get_mut(current_weapon_idx) {
                 if weapon.weapon_type == WeaponType::Plasma && weapon.alt_fire_charge > 0.5 {
                     let charge_mult = weapon.alt_fire_charge;
@@ -631,6 +838,7 @@ fn fire_weapon(
     energy: &mut Energy,
     bonuses: &crate::components::ship_classes::ClassBonuses,
     is_burst: bool,
+    rng: &mut impl Rng,
 ) {
     // Cooldown is set by caller with fire_rate_multiplier
     energy.current -= weapon.energy_cost;
@@ -664,8 +872,8 @@ fn fire_weapon(
     
     // Add spread
     let spread_mult = if is_burst { 1.5 } else { 1.0 };
-    let spread_x = (rand::random::<f32>() - 0.5) * weapon.spread * spread_mult;
-    let spread_y = (rand::random::<f32>() - 0.5) * weapon.spread * spread_mult;
+    let spread_x = (rng.gen::<f32>() - 0.5) * weapon.spread * spread_mult;
+    let spread_y = (rng.gen::<f32>() - 0.5) * weapon.spread * spread_mult;
     let spread_rotation = Quat::from_euler(EulerRot::XYZ, spread_y, spread_x, 0.0);
     let projectile_direction = (spread_rotation * forward.as_vec3()).normalize();
     
@@ -684,15 +892,14 @@ fn fire_weapon(
     // Calculate final damage first (needed for laser color)
     let base_damage = weapon.damage;
     let mut final_damage = base_damage * bonuses.damage_multiplier;
-    let is_critical = rand::random::<f32>() < bonuses.critical_chance;
+    let is_critical = rng.gen::<f32>() < bonuses.critical_chance;
     if is_critical {
         final_damage *= bonuses.critical_multiplier;
     }
     
-    // Determine piercing for lasers - check if projectile should pierce
-    // This will be set on the Projectile component based on upgrades
-    // For now, lasers don't pierce by default (only railgun does)
-    let laser_piercing = false;
+    // Determine piercing for lasers - comes from the weapon's rolled affixes
+    // (only railgun pierces by default)
+    let laser_piercing = weapon.piercing;
     
     // Get weapon visual - for lasers, calculate color based on damage and piercing
     let (mesh, base_color) = get_weapon_visual(weapon.weapon_type, meshes);
@@ -709,6 +916,7 @@ fn fire_weapon(
         WeaponType::Railgun => (0.0, 0.0, true),        // Piercing rounds
         _ => (0.0, 0.0, false),                         // No special effects
     };
+    let piercing = piercing || weapon.piercing;
     
     // Calculate rotation based on projectile velocity
     // Capsules are aligned along Y-axis, so rotate from Y to velocity direction
@@ -751,6 +959,7 @@ fn fire_weapon(
             homing_strength,
             homing_target: None,
             initial_direction: projectile_direction,
+            is_critical,
         },
         Velocity(projectile_velocity),
         Faction::Player,
@@ -766,6 +975,7 @@ fn fire_weapon_spread(
     _velocity: &Velocity,  // Not used - projectiles don't inherit momentum
     weapon: &mut Weapon,
     energy: &mut Energy,
+    rng: &mut impl Rng,
 ) {
     energy.current -= weapon.energy_cost;
     
@@ -778,8 +988,8 @@ fn fire_weapon_spread(
     let projectile_pos = transform.translation + forward.as_vec3() * 3.0;
     
     // Extra spread for shotgun effect
-    let spread_x = (rand::random::<f32>() - 0.5) * 0.15;
-    let spread_y = (rand::random::<f32>() - 0.5) * 0.15;
+    let spread_x = (rng.gen::<f32>() - 0.5) * 0.15;
+    let spread_y = (rng.gen::<f32>() - 0.5) * 0.15;
     let spread_rotation = Quat::from_euler(EulerRot::XYZ, spread_y, spread_x, 0.0);
     let projectile_direction = (spread_rotation * forward.as_vec3()).normalize();
     
@@ -816,11 +1026,12 @@ fn fire_weapon_spread(
             weapon_type: weapon.weapon_type,
             shield_damage_multiplier: weapon.shield_damage_multiplier,
             hull_damage_multiplier: weapon.hull_damage_multiplier,
-            piercing: false,
+            piercing: weapon.piercing,
             area_damage: 0.0,
             homing_strength: 0.0,
             homing_target: None,
             initial_direction: projectile_direction,
+            is_critical: false,
         },
         Velocity(projectile_velocity),
         Faction::Player,
@@ -880,6 +1091,7 @@ fn fire_missile_swarm(
                 homing_strength: 12.0,    // Slightly weaker homing
                 homing_target: None,
                 initial_direction: projectile_direction,
+                is_critical: false,
             },
             Velocity(projectile_velocity),
             Faction::Player,
@@ -936,6 +1148,7 @@ fn fire_piercing_railgun(
             homing_strength: 0.0,
             homing_target: None,
             initial_direction: projectile_direction,
+            is_critical: false,
         },
         Velocity(projectile_velocity),
         Faction::Player,
@@ -994,6 +1207,7 @@ fn fire_charged_plasma(
             homing_strength: 0.0,
             homing_target: None,
             initial_direction: projectile_direction,
+            is_critical: false,
         },
         Velocity(projectile_velocity),
         Faction::Player,
@@ -1011,6 +1225,7 @@ fn fire_turret_weapon(
     weapon: &mut Weapon,
     energy: &mut Energy,
     bonuses: &crate::components::ship_classes::ClassBonuses,
+    rng: &mut impl Rng,
 ) {
     // Consume energy
     energy.current -= weapon.energy_cost;
@@ -1033,8 +1248,8 @@ fn fire_turret_weapon(
     let projectile_pos = turret_pos + turret_forward * 1.5; // Spawn slightly in front of turret
     
     // Add spread
-    let spread_x = (rand::random::<f32>() - 0.5) * weapon.spread;
-    let spread_y = (rand::random::<f32>() - 0.5) * weapon.spread;
+    let spread_x = (rng.gen::<f32>() - 0.5) * weapon.spread;
+    let spread_y = (rng.gen::<f32>() - 0.5) * weapon.spread;
     let spread_rotation = Quat::from_euler(EulerRot::XYZ, spread_y, spread_x, 0.0);
     let projectile_direction = (spread_rotation * turret_forward).normalize();
     
@@ -1044,7 +1259,7 @@ fn fire_turret_weapon(
     // Calculate damage
     let base_damage = weapon.damage;
     let mut final_damage = base_damage * bonuses.damage_multiplier;
-    let is_critical = rand::random::<f32>() < bonuses.critical_chance;
+    let is_critical = rng.gen::<f32>() < bonuses.critical_chance;
     if is_critical {
         final_damage *= bonuses.critical_multiplier;
     }
@@ -1052,7 +1267,7 @@ fn fire_turret_weapon(
     // Get weapon visual
     let (mesh, base_color) = get_weapon_visual(weapon.weapon_type, meshes);
     let color = if weapon.weapon_type == WeaponType::Laser {
-        calculate_laser_color(base_damage, final_damage, false)
+        calculate_laser_color(base_damage, final_damage, weapon.piercing)
     } else {
         base_color
     };
@@ -1064,6 +1279,7 @@ fn fire_turret_weapon(
         WeaponType::Railgun => (0.0, 0.0, true),
         _ => (0.0, 0.0, false),
     };
+    let piercing = piercing || weapon.piercing;
     
     // Calculate rotation - capsules are aligned along Y-axis
     let projectile_rotation = if projectile_velocity.length() > 0.1 {
@@ -1112,6 +1328,7 @@ fn fire_turret_weapon(
             homing_strength,
             homing_target: None,
             initial_direction: projectile_direction,
+            is_critical,
         },
         Velocity(projectile_velocity),
         Faction::Player,
@@ -1211,11 +1428,12 @@ pub fn projectile_movement_system(
     }
 }
 
-/// Homing projectile system - makes missiles track enemies
+/// Homing projectile system - missiles only steer toward the target they were locked onto at launch
 pub fn homing_projectile_system(
     time: Res<Time>,
     mut projectiles: Query<(&mut Projectile, &mut Velocity, &Transform, &Faction)>,
-    enemies: Query<(Entity, &Transform, &Faction), (With<Health>, Without<Projectile>)>,
+    targets: Query<&Transform, (With<Health>, Without<Projectile>)>,
+    decoys: Query<&Transform, (With<Decoy>, Without<Projectile>)>,
 ) {
     let dt = time.delta_seconds();
     
@@ -1225,60 +1443,299 @@ pub fn homing_projectile_system(
             continue;
         }
         
-        // Try to find target
-        let target_pos = if let Some(target_entity) = projectile.homing_target {
-            // Check if target still exists
-            if let Ok((_, target_transform, _)) = enemies.get(target_entity) {
-                Some(target_transform.translation)
-            } else {
-                // Target destroyed, find new one
-                projectile.homing_target = None;
-                None
-            }
+        let Some(target_entity) = projectile.homing_target else {
+            continue;
+        };
+        
+        // Locked target may be a ship or a countermeasure decoy
+        let target_pos = if let Ok(target_transform) = targets.get(target_entity) {
+            target_transform.translation
+        } else if let Ok(decoy_transform) = decoys.get(target_entity) {
+            decoy_transform.translation
         } else {
-            None
+            // Target destroyed or decoy burned out - missile goes ballistic
+            projectile.homing_target = None;
+            continue;
         };
         
-        // If no target, find closest enemy
-        let target_pos = target_pos.or_else(|| {
-            let mut closest_dist = f32::MAX;
-            let mut closest_pos = None;
-            let mut closest_entity = None;
-            
-            for (entity, enemy_transform, enemy_faction) in enemies.iter() {
-                // Don't target same faction
-                if proj_faction == enemy_faction {
-                    continue;
-                }
-                
-                let dist = proj_transform.translation.distance(enemy_transform.translation);
-                if dist < closest_dist && dist < 100.0 { // Max lock range
-                    closest_dist = dist;
-                    closest_pos = Some(enemy_transform.translation);
-                    closest_entity = Some(entity);
+        let to_target = (target_pos - proj_transform.translation).normalize();
+        let current_dir = velocity.0.normalize();
+        
+        // Blend current direction with target direction
+        let homing_factor = projectile.homing_strength * dt;
+        let new_dir = (current_dir + to_target * homing_factor).normalize();
+        
+        // Update velocity maintaining speed
+        let speed = velocity.0.length();
+        velocity.0 = new_dir * speed;
+        
+        // Debug: Log when missile first acquires target (only once)
+        if *proj_faction == Faction::Player && projectile.lifetime > 4.9 && projectile.lifetime < 5.0 {
+            println!("[Combat] Missile tracking locked target, homing strength: {}", projectile.homing_strength);
+        }
+    }
+}
+
+/// Missile lock-on system - builds a lock on the target held longest inside the lock cone
+pub fn missile_lock_system(
+    time: Res<Time>,
+    mut lockers: Query<(Entity, &Transform, &Faction, &WeaponMount, &mut MissileLock, Has<Player>)>,
+    targets: Query<(Entity, &Transform, &Faction), (With<Health>, Without<Projectile>)>,
+    reputation: Res<crate::components::Reputation>,
+) {
+    let dt = time.delta_seconds();
+    
+    for (entity, transform, faction, weapon_mount, mut lock, is_player) in lockers.iter_mut() {
+        // Player must have missiles selected; AI ships lock whenever they carry missiles
+        let has_missiles = if is_player {
+            weapon_mount.weapons.get(weapon_mount.current_weapon)
+                .map_or(false, |w| w.weapon_type == WeaponType::Missile)
+        } else {
+            weapon_mount.weapons.iter().any(|w| w.weapon_type == WeaponType::Missile)
+        };
+        
+        if !has_missiles {
+            lock.reset();
+            continue;
+        }
+        
+        // Find the hostile closest to the center of the lock cone
+        let forward = transform.forward().as_vec3();
+        let mut best: Option<(Entity, f32)> = None;
+        for (target_entity, target_transform, target_faction) in targets.iter() {
+            if target_entity == entity || !reputation.is_hostile(*faction, *target_faction) {
+                continue;
+            }
+            let to_target = target_transform.translation - transform.translation;
+            let distance = to_target.length();
+            if distance < 0.1 || distance > lock.range {
+                continue;
+            }
+            let angle = forward.angle_between(to_target / distance);
+            if angle < lock.cone_angle && best.map_or(true, |(_, a)| angle < a) {
+                best = Some((target_entity, angle));
+            }
+        }
+        
+        // Keep lock progress while the current candidate stays in the cone
+        let candidate = match (lock.candidate, best) {
+            (Some(current), _) if targets.get(current).map_or(false, |(_, t, _)| {
+                let to_target = t.translation - transform.translation;
+                to_target.length() <= lock.range && forward.angle_between(to_target.normalize()) < lock.cone_angle
+            }) => Some(current),
+            (_, Some((new_candidate, _))) => Some(new_candidate),
+            _ => None,
+        };
+        
+        if candidate != lock.candidate {
+            lock.reset();
+            lock.candidate = candidate;
+        }
+        
+        if lock.candidate.is_some() && !lock.is_locked() {
+            lock.progress += dt;
+            if lock.progress >= lock.lock_time {
+                lock.target = lock.candidate;
+                if is_player {
+                    println!("[Combat] Missile lock acquired");
                 }
             }
-            
-            projectile.homing_target = closest_entity;
-            closest_pos
-        });
+        }
+    }
+}
+
+/// Assign guidance to newly fired missiles - locked missiles home, unlocked ones fly straight
+pub fn missile_guidance_assignment_system(
+    mut new_projectiles: Query<&mut Projectile, Added<Projectile>>,
+    locks: Query<&MissileLock>,
+) {
+    for mut projectile in new_projectiles.iter_mut() {
+        if projectile.homing_strength <= 0.0 || projectile.homing_target.is_some() {
+            continue;
+        }
+        
+        projectile.homing_target = locks.get(projectile.owner).ok().and_then(|lock| lock.target);
+        if projectile.homing_target.is_none() {
+            // No lock - dumbfire
+            projectile.homing_strength = 0.0;
+        }
+    }
+}
+
+/// Countermeasure system - F deploys flares for the player, AI ships deploy when a missile closes in.
+/// Deploying spoofs missiles homing on the ship and breaks any lock on it.
+pub fn countermeasure_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    input: Res<PlayerInput>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut deployers: Query<(Entity, &Transform, &mut Countermeasures, Has<Player>)>,
+    mut projectiles: Query<(&mut Projectile, &Transform), Without<Countermeasures>>,
+    mut locks: Query<&mut MissileLock>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let dt = time.delta_seconds();
+    let rng = game_rng.stream(RngStream::Combat);
+    
+    for (entity, transform, mut countermeasures, is_player) in deployers.iter_mut() {
+        countermeasures.cooldown_timer = (countermeasures.cooldown_timer - dt).max(0.0);
+        
+        let wants_deploy = if is_player {
+            input.just_pressed(KeyCode::KeyF)
+        } else {
+            // AI pops flares when a missile homing on it gets close
+            projectiles.iter().any(|(p, t)| {
+                p.homing_target == Some(entity) && t.translation.distance(transform.translation) < 60.0
+            })
+        };
+        
+        if !wants_deploy {
+            continue;
+        }
+        if !countermeasures.can_deploy() {
+            if is_player && countermeasures.charges == 0 {
+                println!("[Combat] No countermeasures left!");
+            }
+            continue;
+        }
+        
+        countermeasures.charges -= 1;
+        countermeasures.cooldown_timer = countermeasures.cooldown;
+        
+        // Spawn a spread of flares behind the ship
+        let back = transform.back().as_vec3();
+        let mut decoy_entities = Vec::new();
+        for i in 0..3 {
+            let angle = i as f32 / 3.0 * std::f32::consts::TAU;
+            let spread = transform.right().as_vec3() * angle.cos() + transform.up().as_vec3() * angle.sin();
+            let decoy = commands.spawn((
+                PbrBundle {
+                    mesh: meshes.add(Sphere::new(0.4)),
+                    material: materials.add(StandardMaterial {
+                        base_color: Color::srgb(1.0, 0.8, 0.3),
+                        emissive: LinearRgba::rgb(1.0, 0.8, 0.3) * 10.0,
+                        ..default()
+                    }),
+                    transform: Transform::from_translation(transform.translation + back * 3.0),
+                    ..default()
+                },
+                Decoy { lifetime: 3.0 },
+                Velocity(back * 15.0 + spread * 10.0),
+            )).id();
+            decoy_entities.push(decoy);
+        }
+        
+        // Spoof incoming missiles
+        let mut spoofed = 0;
+        for (mut projectile, proj_transform) in projectiles.iter_mut() {
+            if projectile.homing_target == Some(entity)
+                && proj_transform.translation.distance(transform.translation) < 150.0
+                && rng.gen::<f32>() < 0.75
+            {
+                let decoy_idx = (rng.gen::<f32>() * decoy_entities.len() as f32) as usize;
+                projectile.homing_target = decoy_entities.get(decoy_idx).copied();
+                spoofed += 1;
+            }
+        }
+        
+        // Break locks on this ship
+        for mut lock in locks.iter_mut() {
+            if lock.candidate == Some(entity) || lock.target == Some(entity) {
+                lock.reset();
+            }
+        }
+        
+        if is_player {
+            println!("[Combat] Countermeasures deployed ({} left), {} missiles spoofed", countermeasures.charges, spoofed);
+        }
+    }
+}
+
+/// Burn out countermeasure decoys
+pub fn decoy_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Decoy, &mut Transform)>,
+) {
+    let dt = time.delta_seconds();
+    
+    for (entity, mut decoy, mut transform) in query.iter_mut() {
+        decoy.lifetime -= dt;
+        if decoy.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        } else {
+            // Flicker and shrink as the flare burns out
+            transform.scale = Vec3::splat((decoy.lifetime / 3.0).max(0.2) * (0.8 + rand::random::<f32>() * 0.4));
+        }
+    }
+}
+
+/// Give newly fired missiles and large area munitions hit points so they can be shot down
+pub fn mark_interceptable_projectiles_system(
+    mut commands: Commands,
+    new_projectiles: Query<(Entity, &Projectile), Added<Projectile>>,
+) {
+    for (entity, projectile) in new_projectiles.iter() {
+        if projectile.is_interceptable() {
+            commands.entity(entity).try_insert(ProjectileHealth {
+                current: 10.0 + projectile.area_damage,
+            });
+        }
+    }
+}
+
+/// Projectile interception - shots that cross a damageable projectile of another faction damage it
+pub fn projectile_intercept_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut threats: Query<(Entity, &Transform, &Faction, &mut ProjectileHealth)>,
+    shots: Query<(Entity, &Transform, &Velocity, &Projectile, &Faction), Without<ProjectileHealth>>,
+) {
+    let dt = time.delta_seconds();
+    let intercept_radius = 2.0;
+    
+    for (shot_entity, shot_transform, shot_velocity, shot, shot_faction) in shots.iter() {
+        // Sweep the shot's path over the last frame so fast rounds don't tunnel through
+        let end = shot_transform.translation;
+        let start = end - shot_velocity.0 * dt;
+        let segment = end - start;
+        let segment_len_sq = segment.length_squared().max(0.0001);
         
-        // Apply homing if we have a target
-        if let Some(target_pos) = target_pos {
-            let to_target = (target_pos - proj_transform.translation).normalize();
-            let current_dir = velocity.0.normalize();
+        for (threat_entity, threat_transform, threat_faction, mut threat_health) in threats.iter_mut() {
+            if threat_faction == shot_faction || threat_health.current <= 0.0 {
+                continue;
+            }
+            
+            let t = ((threat_transform.translation - start).dot(segment) / segment_len_sq).clamp(0.0, 1.0);
+            let closest = start + segment * t;
+            if closest.distance(threat_transform.translation) > intercept_radius {
+                continue;
+            }
             
-            // Blend current direction with target direction
-            let homing_factor = projectile.homing_strength * dt;
-            let new_dir = (current_dir + to_target * homing_factor).normalize();
+            threat_health.current -= shot.damage;
+            if !shot.piercing {
+                commands.entity(shot_entity).despawn();
+            }
             
-            // Update velocity maintaining speed
-            let speed = velocity.0.length();
-            velocity.0 = new_dir * speed;
+            if threat_health.current <= 0.0 {
+                if *shot_faction == Faction::Player {
+                    println!("[Turret] Point defense intercept!");
+                }
+                crate::systems::effects::spawn_hull_spark_effect(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    threat_transform.translation,
+                    -shot_velocity.0.normalize_or_zero(),
+                );
+                commands.entity(threat_entity).despawn();
+            }
             
-            // Debug: Log when missile first acquires target (only once)
-            if *proj_faction == Faction::Player && projectile.lifetime > 4.9 && projectile.lifetime < 5.0 {
-                println!("[Combat] Missile acquired target, homing strength: {}", projectile.homing_strength);
+            if !shot.piercing {
+                break;
             }
         }
     }
@@ -1311,14 +1768,23 @@ pub fn projectile_lifetime_system(
 pub fn projectile_collision_system(
     mut commands: Commands,
     projectiles: Query<(Entity, &Transform, &Projectile, &Faction)>,
-    mut ships: Query<(Entity, &Transform, &mut Health, &mut Shield, &Faction), Without<Projectile>>,
+    mut ships: Query<(Entity, &Transform, &mut Health, &mut Shield, &Faction, Option<&mut ShieldFacings>, Option<&mut crate::components::status_effects::StatusEffects>, Option<&crate::components::ship_classes::ClassBonuses>), Without<Projectile>>,
+    mut subsystem_pieces: Query<(&GlobalTransform, &crate::components::ship::ShipPiece, &mut crate::components::ship::SubsystemHealth)>,
+    subsystem_targeting: Res<crate::components::ship::SubsystemTargeting>,
+    mut hit_events: EventWriter<crate::systems::combat_feedback::HitEvent>,
+    player_ships: Query<(), With<Player>>,
+    mut reputation: ResMut<crate::components::Reputation>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
+    let rng = game_rng.stream(RngStream::Combat);
     for (proj_entity, proj_transform, projectile, proj_faction) in projectiles.iter() {
         let mut hit_primary = false;
+        // Wingmen fly under the player's faction - hit feedback is only for the player's own shots
+        let fired_by_player = player_ships.contains(projectile.owner);
         
-        for (ship_entity, ship_transform, mut health, mut shield, ship_faction) in ships.iter_mut() {
+        for (ship_entity, ship_transform, mut health, mut shield, ship_faction, mut facings, status_effects, bonuses) in ships.iter_mut() {
             // Don't hit own faction or owner
             if proj_faction == ship_faction || projectile.owner == ship_entity {
                 continue;
@@ -1332,8 +1798,31 @@ pub fn projectile_collision_system(
             let direct_hit = distance < 2.0;
             
             if direct_hit || (in_area && hit_primary) {
+                // Evasive pilots can slip a direct hit entirely (capped so nobody is untouchable)
+                let evasion_chance = bonuses.map_or(0.0, |b| b.evasion_chance.min(0.75));
+                if direct_hit && evasion_chance > 0.0 && rng.gen::<f32>() < evasion_chance {
+                    let against_player = player_ships.contains(ship_entity);
+                    if fired_by_player || against_player {
+                        hit_events.send(crate::systems::combat_feedback::HitEvent {
+                            position: proj_transform.translation,
+                            damage: 0.0,
+                            critical: false,
+                            evaded: true,
+                            against_player,
+                        });
+                    }
+                    
+                    // The shot is spent either way - piercing rounds carry on to the next ship
+                    if !projectile.piercing {
+                        commands.entity(proj_entity).despawn();
+                        break;
+                    }
+                    continue;
+                }
+                
                 // Calculate damage based on hit type
                 let damage_mult = if direct_hit { 1.0 } else { 0.5 }; // Area damage is 50%
+                let was_alive = health.current > 0.0;
                 
                 let hull_damage = projectile.damage * projectile.hull_damage_multiplier * damage_mult;
                 let shield_damage = projectile.damage * projectile.shield_damage_multiplier * damage_mult;
@@ -1349,11 +1838,39 @@ pub fn projectile_collision_system(
                     );
                 }
                 
+                // Only the shield facing toward the impact protects the hull
+                let facing = ShieldFacing::from_hit(ship_transform, proj_transform.translation);
+                let facing_shield = match facings.as_ref() {
+                    Some(facings) => facings.current[facing.index()],
+                    None => shield.current,
+                };
+                
+                // Plasma burns, ion cannons EMP (or disable, through a downed shield), flak slows
+                if direct_hit {
+                    if let (Some(mut status_effects), Some((kind, duration, magnitude))) = (
+                        status_effects,
+                        crate::components::status_effects::StatusEffectKind::from_weapon(projectile.weapon_type, facing_shield <= 0.0),
+                    ) {
+                        status_effects.apply(kind, duration, magnitude);
+                    }
+                }
+                
                 // Hit shields first
-                if shield.current > 0.0 {
-                    let shield_before = shield.current;
-                    shield.current -= shield_damage;
+                if facing_shield > 0.0 {
                     shield.time_since_last_hit = 0.0;
+                    let overflow = match facings.as_mut() {
+                        Some(facings) => {
+                            let overflow = facings.absorb(facing, shield_damage);
+                            shield.current = facings.total();
+                            overflow
+                        }
+                        None => {
+                            shield.current -= shield_damage;
+                            let overflow = (-shield.current).max(0.0);
+                            shield.current = shield.current.max(0.0);
+                            overflow
+                        }
+                    };
                     
                     // Spawn shield hit effect
                     if direct_hit {
@@ -1361,30 +1878,44 @@ pub fn projectile_collision_system(
                             &mut commands,
                             &mut meshes,
                             &mut materials,
-                            ship_transform.translation,
+                            ship_transform,
                             proj_transform.translation,
+                            facing,
                         );
                     }
                     
-                    if shield.current <= 0.0 {
-                        // Shield broken! Spawn break effect
-                        if shield_before > 0.0 {
-                            crate::systems::effects::spawn_shield_break_effect(
-                                &mut commands,
-                                &mut meshes,
-                                &mut materials,
-                                ship_transform.translation,
-                            );
-                        }
+                    if facing_shield <= shield_damage {
+                        // Facing broken! Spawn break effect
+                        crate::systems::effects::spawn_shield_break_effect(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            ship_transform.translation,
+                        );
                         
                         // Overflow damage to hull (using hull multiplier)
-                        health.current += shield.current * projectile.hull_damage_multiplier / projectile.shield_damage_multiplier;
-                        shield.current = 0.0;
+                        health.current -= overflow * projectile.hull_damage_multiplier / projectile.shield_damage_multiplier;
                     }
                 } else {
                     // Hull hit - spawn sparks
                     health.current -= hull_damage;
                     
+                    // Hull hits also damage the nearby subsystem (or the player's focused subsystem)
+                    if direct_hit {
+                        let focus = if fired_by_player {
+                            subsystem_targeting.target
+                        } else {
+                            None
+                        };
+                        crate::systems::subsystems::damage_subsystem(
+                            &mut subsystem_pieces,
+                            ship_entity,
+                            proj_transform.translation,
+                            hull_damage,
+                            focus,
+                        );
+                    }
+                    
                     if direct_hit {
                         let projectile_dir = (proj_transform.translation - ship_transform.translation).normalize();
                         crate::systems::effects::spawn_hull_spark_effect(
@@ -1397,9 +1928,30 @@ pub fn projectile_collision_system(
                     }
                 }
                 
+                // Factions remember who shoots their ships - and who shoots their enemies
+                if fired_by_player {
+                    if direct_hit {
+                        reputation.record_attack(*ship_faction);
+                    }
+                    if was_alive && health.current <= 0.0 {
+                        reputation.record_kill(*ship_faction);
+                    }
+                }
+                
                 if direct_hit {
                     hit_primary = true;
                     
+                    // Hit marker and damage number for the player's shots
+                    if fired_by_player {
+                        hit_events.send(crate::systems::combat_feedback::HitEvent {
+                            position: proj_transform.translation,
+                            damage: if facing_shield > 0.0 { shield_damage } else { hull_damage },
+                            critical: projectile.is_critical,
+                            evaded: false,
+                            against_player: false,
+                        });
+                    }
+                    
                     // Spawn area damage effect for missiles
                     if projectile.area_damage > 0.0 {
                         crate::systems::effects::spawn_explosion(
@@ -1424,12 +1976,16 @@ pub fn projectile_collision_system(
 /// Damage system
 pub fn damage_system(
     mut commands: Commands,
-    query: Query<(Entity, &Health, Option<&Enemy>)>,
+    query: Query<(Entity, &Health, Option<&Enemy>, Option<&crate::components::Wingman>, Option<&crate::components::Civilian>)>,
 ) {
-    for (entity, health, enemy) in query.iter() {
+    for (entity, health, enemy, wingman, civilian) in query.iter() {
         if health.current <= 0.0 {
             if let Some(enemy) = enemy {
                 println!("[Damage System] Marking {:?} as dead (Health: {:.1})", enemy.enemy_type, health.current);
+            } else if let Some(wingman) = wingman {
+                println!("[Damage System] Wingman {} is down (Health: {:.1})", wingman.callsign, health.current);
+            } else if let Some(civilian) = civilian {
+                println!("[Damage System] Marking civilian {:?} as dead (Health: {:.1})", civilian.kind, health.current);
             } else {
                 println!("[Damage System] Marking player as dead (Health: {:.1})", health.current);
             }
@@ -1441,15 +1997,75 @@ pub fn damage_system(
 /// Shield recharge system
 pub fn shield_recharge_system(
     time: Res<Time>,
-    mut query: Query<&mut Shield>,
+    mut query: Query<(&mut Shield, Option<&crate::components::ship::SubsystemStatus>, Option<&mut ShieldFacings>, Option<&PowerDistribution>)>,
 ) {
     let dt = time.delta_seconds();
     
-    for mut shield in query.iter_mut() {
+    for (mut shield, subsystems, facings, power) in query.iter_mut() {
         shield.time_since_last_hit += dt;
         
+        // Abilities, upgrades and loading write the pool directly - spread any change over the facings
+        let mut facings = facings;
+        if let Some(facings) = facings.as_mut() {
+            if (facings.total() - shield.current).abs() > 0.01 {
+                facings.redistribute(shield.current);
+            }
+        }
+        
+        // Destroyed shield emitters stop recharge entirely
+        if subsystems.map_or(false, |s| !s.shields_online()) {
+            continue;
+        }
+        
         if shield.time_since_last_hit >= shield.recharge_delay {
-            shield.current = (shield.current + shield.recharge_rate * dt).min(shield.max);
+            let recharge_rate = shield.recharge_rate * power.map_or(1.0, |p| p.multiplier(PowerSystem::Shields));
+            match facings.as_mut() {
+                Some(facings) => {
+                    facings.recharge(recharge_rate * dt, shield.max);
+                    shield.current = facings.total();
+                }
+                None => {
+                    shield.current = (shield.current + recharge_rate * dt).min(shield.max);
+                }
+            }
+        }
+    }
+}
+
+/// Split every new shield pool into directional facings
+pub fn shield_facings_setup_system(
+    mut commands: Commands,
+    new_shields: Query<(Entity, &Shield), (Added<Shield>, Without<ShieldFacings>)>,
+) {
+    for (entity, shield) in new_shields.iter() {
+        commands.entity(entity).try_insert(ShieldFacings::new(shield.current));
+    }
+}
+
+/// Shunt shield capacity between facings: arrow keys reinforce a facing, Home rebalances
+pub fn shield_shunt_system(
+    input: Res<PlayerInput>,
+    mut query: Query<(&Shield, &mut ShieldFacings), With<Player>>,
+) {
+    let shunt = [
+        (KeyCode::ArrowUp, ShieldFacing::Front),
+        (KeyCode::ArrowDown, ShieldFacing::Rear),
+        (KeyCode::ArrowLeft, ShieldFacing::Left),
+        (KeyCode::ArrowRight, ShieldFacing::Right),
+    ];
+    
+    for (shield, mut facings) in query.iter_mut() {
+        for (key, facing) in shunt {
+            if input.just_pressed(key) {
+                facings.shunt(facing, shield.max);
+                println!("[Shields] Shunted power to {} shields ({:.0}% capacity)",
+                    facing.short_name(), facings.allocation[facing.index()] * 100.0);
+            }
+        }
+        
+        if input.just_pressed(KeyCode::Home) {
+            facings.balance();
+            println!("[Shields] Shield facings balanced");
         }
     }
 }
@@ -1464,10 +2080,19 @@ pub fn ship_death_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut next_state: ResMut<NextState<GameState>>,
-    query: Query<(Entity, &Transform, Option<&Enemy>), With<DeadShip>>,
+    query: Query<(Entity, &Transform, Option<&Enemy>, Option<&crate::components::Boss>), With<DeadShip>>,
     player_query: Query<Entity, With<Player>>,
+    mut galaxy: Option<ResMut<crate::resources::Galaxy>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    for (entity, transform, enemy) in query.iter() {
+    let rng = game_rng.stream(RngStream::Loot);
+    let difficulty = galaxy
+        .as_ref()
+        .and_then(|g| g.current_system())
+        .map(|s| s.difficulty)
+        .unwrap_or(1);
+    
+    for (entity, transform, enemy, boss) in query.iter() {
         // Spawn explosion effect
         crate::systems::effects::spawn_explosion(
             &mut commands,
@@ -1489,7 +2114,35 @@ pub fn ship_death_system(
                 &mut materials,
                 transform,
                 enemy,
+                rng,
+            );
+            
+            // Occasionally drop a rolled weapon item
+            crate::systems::weapon_items::spawn_weapon_drop_for_enemy(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                transform,
+                enemy,
+                difficulty,
+                rng,
             );
+            
+            // Bosses always drop a rare weapon and stay dead
+            if let Some(boss) = boss {
+                println!("[Boss] {} destroyed!", boss.name);
+                crate::systems::weapon_items::spawn_weapon_drop(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    transform,
+                    crate::components::WeaponItem::roll_boss_reward(difficulty, rng),
+                    rng,
+                );
+                if let Some(galaxy) = galaxy.as_mut() {
+                    galaxy.mark_boss_defeated(boss.system_id);
+                }
+            }
         }
         
         commands.entity(entity).despawn_recursive();
@@ -1500,15 +2153,42 @@ pub fn ship_death_system(
 #[derive(Component)]
 pub struct ShouldSpawnLoot;
 
+/// Power distribution input - F1/F2/F3 move a pip to weapons/shields/engines, F4 rebalances
+pub fn power_distribution_input_system(
+    input: Res<PlayerInput>,
+    mut query: Query<&mut PowerDistribution, With<Player>>,
+) {
+    for mut power in query.iter_mut() {
+        let boosted = [
+            (KeyCode::F1, PowerSystem::Weapons),
+            (KeyCode::F2, PowerSystem::Shields),
+            (KeyCode::F3, PowerSystem::Engines),
+        ].into_iter().find(|(key, _)| input.just_pressed(*key));
+        
+        if let Some((_, system)) = boosted {
+            if power.boost(system) {
+                println!("[Power] {:?} +1 pip - WPN {} / SHD {} / ENG {}",
+                    system, power.weapons, power.shields, power.engines);
+            }
+        }
+        
+        if input.just_pressed(KeyCode::F4) {
+            *power = PowerDistribution::balanced();
+            println!("[Power] Power distribution balanced");
+        }
+    }
+}
+
 /// Energy recharge system
 pub fn energy_recharge_system(
     time: Res<Time>,
-    mut query: Query<&mut Energy>,
+    mut query: Query<(&mut Energy, Option<&PowerDistribution>)>,
 ) {
     let dt = time.delta_seconds();
     
-    for mut energy in query.iter_mut() {
-        energy.current = (energy.current + energy.recharge_rate * dt).min(energy.max);
+    for (mut energy, power) in query.iter_mut() {
+        let power_multiplier = power.map_or(1.0, |p| p.multiplier(PowerSystem::Weapons));
+        energy.current = (energy.current + energy.recharge_rate * power_multiplier * dt).min(energy.max);
     }
 }
 
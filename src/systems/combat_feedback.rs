@@ -33,6 +33,8 @@ pub struct HitEvent {
     pub position: Vec3,
     pub damage: f32,
     pub critical: bool,
+    pub evaded: bool,         // Evasion avoided all damage
+    pub against_player: bool, // Shot was aimed at the player (shown as EVADED rather than MISS)
 }
 
 /// Event when player kills an enemy
@@ -57,89 +59,130 @@ pub fn spawn_hit_marker_system(
     
     for event in hit_events.read() {
         // Project 3D position to screen space
-        if let Some(screen_pos) = camera.world_to_viewport(camera_transform, event.position) {
-            // Spawn hit marker UI
-            commands.spawn((
-                NodeBundle {
-                    style: Style {
-                        position_type: PositionType::Absolute,
-                        left: Val::Px(screen_pos.x - 25.0),
-                        top: Val::Px(screen_pos.y - 25.0),
-                        width: Val::Px(50.0),
-                        height: Val::Px(50.0),
-                        ..default()
-                    },
-                    z_index: ZIndex::Global(50),
-                    ..default()
-                },
-                HitMarker { lifetime: 0.2 },
-            )).with_children(|parent| {
-                // X-shaped hit marker
-                let color = if event.critical {
-                    colors::NEON_YELLOW // Critical hit in yellow
-                } else {
-                    Color::WHITE // Normal hit in white
-                };
-                
-                // Horizontal line
-                parent.spawn(NodeBundle {
-                    style: Style {
-                        position_type: PositionType::Absolute,
-                        width: Val::Px(40.0),
-                        height: Val::Px(3.0),
-                        left: Val::Px(5.0),
-                        top: Val::Px(23.5),
-                        ..default()
-                    },
-                    background_color: color.into(),
-                    ..default()
-                });
-                
-                // Vertical line
-                parent.spawn(NodeBundle {
-                    style: Style {
-                        position_type: PositionType::Absolute,
-                        width: Val::Px(3.0),
-                        height: Val::Px(40.0),
-                        left: Val::Px(23.5),
-                        top: Val::Px(5.0),
-                        ..default()
-                    },
-                    background_color: color.into(),
-                    ..default()
-                });
-            });
-            
-            // Spawn floating damage number
+        let Some(screen_pos) = camera.world_to_viewport(camera_transform, event.position) else {
+            continue;
+        };
+        
+        // Evaded shots get a popup instead of a marker
+        if event.evaded {
+            let (label, color) = if event.against_player {
+                ("EVADED", colors::NEON_CYAN)
+            } else {
+                ("MISS", Color::srgb(0.7, 0.7, 0.7))
+            };
             commands.spawn((
                 TextBundle {
                     text: Text::from_section(
-                        format!("{}", event.damage as i32),
+                        label,
                         TextStyle {
-                            font_size: if event.critical { 28.0 } else { 20.0 },
-                            color: if event.critical {
-                                colors::NEON_YELLOW
-                            } else {
-                                Color::WHITE
-                            },
+                            font_size: 22.0,
+                            color,
                             ..default()
                         },
                     ),
                     style: Style {
                         position_type: PositionType::Absolute,
-                        left: Val::Px(screen_pos.x + 30.0),
-                        top: Val::Px(screen_pos.y - 10.0),
+                        left: Val::Px(screen_pos.x - 30.0),
+                        top: Val::Px(screen_pos.y - 30.0),
                         ..default()
                     },
                     z_index: ZIndex::Global(51),
                     ..default()
                 },
                 DamageNumber {
-                    lifetime: 1.0,
-                    velocity: Vec3::new(0.0, 50.0, 0.0), // Float upward
+                    lifetime: 0.8,
+                    velocity: Vec3::new(0.0, 30.0, 0.0),
                 },
             ));
+            continue;
         }
+        
+        // Critical hits get a larger, thicker, boxed marker that lingers a little longer
+        let (size, thickness, lifetime, color) = if event.critical {
+            (70.0, 5.0, 0.35, colors::NEON_YELLOW)
+        } else {
+            (50.0, 3.0, 0.2, Color::WHITE) // Normal hit in white
+        };
+        let line = size - 10.0;
+        
+        // Spawn hit marker UI
+        let mut marker = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(screen_pos.x - size / 2.0),
+                    top: Val::Px(screen_pos.y - size / 2.0),
+                    width: Val::Px(size),
+                    height: Val::Px(size),
+                    border: if event.critical { UiRect::all(Val::Px(2.0)) } else { UiRect::ZERO },
+                    ..default()
+                },
+                border_color: color.into(),
+                z_index: ZIndex::Global(50),
+                ..default()
+            },
+            HitMarker { lifetime },
+        ));
+        marker.with_children(|parent| {
+            // Horizontal line
+            parent.spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(line),
+                    height: Val::Px(thickness),
+                    left: Val::Px((size - line) / 2.0),
+                    top: Val::Px((size - thickness) / 2.0),
+                    ..default()
+                },
+                background_color: color.into(),
+                ..default()
+            });
+            
+            // Vertical line
+            parent.spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(thickness),
+                    height: Val::Px(line),
+                    left: Val::Px((size - thickness) / 2.0),
+                    top: Val::Px((size - line) / 2.0),
+                    ..default()
+                },
+                background_color: color.into(),
+                ..default()
+            });
+        });
+        
+        // Spawn floating damage number - crits are bigger, orange and shout about it
+        let (text, font_size, number_color) = if event.critical {
+            (format!("{}!", event.damage as i32), 32.0, colors::NEON_ORANGE)
+        } else {
+            (format!("{}", event.damage as i32), 20.0, Color::WHITE)
+        };
+        commands.spawn((
+            TextBundle {
+                text: Text::from_section(
+                    text,
+                    TextStyle {
+                        font_size,
+                        color: number_color,
+                        ..default()
+                    },
+                ),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(screen_pos.x + size / 2.0 + 5.0),
+                    top: Val::Px(screen_pos.y - 10.0),
+                    ..default()
+                },
+                z_index: ZIndex::Global(51),
+                ..default()
+            },
+            DamageNumber {
+                lifetime: 1.0,
+                velocity: Vec3::new(0.0, if event.critical { 70.0 } else { 50.0 }, 0.0), // Float upward
+            },
+        ));
     }
 }
 
@@ -156,7 +199,7 @@ pub fn update_hit_markers_system(
             commands.entity(entity).despawn_recursive();
         } else {
             // Fade out
-            let alpha = marker.lifetime / 0.2;
+            let alpha = (marker.lifetime / 0.2).min(1.0);
             let current_color = color.0;
             *color = Color::srgba(
                 current_color.to_srgba().red,
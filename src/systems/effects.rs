@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::components::combat::ShieldFacing;
 
 /// Explosion effect marker
 #[derive(Component)]
@@ -24,6 +25,7 @@ pub struct ShieldHitEffect {
     pub lifetime: f32,
     pub max_lifetime: f32,
     pub impact_point: Vec3,
+    pub base_scale: Vec3, // Shape the effect pulses from (flattened for single facings)
 }
 
 /// Hull spark effect marker
@@ -33,6 +35,13 @@ pub struct HullSparkEffect {
     pub max_lifetime: f32,
 }
 
+/// Warp arrival flash marker
+#[derive(Component)]
+pub struct WarpFlash {
+    pub lifetime: f32,
+    pub max_lifetime: f32,
+}
+
 /// Spawn an explosion effect with multiple stages
 pub fn spawn_explosion(
     commands: &mut Commands,
@@ -195,13 +204,19 @@ pub fn spawn_shield_hit_effect(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
-    ship_position: Vec3,
+    ship_transform: &Transform,
     impact_point: Vec3,
+    facing: ShieldFacing,
 ) {
-    // Create an oval shield bubble around the ship
+    // Light up only the struck quadrant: a flattened shell pushed out toward that facing
+    let facing_direction = ship_transform.rotation * facing.local_direction();
+    let base_scale = match facing {
+        ShieldFacing::Front | ShieldFacing::Rear => Vec3::new(1.2, 1.0, 0.35),
+        ShieldFacing::Left | ShieldFacing::Right => Vec3::new(0.35, 1.0, 1.2),
+    };
     commands.spawn((
         PbrBundle {
-            mesh: meshes.add(Sphere::new(4.0)), // Large enough to encompass ship
+            mesh: meshes.add(Sphere::new(4.0)), // Large enough to cover the ship's side
             material: materials.add(StandardMaterial {
                 base_color: Color::srgba(0.2, 0.5, 1.0, 0.3),
                 emissive: Color::srgb(0.5, 1.0, 2.0).into(),
@@ -209,20 +224,22 @@ pub fn spawn_shield_hit_effect(
                 cull_mode: None,
                 ..default()
             }),
-            transform: Transform::from_translation(ship_position)
-                .with_scale(Vec3::new(1.2, 1.0, 1.2)), // Slightly oval
+            transform: Transform::from_translation(ship_transform.translation + facing_direction * 2.5)
+                .with_rotation(ship_transform.rotation)
+                .with_scale(base_scale),
             ..default()
         },
         ShieldHitEffect {
             lifetime: 0.0,
             max_lifetime: 0.2,
             impact_point,
+            base_scale,
         },
     ));
     
     // Add a bright flash at the impact point
-    let direction = (impact_point - ship_position).normalize();
-    let flash_pos = ship_position + direction * 3.5;
+    let direction = (impact_point - ship_transform.translation).normalize_or_zero();
+    let flash_pos = ship_transform.translation + direction * 3.5;
     
     commands.spawn((
         PbrBundle {
@@ -239,6 +256,7 @@ pub fn spawn_shield_hit_effect(
             lifetime: 0.0,
             max_lifetime: 0.15,
             impact_point,
+            base_scale: Vec3::new(1.2, 1.0, 1.2),
         },
     ));
 }
@@ -341,6 +359,7 @@ pub fn spawn_shield_break_effect(
             lifetime: 0.0,
             max_lifetime: 0.4,
             impact_point: position,
+            base_scale: Vec3::new(1.2, 1.0, 1.2),
         },
     ));
     
@@ -365,6 +384,7 @@ pub fn spawn_shield_break_effect(
                 lifetime: 0.0,
                 max_lifetime: 0.6,
                 impact_point: position,
+                base_scale: Vec3::new(1.2, 1.0, 1.2),
             },
         ));
     }
@@ -617,7 +637,7 @@ pub fn update_shield_effects(
                     if progress < 0.5 {
                         // Shield bubble expands then fades
                         let scale = 1.0 + progress * 0.3;
-                        transform.scale = Vec3::new(scale * 1.2, scale * 1.0, scale * 1.2);
+                        transform.scale = effect.base_scale * scale;
                     } else {
                         // Collapse effect for shield break
                         let collapse = 1.0 - (progress - 0.5) * 2.0;
@@ -633,3 +653,56 @@ pub fn update_shield_effects(
     }
 }
 
+
+/// Warp arrival flash - a bright streak along the arrival heading that blooms and fades
+pub fn spawn_warp_flash(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+    direction: Vec3,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(2.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.7, 0.85, 1.0, 0.8),
+                emissive: Color::srgb(4.0, 6.0, 10.0).into(),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            // Stretched along the heading so it reads as a streak out of warp
+            transform: Transform::from_translation(position)
+                .with_rotation(Quat::from_rotation_arc(Vec3::Z, direction.normalize_or(Vec3::Z)))
+                .with_scale(Vec3::new(0.6, 0.6, 4.0)),
+            ..default()
+        },
+        WarpFlash {
+            lifetime: 0.0,
+            max_lifetime: 0.5,
+        },
+    ));
+}
+
+/// Warp flashes collapse to a point as they fade
+pub fn update_warp_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut WarpFlash, &mut Transform, &Handle<StandardMaterial>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut flash, mut transform, material_handle) in query.iter_mut() {
+        flash.lifetime += time.delta_seconds();
+        if flash.lifetime >= flash.max_lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        
+        let remaining = 1.0 - flash.lifetime / flash.max_lifetime;
+        transform.scale = Vec3::new(0.6 + (1.0 - remaining) * 1.4, 0.6 + (1.0 - remaining) * 1.4, 4.0 * remaining);
+        if let Some(material) = materials.get_mut(material_handle) {
+            material.base_color.set_alpha(0.8 * remaining);
+        }
+    }
+}
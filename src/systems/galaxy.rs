@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use crate::components::galaxy::*;
 use crate::components::travel::*;
-use crate::resources::Galaxy;
+use crate::resources::{Galaxy, RunClock};
 
 /// Resource flag to trigger system content spawning
 #[derive(Resource)]
@@ -101,6 +101,16 @@ fn spawn_planet(
             orbit_offset: planet_data.orbit_offset,
             center,
         },
+        Obstacle {
+            radius: planet_data.size,
+        },
+        // Skimming the upper atmosphere sets ships on fire
+        crate::components::status_effects::HazardZone {
+            radius: planet_data.size * 1.4,
+            kind: crate::components::status_effects::StatusEffectKind::Burning,
+            duration: 3.0,
+            magnitude: 5.0,
+        },
     ));
 }
 
@@ -133,6 +143,9 @@ fn spawn_jump_gate(
             target_system_id,
             activation_range: 30.0,
         },
+        Obstacle {
+            radius: 16.5, // Ring major radius plus its thickness
+        },
     )).id();
     
     // Create gate ring (torus)
@@ -188,13 +201,22 @@ fn spawn_jump_gate(
     println!("[Galaxy System] Spawned jump gate to system {} at {:?}", target_system_id, position);
 }
 
-/// Update planet orbits
-pub fn update_planet_orbits(
+/// Advance the run clock by one simulation tick
+pub fn advance_run_clock_system(
     time: Res<Time>,
+    mut run_clock: ResMut<RunClock>,
+) {
+    run_clock.elapsed += time.delta_seconds();
+}
+
+/// Update planet orbits - keyed to the run clock, so planets (and what steers around them)
+/// are in the same place on the same tick of a replay
+pub fn update_planet_orbits(
+    run_clock: Res<RunClock>,
     mut query: Query<(&mut Transform, &Planet)>,
 ) {
     for (mut transform, planet) in query.iter_mut() {
-        let elapsed = time.elapsed_seconds();
+        let elapsed = run_clock.elapsed;
         let angle = planet.orbit_offset + (elapsed * planet.orbit_speed);
         
         let x = planet.center.x + planet.orbit_radius * angle.cos();
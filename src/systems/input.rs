@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy::input::mouse::MouseMotion;
+use crate::resources::PlayerInput;
+
+/// Latch this frame's keyboard and mouse into the player's tick input. Presses and motion pile
+/// up until a simulation tick consumes them.
+pub fn sample_player_input_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut input: ResMut<PlayerInput>,
+) {
+    input.keys_held = keyboard.get_pressed().copied().collect();
+    input.keys_pressed.extend(keyboard.get_just_pressed().copied());
+    input.buttons_held = mouse.get_pressed().copied().collect();
+    input.buttons_pressed.extend(mouse.get_just_pressed().copied());
+    input.buttons_released.extend(mouse.get_just_released().copied());
+    for motion in mouse_motion.read() {
+        input.mouse_delta += motion.delta;
+    }
+}
+
+/// Last step of every simulation tick - the presses have been acted on
+pub fn consume_player_input_system(mut input: ResMut<PlayerInput>) {
+    input.consume_edges();
+}
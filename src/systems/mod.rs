@@ -1,4 +1,5 @@
 pub mod movement;
+pub mod input;
 pub mod combat;
 pub mod ai;
 pub mod spawning;
@@ -21,4 +22,13 @@ pub mod ship_visuals;
 pub mod skill_tree_ui;
 pub mod stat_visualization;
 pub mod upgrade_effects;
-
+pub mod weapon_items;
+pub mod subsystems;
+pub mod status_effects;
+pub mod squadron;
+pub mod carrier;
+pub mod wingman;
+pub mod traffic;
+pub mod boss;
+pub mod replay;
+pub mod simulation;
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 use bevy::window::{PrimaryWindow, CursorGrabMode};
-use bevy::input::mouse::MouseMotion;
+use bevy::time::Fixed;
 use crate::components::ship::*;
+use crate::components::combat::{PowerDistribution, PowerSystem};
+use crate::resources::PlayerInput;
+use crate::utils::math;
 
 /// Mouse sensitivity resource
 #[derive(Resource)]
@@ -28,11 +31,11 @@ pub struct MouseFlightState {
 
 /// Ship movement system - handles player input and applies forces
 pub fn ship_movement_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    keyboard: Res<PlayerInput>,
     time: Res<Time>,
-    mut query: Query<(&Ship, &mut Velocity, &mut AngularVelocity, &Transform), With<Player>>,
+    mut query: Query<(&Ship, &mut Velocity, &mut AngularVelocity, &Transform, Option<&PowerDistribution>, Option<&SubsystemStatus>), With<Player>>,
 ) {
-    for (ship, mut velocity, mut angular_velocity, transform) in query.iter_mut() {
+    for (ship, mut velocity, mut angular_velocity, transform, power, subsystems) in query.iter_mut() {
         let dt = time.delta_seconds();
         
         // Get ship's local axes
@@ -67,11 +70,13 @@ pub fn ship_movement_system(
             thrust -= up.as_vec3();
         }
         
-        // Apply boost
+        // Apply boost, scaled by engine power and surviving engines
+        let engine_power = power.map_or(1.0, |p| p.multiplier(PowerSystem::Engines))
+            * subsystems.map_or(1.0, |s| s.engine_factor());
         let speed_multiplier = if keyboard.pressed(KeyCode::ShiftLeft) {
-            ship.boost_multiplier
+            ship.boost_multiplier * engine_power
         } else {
-            1.0
+            engine_power
         };
         
         // Normalize thrust and apply acceleration
@@ -81,7 +86,7 @@ pub fn ship_movement_system(
         }
         
         // Apply drag
-        velocity.0 *= 0.98;
+        velocity.0 *= math::damping(0.98, dt);
         
         // Speed limit
         let max_speed = ship.max_speed * speed_multiplier;
@@ -101,23 +106,20 @@ pub fn ship_movement_system(
         angular_velocity.0.z = roll * dt;
         
         // Angular drag
-        angular_velocity.0 *= 0.9;
+        angular_velocity.0 *= math::damping(0.9, dt);
     }
 }
 
 /// Mouse flight system - uses mouse delta movement for flight control
 pub fn mouse_flight_system(
-    mut mouse_motion: EventReader<MouseMotion>,
+    input: Res<PlayerInput>,
     settings: Res<MouseFlightSettings>,
     mut flight_state: ResMut<MouseFlightState>,
     time: Res<Time>,
     mut query: Query<(&Ship, &mut Transform), With<Player>>,
 ) {
-    // Accumulate mouse delta
-    let mut delta = Vec2::ZERO;
-    for motion in mouse_motion.read() {
-        delta += motion.delta;
-    }
+    // Mouse motion gathered since the last tick
+    let delta = input.mouse_delta;
     
     if delta.length_squared() < 0.001 {
         return;
@@ -177,6 +179,63 @@ pub fn apply_angular_velocity_system(
     }
 }
 
+/// Start of each simulation tick - put moving entities back on their simulated pose (rendering
+/// left them part way between ticks) and remember it as the pose the tick starts from
+pub fn begin_interpolation_tick_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, Option<&mut TransformInterpolation>), With<Velocity>>,
+) {
+    for (entity, mut transform, interpolation) in query.iter_mut() {
+        let Some(mut interpolation) = interpolation else {
+            commands.entity(entity).insert(TransformInterpolation::new(*transform));
+            continue;
+        };
+
+        let moved_outside_simulation = transform.translation != interpolation.rendered.translation
+            || transform.rotation != interpolation.rendered.rotation;
+        if moved_outside_simulation {
+            // Teleported (jump, respawn, load) - start from here rather than sliding over
+            interpolation.current.translation = transform.translation;
+            interpolation.current.rotation = transform.rotation;
+        } else {
+            transform.translation = interpolation.current.translation;
+            transform.rotation = interpolation.current.rotation;
+        }
+        interpolation.previous = interpolation.current;
+    }
+}
+
+/// End of each simulation tick - record where the tick left everything
+pub fn end_interpolation_tick_system(
+    mut query: Query<(&Transform, &mut TransformInterpolation)>,
+) {
+    for (transform, mut interpolation) in query.iter_mut() {
+        interpolation.current = *transform;
+        interpolation.rendered = *transform;
+    }
+}
+
+/// Draw moving entities between their last two simulated poses, by how far the clock has run
+/// into the next tick - motion stays smooth at any frame rate
+pub fn interpolate_transforms_system(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut TransformInterpolation)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+
+    for (mut transform, mut interpolation) in query.iter_mut() {
+        // Moved outside the simulation since the tick - leave it where it was put
+        if transform.translation != interpolation.rendered.translation
+            || transform.rotation != interpolation.rendered.rotation
+        {
+            continue;
+        }
+        transform.translation = interpolation.previous.translation.lerp(interpolation.current.translation, alpha);
+        transform.rotation = interpolation.previous.rotation.slerp(interpolation.current.rotation, alpha);
+        interpolation.rendered = *transform;
+    }
+}
+
 /// Manage cursor locking for flight controls
 pub fn manage_cursor_lock(
     mut window_query: Query<&mut Window, With<PrimaryWindow>>,
@@ -199,3 +258,89 @@ pub fn release_cursor_lock(
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    /// Ticks run so far, and where each one left the player
+    #[derive(Resource, Default)]
+    struct Trajectory {
+        ticks: u32,
+        poses: Vec<Transform>,
+    }
+
+    /// Scripted controls keyed to the tick, like a recording would feed them
+    fn scripted_input_system(trajectory: Res<Trajectory>, mut input: ResMut<PlayerInput>) {
+        let tick = trajectory.ticks;
+        *input = PlayerInput::default();
+        input.keys_held.insert(KeyCode::KeyW);
+        if tick % 90 < 45 {
+            input.keys_held.insert(KeyCode::KeyD);
+        }
+        if (120..180).contains(&tick) {
+            input.keys_held.insert(KeyCode::ShiftLeft);
+            input.keys_held.insert(KeyCode::KeyQ);
+        }
+        if tick % 30 < 10 {
+            input.mouse_delta = Vec2::new(6.0, -2.0);
+        }
+    }
+
+    fn record_pose_system(player: Query<&Transform, With<Player>>, mut trajectory: ResMut<Trajectory>) {
+        trajectory.ticks += 1;
+        trajectory.poses.push(*player.single());
+    }
+
+    /// Run the real flight model on `Time<Fixed>` for `seconds`, rendering frames `frame_delta`
+    /// apart (interpolated in between, as the game does)
+    fn fly(frame_delta: Duration, seconds: f64) -> Vec<Transform> {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(frame_delta))
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .init_resource::<PlayerInput>()
+            .init_resource::<MouseFlightSettings>()
+            .init_resource::<MouseFlightState>()
+            .init_resource::<Trajectory>()
+            .add_systems(FixedFirst, (begin_interpolation_tick_system, scripted_input_system).chain())
+            .add_systems(FixedUpdate, (
+                ship_movement_system,
+                mouse_flight_system,
+                apply_velocity_system,
+                apply_angular_velocity_system,
+            ).chain())
+            .add_systems(FixedLast, (end_interpolation_tick_system, record_pose_system).chain())
+            .add_systems(PostUpdate, interpolate_transforms_system);
+        app.world_mut().spawn((
+            Transform::default(),
+            Ship::fighter(),
+            Velocity::default(),
+            AngularVelocity::default(),
+            Player,
+        ));
+
+        for _ in 0..(seconds / frame_delta.as_secs_f64()).round() as u32 {
+            app.update();
+        }
+        app.world_mut().resource_mut::<Trajectory>().poses.split_off(0)
+    }
+
+    #[test]
+    fn fixed_step_trajectory_independent_of_frame_rate() {
+        let at_30 = fly(Duration::from_secs_f64(1.0 / 30.0), 5.0);
+        let at_144 = fly(Duration::from_secs_f64(1.0 / 144.0), 5.0);
+
+        // Both frame rates ran (nearly) five seconds of ticks...
+        assert!(at_30.len() >= 295 && at_144.len() >= 295, "{} / {} ticks", at_30.len(), at_144.len());
+        // ...and every tick they share left the player in exactly the same place
+        for (tick, (a, b)) in at_30.iter().zip(&at_144).enumerate() {
+            assert_eq!(a.translation, b.translation, "position differs at tick {}", tick);
+            assert_eq!(a.rotation, b.rotation, "rotation differs at tick {}", tick);
+        }
+        // It actually flew somewhere
+        assert!(at_30.last().unwrap().translation.length() > 50.0);
+    }
+}
@@ -0,0 +1,651 @@
+use bevy::prelude::*;
+use bevy::time::Fixed;
+use std::fs;
+use std::path::PathBuf;
+use crate::components::combat::*;
+use crate::components::encounter::EncounterDirector;
+use crate::components::resources::{Inventory, Loot};
+use crate::components::ship::*;
+use crate::components::ship_classes::ClassProgression;
+use crate::resources::*;
+use crate::systems::movement::MouseFlightSettings;
+use crate::systems::ui::{LoadGameFlag, RestartGameFlag, RestartSeed};
+
+/// Get replay file path
+fn get_replay_path() -> PathBuf {
+    let mut path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push(".space_combat_game");
+    fs::create_dir_all(&path).ok();
+    path.push("replay.json");
+    path
+}
+
+/// Write a recorded run to disk
+pub fn save_replay(data: &ReplayData) -> Result<(), String> {
+    let json = serde_json::to_string(data)
+        .map_err(|e| format!("Failed to serialize replay: {}", e))?;
+
+    let replay_path = get_replay_path();
+    fs::write(&replay_path, json)
+        .map_err(|e| format!("Failed to write replay file: {}", e))?;
+
+    println!("[Replay] Saved {} ticks to {:?}", data.tick_count(), replay_path);
+    Ok(())
+}
+
+/// Read the last recorded run from disk
+pub fn load_replay() -> Result<ReplayData, String> {
+    let replay_path = get_replay_path();
+
+    if !replay_path.exists() {
+        return Err("No replay file found".to_string());
+    }
+
+    let json = fs::read_to_string(&replay_path)
+        .map_err(|e| format!("Failed to read replay file: {}", e))?;
+
+    serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to deserialize replay: {}", e))
+}
+
+/// Restart the run from `seed` with the clock held until the fresh world has spawned
+fn restart_run(commands: &mut Commands, replay: &mut Replay, time: &mut Time<Virtual>, seed: u64) {
+    commands.insert_resource(RestartSeed(seed));
+    commands.insert_resource(RestartGameFlag);
+    replay.tick = 0;
+    replay.diverged_at = None;
+    replay.settle_frames = SETTLE_FRAMES;
+    time.pause();
+}
+
+/// Leave recording/playback - a recording is written out first
+fn stop_replay(replay: &mut Replay, time: &mut Time<Virtual>) {
+    if replay.mode == ReplayMode::Recording {
+        if let Some(data) = &replay.data {
+            if let Err(e) = save_replay(data) {
+                println!("[Replay] {}", e);
+            }
+        }
+    }
+    if replay.mode == ReplayMode::Playing {
+        println!("[Replay] Playback stopped at tick {}", replay.tick);
+    }
+
+    replay.mode = ReplayMode::Idle;
+    replay.seek_target = None;
+    replay.settle_frames = 0;
+    time.unpause();
+    time.set_relative_speed_f64(1.0);
+}
+
+/// The settings in effect right now
+fn current_config(
+    fixed_time: &Time<Fixed>,
+    mouse_settings: &MouseFlightSettings,
+    class_progression: &ClassProgression,
+) -> ReplayConfig {
+    ReplayConfig {
+        tick_rate: 1.0 / fixed_time.timestep().as_secs_f64(),
+        checksum_interval: CHECKSUM_INTERVAL,
+        mouse_sensitivity: mouse_settings.sensitivity,
+        mouse_smoothing: mouse_settings.smoothing,
+        class_progression: class_progression.clone(),
+        game_version: env!("CARGO_PKG_VERSION").to_string(),
+    }
+}
+
+fn apply_config(
+    config: &ReplayConfig,
+    fixed_time: &mut Time<Fixed>,
+    mouse_settings: &mut MouseFlightSettings,
+    class_progression: &mut ClassProgression,
+) {
+    fixed_time.set_timestep_hz(config.tick_rate);
+    mouse_settings.sensitivity = config.mouse_sensitivity;
+    mouse_settings.smoothing = config.mouse_smoothing;
+    *class_progression = config.class_progression.clone();
+}
+
+/// Replay controls: F9 records a fresh run (again to stop and save), F10 plays back the saved
+/// run. During playback -/= change speed and ,/. seek back/forward ten seconds.
+pub fn replay_control_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut replay: ResMut<Replay>,
+    mut time: ResMut<Time<Virtual>>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut mouse_settings: ResMut<MouseFlightSettings>,
+    mut class_progression: ResMut<ClassProgression>,
+) {
+    if keyboard.just_pressed(KeyCode::F9) {
+        match replay.mode {
+            ReplayMode::Idle => {
+                let seed = rand::random();
+                replay.data = Some(ReplayData {
+                    seed,
+                    config: current_config(&fixed_time, &mouse_settings, &class_progression),
+                    inputs: Vec::new(),
+                    checksums: Vec::new(),
+                });
+                replay.mode = ReplayMode::Recording;
+                restart_run(&mut commands, &mut replay, &mut time, seed);
+                println!("[Replay] Recording a new run (seed {})", seed);
+            }
+            ReplayMode::Recording => stop_replay(&mut replay, &mut time),
+            ReplayMode::Playing => {}
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::F10) {
+        match replay.mode {
+            ReplayMode::Idle => match load_replay() {
+                Ok(data) => {
+                    if data.config.game_version != env!("CARGO_PKG_VERSION") {
+                        println!("[Replay] Recorded on version {} - playback may desync", data.config.game_version);
+                    }
+
+                    // Put back everything the run depended on besides its seed and inputs
+                    let live_config = current_config(&fixed_time, &mouse_settings, &class_progression);
+                    apply_config(&data.config, &mut fixed_time, &mut mouse_settings, &mut class_progression);
+                    replay.live_config = Some(live_config);
+
+                    let seed = data.seed;
+                    println!("[Replay] Playing back {} ticks (seed {})", data.tick_count(), seed);
+                    replay.data = Some(data);
+                    replay.mode = ReplayMode::Playing;
+                    replay.seek_target = None;
+                    restart_run(&mut commands, &mut replay, &mut time, seed);
+                }
+                Err(e) => println!("[Replay] {}", e),
+            },
+            ReplayMode::Playing => stop_replay(&mut replay, &mut time),
+            ReplayMode::Recording => {}
+        }
+    }
+
+    if replay.mode == ReplayMode::Playing {
+        if keyboard.just_pressed(KeyCode::Minus) {
+            replay.speed = replay.speed.saturating_sub(1);
+        }
+        if keyboard.just_pressed(KeyCode::Equal) {
+            replay.speed = (replay.speed + 1).min(PLAYBACK_SPEEDS.len() - 1);
+        }
+
+        let total = replay.data.as_ref().map_or(0, |d| d.tick_count());
+        let from = replay.seek_target.unwrap_or(replay.tick);
+        if keyboard.just_pressed(KeyCode::Period) {
+            replay.seek_target = Some((from + SEEK_STEP_TICKS).min(total));
+        }
+        if keyboard.just_pressed(KeyCode::Comma) {
+            // The simulation can't run backwards - replay from the start up to the target
+            let target = from.saturating_sub(SEEK_STEP_TICKS);
+            let seed = replay.data.as_ref().map_or(0, |d| d.seed);
+            restart_run(&mut commands, &mut replay, &mut time, seed);
+            replay.seek_target = (target > 0).then_some(target);
+        }
+    }
+
+    // Hold the clock until a restarted run has spawned
+    if replay.settle_frames > 0 {
+        replay.settle_frames -= 1;
+        if replay.settle_frames == 0 {
+            time.unpause();
+        }
+    }
+
+    time.set_relative_speed_f64(replay.time_scale());
+}
+
+/// First step of every tick: record the player's input, or replace it with the recorded one
+pub fn replay_tick_input_system(
+    mut replay: ResMut<Replay>,
+    mut time: ResMut<Time<Virtual>>,
+    mut input: ResMut<PlayerInput>,
+) {
+    let tick = replay.tick as usize;
+    match replay.mode {
+        ReplayMode::Idle => {}
+        ReplayMode::Recording => {
+            // Ticks see exactly what gets saved, so the recording plays back the same way
+            let captured = TickInput::capture(&input);
+            *input = captured.to_player_input();
+            if let Some(data) = replay.data.as_mut() {
+                data.inputs.push(captured);
+            }
+        }
+        ReplayMode::Playing => {
+            match replay.data.as_ref().and_then(|d| d.inputs.get(tick)) {
+                Some(recorded) => *input = recorded.to_player_input(),
+                None => {
+                    println!("[Replay] Playback finished");
+                    stop_replay(&mut replay, &mut time);
+                }
+            }
+        }
+    }
+}
+
+/// FNV-1a over a run of 32-bit words
+fn fnv(words: &[u32]) -> u64 {
+    words.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, word| {
+        word.to_le_bytes().iter().fold(hash, |h, byte| (h ^ *byte as u64).wrapping_mul(0x0100_0000_01b3))
+    })
+}
+
+/// Order-independent sum of one hash per entity
+fn entity_sum<T>(items: impl Iterator<Item = T>, words: impl Fn(T) -> Vec<u32>) -> u64 {
+    items.fold(0, |sum, item| sum.wrapping_add(fnv(&words(item))))
+}
+
+fn pose_words(transform: &Transform) -> [u32; 7] {
+    let t = transform.translation;
+    let r = transform.rotation;
+    [t.x.to_bits(), t.y.to_bits(), t.z.to_bits(), r.x.to_bits(), r.y.to_bits(), r.z.to_bits(), r.w.to_bits()]
+}
+
+/// Hash of the simulation state: every ship's pose, hull, shield, energy and ammo, every
+/// projectile and loot pickup, the RNG streams, the player's resources and the director - so a
+/// desync shows up at the next checksum, not once it finally reaches a ship
+fn world_checksum(
+    ships: &Query<(&Transform, &Health, Option<&Shield>, Option<&Energy>, Option<&WeaponMount>), With<Ship>>,
+    projectiles: &Query<(&Transform, &Projectile)>,
+    loot: &Query<(&Transform, &Loot)>,
+    game_rng: &GameRng,
+    inventory: &Inventory,
+    director: &EncounterDirector,
+) -> u64 {
+    let ships = entity_sum(ships.iter(), |(transform, health, shield, energy, weapons)| {
+        let mut words = pose_words(transform).to_vec();
+        words.push(health.current.to_bits());
+        words.push(shield.map_or(0, |s| s.current.to_bits()));
+        words.push(energy.map_or(0, |e| e.current.to_bits()));
+        if let Some(mount) = weapons {
+            words.push(mount.current_weapon as u32);
+            for weapon in &mount.weapons {
+                words.extend([weapon.current_ammo, weapon.reserve_ammo, weapon.heat.to_bits(), weapon.cooldown_timer.to_bits()]);
+            }
+        }
+        words
+    });
+    let projectiles = entity_sum(projectiles.iter(), |(transform, projectile)| {
+        let mut words = pose_words(transform).to_vec();
+        words.extend([projectile.damage.to_bits(), projectile.lifetime.to_bits()]);
+        words
+    });
+    let loot = entity_sum(loot.iter(), |(transform, loot)| {
+        let mut words = pose_words(transform).to_vec();
+        words.extend([loot.resource_type as u32, loot.amount]);
+        words
+    });
+
+    let mut world = Vec::new();
+    for stream in game_rng.fingerprint() {
+        world.extend([stream as u32, (stream >> 32) as u32]);
+    }
+    world.extend([inventory.scrap_metal, inventory.energy_cores, inventory.rare_minerals, inventory.tech_components]);
+    world.extend([
+        director.phase as u32,
+        director.phase_timer.to_bits(),
+        director.wave_timer.to_bits(),
+        director.intensity.to_bits(),
+        director.threat_budget,
+        director.live_threat,
+        director.waves_spawned,
+    ]);
+
+    fnv(&world) ^ ships ^ projectiles.rotate_left(16) ^ loot.rotate_left(32)
+}
+
+/// Last step of every tick: checksum the world every so often (recorded, or compared against the
+/// recording), then advance the tick and finish any seek that's been reached
+pub fn replay_tick_end_system(
+    mut replay: ResMut<Replay>,
+    ships: Query<(&Transform, &Health, Option<&Shield>, Option<&Energy>, Option<&WeaponMount>), With<Ship>>,
+    projectiles: Query<(&Transform, &Projectile)>,
+    loot: Query<(&Transform, &Loot)>,
+    game_rng: Res<GameRng>,
+    inventory: Res<Inventory>,
+    director: Res<EncounterDirector>,
+) {
+    if !replay.is_active() {
+        return;
+    }
+
+    let tick = replay.tick;
+    let interval = replay.data.as_ref().map_or(CHECKSUM_INTERVAL, |d| d.config.checksum_interval).max(1);
+    if tick % interval == 0 {
+        let checksum = world_checksum(&ships, &projectiles, &loot, &game_rng, &inventory, &director);
+        let mode = replay.mode;
+        let recorded = replay.data.as_mut().and_then(|data| match mode {
+            ReplayMode::Recording => {
+                data.checksums.push(checksum);
+                None
+            }
+            _ => data.checksums.get((tick / interval) as usize).copied(),
+        });
+        if recorded.is_some_and(|r| r != checksum) && replay.diverged_at.is_none() {
+            println!("[Replay] Desync at tick {} - the run no longer matches the recording", tick);
+            replay.diverged_at = Some(tick);
+        }
+    }
+
+    replay.tick += 1;
+    if replay.seek_target.is_some_and(|target| replay.tick >= target) {
+        replay.seek_target = None;
+    }
+}
+
+/// A restart or load from the menus replaces the replayed run - stop following it
+pub fn stop_replay_on_external_restart_system(
+    mut replay: ResMut<Replay>,
+    mut time: ResMut<Time<Virtual>>,
+    restart_flag: Option<Res<RestartGameFlag>>,
+    load_flag: Option<Res<LoadGameFlag>>,
+) {
+    if replay.is_active() && replay.settle_frames == 0 && (restart_flag.is_some() || load_flag.is_some()) {
+        stop_replay(&mut replay, &mut time);
+    }
+}
+
+/// The run ended (death or back to the menu) - save the recording or end playback
+pub fn stop_replay_on_exit_system(mut replay: ResMut<Replay>, mut time: ResMut<Time<Virtual>>) {
+    if replay.is_active() {
+        stop_replay(&mut replay, &mut time);
+    }
+}
+
+/// Once playback is over, however it ended, hand the player back their own settings
+pub fn restore_live_config_system(
+    mut replay: ResMut<Replay>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut mouse_settings: ResMut<MouseFlightSettings>,
+    mut class_progression: ResMut<ClassProgression>,
+) {
+    if replay.mode != ReplayMode::Idle {
+        return;
+    }
+    if let Some(config) = replay.live_config.take() {
+        apply_config(&config, &mut fixed_time, &mut mouse_settings, &mut class_progression);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use crate::components::ai::EnemyType;
+    use crate::systems::{movement, spawning};
+    use crate::systems::simulation::SimulationPlugin;
+
+    const TICKS: u64 = 600;
+
+    /// A player and a couple of other ships, the same every run
+    fn new_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(Time::<Virtual>::default());
+        world.insert_resource(PlayerInput::default());
+        world.insert_resource(Replay::default());
+        world.insert_resource(GameRng::new(7));
+        world.insert_resource(Inventory { scrap_metal: 100, energy_cores: 50, rare_minerals: 25, tech_components: 10 });
+        world.insert_resource(EncounterDirector::default());
+
+        world.spawn((
+            Transform::default(),
+            Ship::fighter(),
+            Velocity::default(),
+            AngularVelocity::default(),
+            Health { current: 100.0, max: 100.0 },
+            Player,
+        ));
+        for x in [-40.0, 60.0] {
+            world.spawn((
+                Transform::from_xyz(x, 0.0, 30.0),
+                Ship::corvette(),
+                Velocity(Vec3::new(0.0, 0.0, -5.0)),
+                AngularVelocity::default(),
+                Health { current: 80.0, max: 80.0 },
+            ));
+        }
+        world
+    }
+
+    /// The fixed-step part of a frame: tick input, movement, then the tick-end checksum
+    fn tick_schedule() -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            replay_tick_input_system,
+            movement::ship_movement_system,
+            movement::apply_velocity_system,
+            movement::apply_angular_velocity_system,
+            replay_tick_end_system,
+        ).chain());
+        schedule
+    }
+
+    fn start(world: &mut World, mode: ReplayMode, data: ReplayData) {
+        let mut replay = world.resource_mut::<Replay>();
+        replay.mode = mode;
+        replay.data = Some(data);
+        replay.tick = 0;
+    }
+
+    fn run_tick(world: &mut World, schedule: &mut Schedule, live_input: PlayerInput) {
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f64(1.0 / 60.0));
+        *world.resource_mut::<PlayerInput>() = live_input;
+        schedule.run(world);
+    }
+
+    /// Record a run of scripted input, returning the finished recording
+    fn record() -> ReplayData {
+        let mut world = new_world();
+        let mut schedule = tick_schedule();
+        start(&mut world, ReplayMode::Recording, ReplayData {
+            seed: 7,
+            config: ReplayConfig {
+                tick_rate: 60.0,
+                checksum_interval: CHECKSUM_INTERVAL,
+                mouse_sensitivity: 0.002,
+                mouse_smoothing: 0.15,
+                class_progression: ClassProgression::new(),
+                game_version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            inputs: Vec::new(),
+            checksums: Vec::new(),
+        });
+
+        for tick in 0..TICKS {
+            let mut input = PlayerInput::default();
+            input.keys_held.insert(KeyCode::KeyW);
+            if tick % 120 < 40 {
+                input.keys_held.insert(KeyCode::KeyD);
+            }
+            if (200..260).contains(&tick) {
+                input.keys_held.insert(KeyCode::ShiftLeft);
+                input.keys_held.insert(KeyCode::KeyE);
+            }
+            run_tick(&mut world, &mut schedule, input);
+        }
+
+        world.resource_mut::<Replay>().data.take().unwrap()
+    }
+
+    #[test]
+    fn replayed_ticks_match_recorded_checksums() {
+        let recording = record();
+        assert_eq!(recording.tick_count(), TICKS);
+        assert_eq!(recording.checksums.len() as u64, TICKS / CHECKSUM_INTERVAL);
+
+        // Play it back while the live keyboard does something else entirely
+        let mut world = new_world();
+        let mut schedule = tick_schedule();
+        start(&mut world, ReplayMode::Playing, recording);
+        for _ in 0..TICKS {
+            let mut live = PlayerInput::default();
+            live.keys_held.insert(KeyCode::KeyS);
+            run_tick(&mut world, &mut schedule, live);
+        }
+
+        let replay = world.resource::<Replay>();
+        assert_eq!(replay.tick, TICKS);
+        assert_eq!(replay.diverged_at, None);
+    }
+
+    #[test]
+    fn tampered_playback_reports_desync() {
+        let recording = record();
+        let mut world = new_world();
+        let mut schedule = tick_schedule();
+        start(&mut world, ReplayMode::Playing, recording);
+
+        for tick in 0..TICKS {
+            if tick == 100 {
+                let mut ships = world.query_filtered::<&mut Transform, Without<Player>>();
+                for mut transform in ships.iter_mut(&mut world) {
+                    transform.translation.x += 0.01;
+                }
+            }
+            run_tick(&mut world, &mut schedule, PlayerInput::default());
+        }
+
+        // First checksum after the nudge
+        assert_eq!(world.resource::<Replay>().diverged_at, Some(120));
+    }
+
+    #[test]
+    fn extra_rng_draw_reports_desync() {
+        let recording = record();
+        let mut world = new_world();
+        let mut schedule = tick_schedule();
+        start(&mut world, ReplayMode::Playing, recording);
+
+        for tick in 0..TICKS {
+            // Nothing on screen changes, but every later roll from this stream would
+            if tick == 250 {
+                rand::Rng::gen::<f32>(world.resource_mut::<GameRng>().stream(RngStream::Loot));
+            }
+            run_tick(&mut world, &mut schedule, PlayerInput::default());
+        }
+
+        assert_eq!(world.resource::<Replay>().diverged_at, Some(300));
+    }
+
+    /// The real game simulation, headless: every fixed-step system on a steady 60 Hz clock,
+    /// started from `seed` the way a replay restarts the run, with a few pirates close by
+    fn simulation_app(seed: u64) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::InGame)
+            .add_plugins(SimulationPlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / 60.0)))
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .insert_resource(Inventory { scrap_metal: 100, energy_cores: 50, rare_minerals: 25, tech_components: 10 })
+            .init_resource::<crate::components::upgrades::PlayerUpgrades>()
+            .init_resource::<SubsystemTargeting>()
+            .init_resource::<crate::components::wingman::WingCommand>()
+            .init_resource::<crate::components::Reputation>()
+            .init_resource::<crate::components::WeaponStash>()
+            .insert_resource(ClassProgression::new())
+            .insert_resource(TrafficTimer(Timer::from_seconds(8.0, TimerMode::Repeating)))
+            .insert_resource(RestartSeed(seed))
+            .insert_resource(RestartGameFlag);
+
+        let world = app.world_mut();
+        world.run_system_once(spawning::handle_restart_game);
+        // Tough enough to see the fight through
+        world.run_system_once(|mut player: Query<&mut Health, With<Player>>| {
+            let mut health = player.single_mut();
+            health.current = 2000.0;
+            health.max = 2000.0;
+        });
+        world.run_system_once(|mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>, mut materials: ResMut<Assets<StandardMaterial>>| {
+            for (enemy_type, position) in [
+                (EnemyType::Fighter, Vec3::new(-30.0, 0.0, -80.0)),
+                (EnemyType::Fighter, Vec3::new(30.0, 5.0, -90.0)),
+                (EnemyType::Corvette, Vec3::new(0.0, -5.0, -120.0)),
+            ] {
+                spawning::spawn_enemy_ship(&mut commands, &mut meshes, &mut materials, enemy_type, Faction::Enemy, position, Vec3::ZERO, 3, 1.0);
+            }
+        });
+        app
+    }
+
+    /// Scripted controls for one tick: fly at the pirates with the guns held down, weaving
+    fn combat_input(tick: u64) -> PlayerInput {
+        let mut input = PlayerInput::default();
+        input.keys_held.insert(KeyCode::KeyW);
+        input.buttons_held.insert(MouseButton::Left);
+        if tick % 200 < 60 {
+            input.keys_held.insert(KeyCode::KeyA);
+        }
+        if tick % 150 == 75 {
+            input.keys_pressed.insert(KeyCode::Digit2);
+        }
+        if tick % 20 < 5 {
+            input.mouse_delta = Vec2::new(if tick % 400 < 200 { 4.0 } else { -4.0 }, 1.0);
+        }
+        input
+    }
+
+    /// Run the full simulation for `ticks` ticks in `mode`, feeding each frame `input(tick)`
+    fn run_simulation(seed: u64, mode: ReplayMode, data: ReplayData, ticks: u64, input: impl Fn(u64) -> PlayerInput) -> App {
+        let mut app = simulation_app(seed);
+        start(app.world_mut(), mode, data);
+        // One tick per frame once the clock is going - the extra frames are the clock starting
+        for _ in 0..ticks + 2 {
+            let tick = app.world().resource::<Replay>().tick;
+            if tick == ticks {
+                break;
+            }
+            *app.world_mut().resource_mut::<PlayerInput>() = input(tick);
+            app.update();
+        }
+        app
+    }
+
+    fn empty_recording(seed: u64) -> ReplayData {
+        ReplayData {
+            seed,
+            config: ReplayConfig {
+                tick_rate: 60.0,
+                checksum_interval: CHECKSUM_INTERVAL,
+                mouse_sensitivity: 0.002,
+                mouse_smoothing: 0.15,
+                class_progression: ClassProgression::new(),
+                game_version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            inputs: Vec::new(),
+            checksums: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn full_simulation_runs_identically_from_the_same_seed() {
+        const SIM_TICKS: u64 = 900;
+        let seed = 0x5EED;
+
+        let mut first = run_simulation(seed, ReplayMode::Recording, empty_recording(seed), SIM_TICKS, combat_input);
+        let mut second = run_simulation(seed, ReplayMode::Recording, empty_recording(seed), SIM_TICKS, combat_input);
+
+        // Things actually happened: gameplay rolled dice and somebody got hurt
+        let fresh = GameRng::new(seed).fingerprint();
+        let rolled = first.world().resource::<GameRng>().fingerprint();
+        assert_ne!(rolled[1], fresh[1], "no combat rolls were made");
+        assert_ne!(rolled[2], fresh[2], "no AI rolls were made");
+        let mut hulls = first.world_mut().query::<&Health>();
+        assert!(hulls.iter(first.world()).any(|h| h.current < h.max), "nobody took hull damage");
+
+        let first = first.world_mut().resource_mut::<Replay>().data.take().unwrap();
+        let second = second.world_mut().resource_mut::<Replay>().data.take().unwrap();
+        assert_eq!(first.checksums.len() as u64, SIM_TICKS / CHECKSUM_INTERVAL);
+        assert_eq!(first.checksums, second.checksums);
+
+        // And the recording plays back through the same schedule, whatever the live input does
+        let playback = run_simulation(seed, ReplayMode::Playing, first, SIM_TICKS, |_| PlayerInput::default());
+        assert_eq!(playback.world().resource::<Replay>().diverged_at, None);
+    }
+}
@@ -1,8 +1,12 @@
 use bevy::prelude::*;
+use rand::Rng;
 use crate::components::resources::*;
 use crate::components::ship::{Player, Velocity};
+use crate::components::combat::{AutoTurret, Weapon, WeaponMount};
 use crate::systems::combat::ShouldSpawnLoot;
 use crate::components::ai::Enemy;
+use crate::resources::{GameRng, PlayerInput, RngStream};
+use crate::utils::math;
 
 /// Marker component for loot entities
 #[derive(Component)]
@@ -18,7 +22,7 @@ pub fn loot_collection_system(
     mut inventory: ResMut<Inventory>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    player_query: Query<&Transform, With<Player>>,
+    mut player_query: Query<(&Transform, &mut WeaponMount, Option<&mut AutoTurret>), With<Player>>,
     mut loot_query: Query<(Entity, &mut Transform, &Loot, &mut Velocity), Without<Player>>,
     galaxy: Option<Res<crate::resources::Galaxy>>,
 ) {
@@ -32,7 +36,7 @@ pub fn loot_collection_system(
         .and_then(|g| g.current_system())
         .map(|s| &s.resource_multipliers);
     
-    for player_transform in player_query.iter() {
+    for (player_transform, mut weapon_mount, mut turret) in player_query.iter_mut() {
         for (loot_entity, mut loot_transform, loot, mut velocity) in loot_query.iter_mut() {
             let to_player = player_transform.translation - loot_transform.translation;
             let distance = to_player.length();
@@ -47,7 +51,7 @@ pub fn loot_collection_system(
                 
                 // Apply velocity with damping
                 loot_transform.translation += velocity.0 * dt;
-                velocity.0 *= 0.95; // Damping
+                velocity.0 *= math::damping(0.95, dt);
             }
             
             // Collect when close enough
@@ -59,19 +63,35 @@ pub fn loot_collection_system(
                         ResourceType::EnergyCores => multipliers.energy_cores,
                         ResourceType::RareMinerals => multipliers.rare_minerals,
                         ResourceType::TechComponents => multipliers.tech_components,
+                        ResourceType::Ammunition => 1.0,
                     }
                 } else {
                     1.0
                 };
                 
                 let amount = (loot.amount as f32 * multiplier).round() as u32;
-                inventory.add_resource(loot.resource_type, amount);
+                if loot.resource_type == ResourceType::Ammunition {
+                    // Each crate unit restocks one magazine for every ammo weapon
+                    let mut rounds_added = 0;
+                    for weapon in weapon_mount.weapons.iter_mut() {
+                        rounds_added += weapon.resupply(weapon.max_ammo * amount);
+                    }
+                    if let Some(turret) = turret.as_mut() {
+                        for weapon in turret.weapons.iter_mut() {
+                            rounds_added += weapon.resupply(weapon.max_ammo * amount);
+                        }
+                    }
+                    println!("[Resources System] Ammo crate restocked {} rounds", rounds_added);
+                } else {
+                    inventory.add_resource(loot.resource_type, amount);
+                }
                 
                 let resource_name = match loot.resource_type {
                     ResourceType::ScrapMetal => "Scrap Metal",
                     ResourceType::EnergyCores => "Energy Core",
                     ResourceType::RareMinerals => "Rare Mineral",
                     ResourceType::TechComponents => "Tech Component",
+                    ResourceType::Ammunition => "Ammo Crate",
                 };
                 println!("[Resources System] Collected {} x{}", resource_name, loot.amount);
                 
@@ -97,6 +117,7 @@ fn spawn_collection_effect(
         ResourceType::EnergyCores => Color::srgb(0.2, 0.8, 1.0),
         ResourceType::RareMinerals => Color::srgb(0.8, 0.2, 0.8),
         ResourceType::TechComponents => Color::srgb(1.0, 0.8, 0.2),
+        ResourceType::Ammunition => Color::srgb(0.4, 1.0, 0.3),
     };
     
     // Spawn multiple small particles
@@ -134,6 +155,7 @@ pub fn spawn_loot_for_enemy(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     transform: &Transform,
     enemy: &Enemy,
+    rng: &mut impl Rng,
 ) {
         // Better drop rates based on enemy type
         let (loot_count, amount_multiplier) = match enemy.enemy_type {
@@ -145,7 +167,7 @@ pub fn spawn_loot_for_enemy(
         
         for i in 0..loot_count {
             // Weighted random for resource type (more scrap and energy, less rare stuff)
-            let roll = rand::random::<f32>();
+            let roll = rng.gen::<f32>();
             let resource_type = if roll < 0.4 {
                 ResourceType::ScrapMetal  // 40% chance
             } else if roll < 0.7 {
@@ -157,11 +179,38 @@ pub fn spawn_loot_for_enemy(
             };
             
             // Spread loot in a circle around the ship
-            let angle = (i as f32 / loot_count as f32) * std::f32::consts::TAU + rand::random::<f32>() * 0.5;
-            let radius = 3.0 + rand::random::<f32>() * 2.0;
+            let angle = (i as f32 / loot_count as f32) * std::f32::consts::TAU + rng.gen::<f32>() * 0.5;
+            spawn_loot_piece(commands, meshes, materials, transform.translation, angle, resource_type, amount_multiplier, rng);
+        }
+        
+        // Ammo crates - rolled separately so resource drop rates are unchanged
+        let (ammo_chance, ammo_magazines) = match enemy.enemy_type {
+            crate::components::ai::EnemyType::Fighter => (0.3, 1),
+            crate::components::ai::EnemyType::Corvette => (0.5, 1),
+            crate::components::ai::EnemyType::Frigate => (0.75, 2),
+            crate::components::ai::EnemyType::CapitalShip => (1.0, 3),
+        };
+        if rng.gen::<f32>() < ammo_chance {
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            spawn_loot_piece(commands, meshes, materials, transform.translation, angle, ResourceType::Ammunition, ammo_magazines, rng);
+        }
+}
+
+/// Spawn a single collectible loot entity near a position
+fn spawn_loot_piece(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    origin: Vec3,
+    angle: f32,
+    resource_type: ResourceType,
+    amount: u32,
+    rng: &mut impl Rng,
+) {
+            let radius = 3.0 + rng.gen::<f32>() * 2.0;
             let offset = Vec3::new(
                 angle.cos() * radius,
-                (rand::random::<f32>() - 0.5) * 2.0,
+                (rng.gen::<f32>() - 0.5) * 2.0,
                 angle.sin() * radius,
             );
             
@@ -186,17 +235,23 @@ pub fn spawn_loot_for_enemy(
                     Color::srgb(1.0, 1.0, 0.4), 
                     3
                 ), // Gold/yellow
+                ResourceType::Ammunition => (
+                    Color::srgb(0.3, 0.6, 0.2), 
+                    Color::srgb(0.4, 1.0, 0.3), 
+                    4
+                ), // Green crate
             };
             
             let mesh = match mesh_type {
                 0 => meshes.add(Cuboid::new(0.5, 0.5, 0.5)),  // Scrap
                 1 => meshes.add(Sphere::new(0.4)),            // Energy
                 2 => meshes.add(Sphere::new(0.35)),           // Minerals (could be different shape)
+                4 => meshes.add(Cuboid::new(0.8, 0.5, 0.5)),  // Ammo crate
                 _ => meshes.add(Cuboid::new(0.4, 0.4, 0.4)),  // Tech (could be different shape)
             };
             
             // Random rotation speed for visual effect
-            let rotation_speed = 1.0 + rand::random::<f32>() * 2.0;
+            let rotation_speed = 1.0 + rng.gen::<f32>() * 2.0;
             
             commands.spawn((
                 PbrBundle {
@@ -208,24 +263,66 @@ pub fn spawn_loot_for_enemy(
                         perceptual_roughness: 0.5,
                         ..default()
                     }),
-                    transform: Transform::from_translation(transform.translation + offset),
+                    transform: Transform::from_translation(origin + offset),
                     ..default()
                 },
                 Loot {
                     resource_type,
-                    amount: amount_multiplier,
+                    amount,
                 },
                 LootVisual {
                     lifetime: 60.0,  // Loot disappears after 60 seconds if not collected
                     rotation_speed,
                 },
                 Velocity(Vec3::new(
-                    (rand::random::<f32>() - 0.5) * 2.0,
-                    rand::random::<f32>() * 1.0,
-                    (rand::random::<f32>() - 0.5) * 2.0,
+                    (rng.gen::<f32>() - 0.5) * 2.0,
+                    rng.gen::<f32>() * 1.0,
+                    (rng.gen::<f32>() - 0.5) * 2.0,
                 )),
             ));
-        }
+}
+
+/// Scrap metal cost to fabricate one magazine
+const AMMO_FABRICATION_COST: u32 = 10;
+
+/// Fabricate ammunition from scrap metal (T key)
+/// Restocks one magazine for the selected weapon, or the first ammo weapon that needs it
+pub fn ammo_fabrication_system(
+    input: Res<PlayerInput>,
+    mut inventory: ResMut<Inventory>,
+    mut player_query: Query<(&mut WeaponMount, Option<&mut AutoTurret>), With<Player>>,
+) {
+    if !input.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+    
+    let Ok((mut weapon_mount, mut turret)) = player_query.get_single_mut() else {
+        return;
+    };
+    
+    if inventory.scrap_metal < AMMO_FABRICATION_COST {
+        println!("[Resources System] Not enough scrap to fabricate ammo ({}/{})", inventory.scrap_metal, AMMO_FABRICATION_COST);
+        return;
+    }
+    
+    // Prefer the selected weapon, then the rest of the mount, then turret weapons
+    let current = weapon_mount.current_weapon;
+    let target: Option<&mut Weapon> = if weapon_mount.weapons.get(current).map_or(false, |w| w.needs_resupply()) {
+        weapon_mount.weapons.get_mut(current)
+    } else if let Some(weapon) = weapon_mount.weapons.iter_mut().find(|w| w.needs_resupply()) {
+        Some(weapon)
+    } else {
+        turret.as_mut().and_then(|t| t.weapons.iter_mut().find(|w| w.needs_resupply()))
+    };
+    
+    if let Some(weapon) = target {
+        let rounds = weapon.max_ammo;
+        let added = weapon.resupply(rounds);
+        inventory.scrap_metal -= AMMO_FABRICATION_COST;
+        println!("[Resources System] Fabricated {} rounds for {:?} (-{} scrap)", added, weapon.weapon_type, AMMO_FABRICATION_COST);
+    } else {
+        println!("[Resources System] Ammo reserves already full");
+    }
 }
 
 /// Old spawn loot system - now deprecated, loot spawns directly in death system
@@ -234,11 +331,12 @@ pub fn spawn_loot_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     query: Query<(Entity, &Transform, &Enemy), With<ShouldSpawnLoot>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     // This system is kept for backwards compatibility but is no longer used
     // Loot is now spawned directly in the ship_death_system
     for (entity, transform, enemy) in query.iter() {
-        spawn_loot_for_enemy(&mut commands, &mut meshes, &mut materials, transform, enemy);
+        spawn_loot_for_enemy(&mut commands, &mut meshes, &mut materials, transform, enemy, game_rng.stream(RngStream::Loot));
         commands.entity(entity).remove::<ShouldSpawnLoot>();
     }
 }
@@ -259,7 +357,7 @@ pub fn animate_loot_system(
         let bob_height = (time.elapsed_seconds() * 2.0).sin() * 0.5;
         
         // Slow down the loot over time
-        velocity.0 *= 0.95;
+        velocity.0 *= math::damping(0.95, dt);
         
         // Add slight upward drift
         velocity.0.y = bob_height * 0.3;
@@ -1,11 +1,14 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use crate::components::ship::*;
 use crate::components::combat::*;
 use crate::components::resources::Inventory;
 use crate::components::upgrades::PlayerUpgrades;
+use crate::components::Reputation;
+use crate::components::weapon_items::WeaponStash;
 use crate::resources::Galaxy;
 
 /// Save data structure
@@ -23,6 +26,12 @@ pub struct SaveData {
     pub upgrades: PlayerUpgrades,
     pub galaxy_seed: u64,
     pub current_system_id: u32,
+    #[serde(default)]
+    pub weapon_stash: WeaponStash,
+    #[serde(default)]
+    pub defeated_bosses: Vec<u32>,
+    #[serde(default)]
+    pub reputation: HashMap<Faction, f32>,
 }
 
 /// Serializable Vec3
@@ -80,13 +89,15 @@ pub fn save_game(
     player_query: &Query<(&Transform, &Health, &Shield, &Energy), With<Player>>,
     inventory: &Inventory,
     upgrades: &PlayerUpgrades,
+    weapon_stash: &WeaponStash,
+    reputation: &Reputation,
     galaxy: Option<&Galaxy>,
 ) -> Result<(), String> {
     if let Ok((transform, health, shield, energy)) = player_query.get_single() {
-        let (galaxy_seed, current_system_id) = if let Some(galaxy) = galaxy {
-            (galaxy.seed, galaxy.current_system_id)
+        let (galaxy_seed, current_system_id, defeated_bosses) = if let Some(galaxy) = galaxy {
+            (galaxy.seed, galaxy.current_system_id, galaxy.defeated_bosses())
         } else {
-            (12345, 0) // Default values if no galaxy
+            (12345, 0, Vec::new()) // Default values if no galaxy
         };
         
         let save_data = SaveData {
@@ -102,6 +113,9 @@ pub fn save_game(
             upgrades: upgrades.clone(),
             galaxy_seed,
             current_system_id,
+            weapon_stash: weapon_stash.clone(),
+            defeated_bosses,
+            reputation: reputation.standings.clone(),
         };
         
         let json = serde_json::to_string_pretty(&save_data)
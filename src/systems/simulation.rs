@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+use bevy::transform::systems::{propagate_transforms, sync_simple_transforms};
+use crate::resources::{GameState, PlayerInput, Replay, RunClock};
+use crate::systems::*;
+
+/// Stages of one simulation tick, run in this order. Every system inside a stage is chained
+/// as well, so the tick runs in one fixed order and the seeded `GameRng` streams are drawn from
+/// in the same order every run - the executor never gets to pick.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimSet {
+    /// Player controls and the ship state they act on (power, shields, status effects)
+    Input,
+    /// AI, wing, squadron and traffic decisions and steering
+    Ai,
+    /// Integrate velocities and bring `GlobalTransform` up to date for the rest of the tick
+    Movement,
+    /// Weapons, projectiles and hits
+    Combat,
+    /// Damage, shield recharge and death
+    Damage,
+    /// Loot, encounters, arrivals and jumps
+    Spawning,
+}
+
+/// The fixed-step simulation: everything that decides how a run plays out, at a steady 60 Hz
+pub struct SimulationPlugin;
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .init_resource::<PlayerInput>()
+            .init_resource::<RunClock>()
+            .init_resource::<Replay>()
+            .init_resource::<movement::MouseFlightSettings>()
+            .init_resource::<movement::MouseFlightState>()
+            .init_resource::<spawning::ReinforcementQueue>()
+            .init_resource::<boss::BossEncounter>()
+            .add_event::<progression::EnemyKillEvent>()
+            .add_event::<combat_feedback::HitEvent>()
+            .add_event::<combat_feedback::KillEvent>()
+            .add_event::<combat_feedback::PlayerDamagedEvent>()
+            .add_event::<ai::DistressCallEvent>()
+            .configure_sets(FixedUpdate, (
+                SimSet::Input,
+                SimSet::Ai,
+                SimSet::Movement,
+                SimSet::Combat,
+                SimSet::Damage,
+                SimSet::Spawning,
+            ).chain().run_if(in_state(GameState::InGame)))
+            .add_systems(FixedFirst, (
+                movement::begin_interpolation_tick_system,
+                replay::replay_tick_input_system,
+            ).chain().run_if(in_state(GameState::InGame)))
+            .add_systems(FixedLast, (
+                movement::end_interpolation_tick_system.run_if(in_state(GameState::InGame)),
+                galaxy::advance_run_clock_system.run_if(in_state(GameState::InGame)),
+                replay::replay_tick_end_system.run_if(in_state(GameState::InGame)),
+                input::consume_player_input_system,
+            ).chain())
+            .add_systems(FixedUpdate, (
+                combat::power_distribution_input_system,
+                combat::shield_facings_setup_system,
+                combat::shield_shunt_system,
+                combat::autofire_toggle_system,
+                subsystems::subsystem_target_cycle_system,
+                wingman::wingman_order_input_system,
+                wingman::wingman_hire_system,
+                weapon_items::weapon_stash_input_system,
+                resources_system::ammo_fabrication_system,
+                abilities::ability_activation_system,
+                abilities::update_ability_cooldowns_system,
+                status_effects::status_effect_tick_system,
+                status_effects::hazard_zone_system,
+                status_effects::status_effect_modifiers_system,
+                subsystems::subsystem_effects_system,
+                combat::energy_recharge_system,
+            ).chain().in_set(SimSet::Input))
+            .add_systems(FixedUpdate, (
+                ai::ai_controller_system,
+                ai::ai_power_management_system,
+                ai::ai_target_acquisition_system,
+                wingman::wingman_targeting_system,
+                ai::ai_weapon_selection_system,
+                ai::ai_combat_system,
+                abilities::enemy_ability_system,
+                ai::ai_retreat_system,
+                squadron::squadron_formation_system,
+                squadron::squadron_morale_system,
+                carrier::carrier_subsystem_system,
+                carrier::carrier_turret_system,
+                carrier::carrier_launch_system,
+                carrier::carrier_fighter_orphan_system,
+                boss::boss_phase_system,
+                wingman::wingman_formation_system,
+                traffic::civilian_movement_system,
+                // Last, so separation and obstacle clearance see every velocity set above
+                ai::ai_steering_system,
+            ).chain().in_set(SimSet::Ai))
+            .add_systems(FixedUpdate, (
+                galaxy::update_planet_orbits,
+                spawning::warp_in_system,
+                movement::ship_movement_system,
+                movement::mouse_flight_system,
+                movement::apply_velocity_system,
+                abilities::quantum_dash_motion_system,
+                movement::apply_angular_velocity_system,
+                // Hit tests and obstacle checks read GlobalTransform - don't leave it a frame behind
+                sync_simple_transforms,
+                propagate_transforms,
+            ).chain().in_set(SimSet::Movement))
+            .add_systems(FixedUpdate, (
+                abilities::devastation_effect_system,
+                combat::autofire_targeting_system,
+                combat::autofire_aiming_system,
+                combat::turret_weapon_state_system,
+                combat::weapon_state_system,
+                combat::autofire_firing_system,
+                combat::weapon_firing_system,
+                combat::missile_lock_system,
+                combat::missile_guidance_assignment_system,
+                combat::homing_projectile_system,
+                combat::countermeasure_system,
+                combat::decoy_system,
+                combat::projectile_movement_system,
+                combat::mark_interceptable_projectiles_system,
+                combat::projectile_intercept_system,
+                combat::projectile_lifetime_system,
+                combat::projectile_collision_system,
+            ).chain().in_set(SimSet::Combat))
+            .add_systems(FixedUpdate, (
+                combat::damage_system,
+                abilities::apply_ability_effects_system,
+                combat::shield_recharge_system,
+                subsystems::subsystem_destruction_system,
+                subsystems::detached_piece_system,
+                combat::ship_death_system,
+            ).chain().in_set(SimSet::Damage))
+            .add_systems(FixedUpdate, (
+                resources_system::spawn_loot_system,
+                resources_system::animate_loot_system,
+                resources_system::loot_collection_system,
+                weapon_items::weapon_drop_pickup_system,
+                spawning::encounter_director_system,
+                spawning::reinforcement_spawner_system,
+                traffic::civilian_traffic_spawner_system,
+                boss::boss_spawn_system,
+                // Jumps happen on a tick too, so a replay leaves the system when the recording did
+                travel::check_jump_gate_proximity,
+                travel::hyperspace_animation_system,
+                travel::handle_system_transition,
+                spawning::handle_respawn_system_content,
+                galaxy::spawn_system_content,
+            ).chain().in_set(SimSet::Spawning));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::schedule::{LogLevel, ScheduleBuildSettings};
+    use bevy::state::app::StatesPlugin;
+
+    /// No two simulation systems that touch the same data (or the same RNG stream) may be left
+    /// for the executor to order
+    #[test]
+    fn fixed_update_has_no_ambiguous_systems() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::InGame)
+            .add_plugins(SimulationPlugin)
+            .edit_schedule(FixedUpdate, |schedule| {
+                schedule.set_build_settings(ScheduleBuildSettings {
+                    ambiguity_detection: LogLevel::Error,
+                    ..default()
+                });
+            });
+
+        let mut schedule = app.world_mut().resource_mut::<Schedules>().remove(FixedUpdate).unwrap();
+        if let Err(e) = schedule.initialize(app.world_mut()) {
+            panic!("{}", e);
+        }
+    }
+}
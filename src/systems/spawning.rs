@@ -1,83 +1,392 @@
 use bevy::prelude::*;
+use rand::Rng;
 use crate::components::ship::*;
 use crate::components::combat::*;
 use crate::components::ai::*;
 use crate::components::resources::{Inventory, Loot};
 use crate::components::upgrades::PlayerUpgrades;
-use crate::resources::{SpawnTimer, Galaxy};
-use crate::utils::ship_builder;
-use crate::systems::ui::{RestartGameFlag, LoadGameFlag};
+use crate::components::weapon_items::{WeaponDrop, WeaponStash};
+use crate::components::status_effects::StatusEffects;
+use crate::components::abilities::AbilityController;
+use crate::components::squadron::{FormationType, Squadron, SquadronMember};
+use crate::components::carrier::Carrier;
+use crate::components::{Civilian, Reputation, Wingman};
+use crate::components::encounter::{DirectorPhase, EncounterDirector, EncounterTemplate, ENCOUNTER_TABLE};
+use crate::components::galaxy::{Obstacle, SystemId};
+use crate::components::travel::{JumpGate, WarpIn};
+use crate::components::camera::CameraController;
+use crate::resources::{Galaxy, GameRng, RngStream, RunClock, TrafficTimer};
+use crate::utils::{ship_builder, spawn_placement};
+use crate::systems::ui::{RestartGameFlag, RestartSeed, LoadGameFlag};
 use crate::systems::save_load;
 use crate::systems::travel::RespawnSystemContentFlag;
 
-/// Enemy spawner system
-pub fn enemy_spawner_system(
+/// Encounter director - paces the fighting in build-up/peak/relax phases and sends waves picked
+/// from the encounter table, keeping the threat on the field under a budget
+pub fn encounter_director_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut spawn_timer: ResMut<SpawnTimer>,
+    mut director: ResMut<EncounterDirector>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut damage_events: EventReader<crate::systems::combat_feedback::PlayerDamagedEvent>,
     player_query: Query<&Transform, With<Player>>,
     enemy_query: Query<&Enemy>,
     galaxy: Option<Res<Galaxy>>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+    gates: Query<&GlobalTransform, With<JumpGate>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<CameraController>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
-    spawn_timer.0.tick(time.delta());
-    
-    if !spawn_timer.0.finished() {
+    let dt = time.delta_seconds();
+    let Some(system) = galaxy.as_ref().and_then(|g| g.current_system()) else {
+        damage_events.clear();
         return;
+    };
+    
+    // Every system starts with a fresh build-up
+    if director.system_id != Some(system.id) {
+        *director = EncounterDirector {
+            system_id: Some(system.id),
+            ..default()
+        };
     }
     
-    // Limit number of enemies (increased from 10 to 15 for more action)
-    let enemy_count = enemy_query.iter().count();
-    if enemy_count >= 15 {
+    // Intensity tracks how hard the player is being pressed
+    let hits = damage_events.read().count() as f32;
+    director.intensity = (director.intensity + hits * EncounterDirector::INTENSITY_PER_HIT - EncounterDirector::INTENSITY_DECAY * dt).clamp(0.0, 1.0);
+    director.live_threat = enemy_query.iter().map(|e| e.enemy_type.threat()).sum();
+    director.threat_budget = (EncounterDirector::base_budget(system.difficulty) as f32 * director.budget_scale()).round() as u32;
+    
+    // Pacing: build-up -> peak (ends early if the player is swamped) -> relax (until things calm down)
+    director.phase_timer += dt;
+    let phase_done = director.phase_timer >= director.phase.duration();
+    match director.phase {
+        DirectorPhase::BuildUp if phase_done => director.enter_phase(DirectorPhase::Peak),
+        DirectorPhase::Peak if phase_done || director.intensity >= EncounterDirector::PEAK_INTENSITY => {
+            director.enter_phase(DirectorPhase::Relax)
+        }
+        DirectorPhase::Relax if phase_done && director.intensity < EncounterDirector::CALM_INTENSITY => {
+            director.enter_phase(DirectorPhase::BuildUp)
+        }
+        _ => {}
+    }
+    
+    let Some(interval) = director.phase.wave_interval() else {
+        return;
+    };
+    director.wave_timer -= dt;
+    if director.wave_timer > 0.0 {
         return;
     }
+    director.wave_timer = interval;
+    
+    // Encounters this system fields that still fit under the budget - ones led by the
+    // system's favourite ship type come up more often
+    let room = director.threat_budget.saturating_sub(director.live_threat);
+    let candidates: Vec<(&EncounterTemplate, f32)> = ENCOUNTER_TABLE.iter()
+        .filter(|t| t.available_at(system.difficulty) && t.threat() <= room)
+        .filter(|t| system.enemy_preference.contains(&t.leader))
+        .map(|t| {
+            let favourite = system.enemy_preference.first() == Some(&t.leader);
+            (t, if favourite { t.weight * 2.0 } else { t.weight })
+        })
+        .collect();
+    let total_weight: f32 = candidates.iter().map(|(_, w)| w).sum();
+    if candidates.is_empty() || total_weight <= 0.0 {
+        return;
+    }
+    let rng = game_rng.stream(RngStream::Spawning);
+    let mut roll = rng.gen::<f32>() * total_weight;
+    let template = candidates.iter()
+        .find(|(_, weight)| {
+            roll -= weight;
+            roll <= 0.0
+        })
+        .map_or(candidates[candidates.len() - 1].0, |(t, _)| *t);
+    
+    // Pirates or militia, weighted by their presence in this system
+    let pirates = system.presence(Faction::Enemy);
+    let militia = system.presence(Faction::Militia);
+    let faction = if rng.gen::<f32>() * (pirates + militia) < militia { Faction::Militia } else { Faction::Enemy };
     
     // Get player position if available
     let player_pos = player_query.iter().next().map(|t| t.translation).unwrap_or(Vec3::ZERO);
     
-    // Random spawn position around player (increased distance for better spawning)
-    let angle = rand::random::<f32>() * std::f32::consts::TAU;
-    let distance = 120.0 + rand::random::<f32>() * 60.0;
-    let spawn_pos = player_pos + Vec3::new(
-        angle.cos() * distance,
-        (rand::random::<f32>() - 0.5) * 30.0,
-        angle.sin() * distance,
+    // Arrive clear of planets and gates, preferably off-screen or out of a gate
+    let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+        .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+        .collect();
+    let gate_positions: Vec<Vec3> = gates.iter().map(|t| t.translation()).collect();
+    let camera = camera_query.get_single().ok();
+    let spawn_pos = spawn_placement::find_spawn_position(
+        player_pos,
+        120.0,
+        180.0,
+        &obstacle_volumes,
+        &gate_positions,
+        |position| camera.map_or(false, |(camera, transform)| spawn_placement::in_view(camera, transform, position)),
+        rng,
     );
     
-    // Get difficulty scaling from current system
-    let (difficulty_scale, enemy_preferences) = if let Some(galaxy) = galaxy.as_ref() {
-        if let Some(system) = galaxy.current_system() {
-            let scale = 1.0 + (system.difficulty as f32 * 0.1);
-            (scale, Some(&system.enemy_preference))
-        } else {
-            (1.0, None)
+    let difficulty_scale = 1.0 + (system.difficulty as f32 * 0.1);
+    let ships = spawn_squadron(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        template.leader,
+        template.escorts,
+        template.formation,
+        faction,
+        spawn_pos,
+        player_pos,
+        system.difficulty,
+        difficulty_scale,
+    );
+    for (ship, position) in ships {
+        warp_in(&mut commands, &mut meshes, &mut materials, ship, position, player_pos - spawn_pos);
+    }
+    
+    director.live_threat += template.threat();
+    director.last_encounter = Some(template.name);
+    director.waves_spawned += 1;
+    println!("[Director] {} sends {} {} (threat {}/{})",
+        director.phase.name(), faction.name(), template.name, director.live_threat, director.threat_budget);
+}
+
+/// Drop a freshly spawned ship out of warp - arrival flash, then it decelerates along `heading`
+pub fn warp_in(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    ship: Entity,
+    position: Vec3,
+    heading: Vec3,
+) {
+    crate::systems::effects::spawn_warp_flash(commands, meshes, materials, position, heading);
+    commands.entity(ship).insert(WarpIn::new());
+}
+
+/// Warp arrivals - hold the ship on its heading while it bleeds off warp speed, then hand it to the AI
+pub fn warp_in_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut arrivals: Query<(Entity, &Transform, &Ship, &mut Velocity, &mut WarpIn)>,
+) {
+    for (entity, transform, ship, mut velocity, mut warp) in arrivals.iter_mut() {
+        warp.elapsed += time.delta_seconds();
+        let progress = warp.progress();
+        // Ease out - most of the speed goes in the first moments
+        let eased = 1.0 - (1.0 - progress) * (1.0 - progress);
+        let speed = warp.entry_speed + (ship.max_speed - warp.entry_speed) * eased;
+        velocity.0 = transform.forward().as_vec3() * speed;
+        
+        if progress >= 1.0 {
+            commands.entity(entity).remove::<WarpIn>();
         }
-    } else {
-        (1.0, None)
+    }
+}
+
+/// Spawn a leader and its escorts in formation, all heading for `look_at`.
+/// Returns every ship with its spawn position, leader first.
+pub fn spawn_squadron(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    leader_type: EnemyType,
+    escort_types: &[EnemyType],
+    formation: FormationType,
+    faction: Faction,
+    spawn_pos: Vec3,
+    look_at: Vec3,
+    difficulty: u32,
+    difficulty_scale: f32,
+) -> Vec<(Entity, Vec3)> {
+    // Militia don't field capital ships
+    let militia_type = |enemy_type: EnemyType| {
+        if faction == Faction::Militia && enemy_type == EnemyType::CapitalShip { EnemyType::Frigate } else { enemy_type }
     };
     
-    // Select enemy type based on system preferences
-    let enemy_type = if let Some(preferences) = enemy_preferences {
-        // Use weighted random based on preferences
-        let roll = rand::random::<f32>();
-        let total = preferences.len() as f32;
-        let index = (roll * total).floor() as usize;
-        preferences.get(index).copied().unwrap_or(EnemyType::Fighter)
-    } else {
-        // Fallback to default weighted random
-        let roll = rand::random::<f32>();
-        if roll < 0.4 {
-            EnemyType::Fighter
-        } else if roll < 0.7 {
-            EnemyType::Corvette
-        } else if roll < 0.9 {
-            EnemyType::Frigate
-        } else {
-            EnemyType::CapitalShip
-        }
+    let leader = spawn_enemy_ship(
+        commands,
+        meshes,
+        materials,
+        militia_type(leader_type),
+        faction,
+        spawn_pos,
+        look_at,
+        difficulty,
+        difficulty_scale,
+    );
+    
+    if escort_types.is_empty() {
+        return vec![(leader, spawn_pos)];
+    }
+    
+    // Escorts start in their formation slots around the leader
+    let leader_rotation = Transform::from_translation(spawn_pos).looking_at(look_at, Vec3::Y).rotation;
+    let count = escort_types.len();
+    let mut members = Vec::with_capacity(count);
+    let mut ships = vec![(leader, spawn_pos)];
+    for (index, escort_type) in escort_types.iter().enumerate() {
+        let slot = formation.slot_offset(index, count);
+        let position = spawn_pos + leader_rotation * slot;
+        let escort = spawn_enemy_ship(
+            commands,
+            meshes,
+            materials,
+            militia_type(*escort_type),
+            faction,
+            position,
+            position + (look_at - spawn_pos),
+            difficulty,
+            difficulty_scale,
+        );
+        commands.entity(escort).insert(SquadronMember {
+            leader,
+            slot,
+            morale: 1.0,
+        });
+        members.push(escort);
+        ships.push((escort, position));
+    }
+    
+    println!("[Spawning System] {} {:?} leads a {:?} squadron of {} wingmen", faction.name(), leader_type, formation, members.len());
+    commands.entity(leader).insert(Squadron::new(formation, members));
+    ships
+}
+
+/// Seconds between a distress call and its reinforcements jumping in
+const REINFORCEMENT_DELAY: f32 = 10.0;
+
+/// Reinforcement wave answering a distress call
+pub struct ReinforcementWave {
+    pub enemy_types: Vec<EnemyType>,
+    pub faction: Faction,
+    pub position: Vec3,
+    pub timer: f32,
+    pub system_id: SystemId, // Waves are dropped if the player leaves the system first
+}
+
+/// Reinforcement waves waiting to arrive
+#[derive(Resource, Default)]
+pub struct ReinforcementQueue {
+    pub waves: Vec<ReinforcementWave>,
+}
+
+/// Reinforcements - distress calls schedule a wave that jumps in near the caller's last position
+/// after a delay, flying as a line squadron
+pub fn reinforcement_spawner_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut distress_events: EventReader<crate::systems::ai::DistressCallEvent>,
+    mut queue: ResMut<ReinforcementQueue>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<&Enemy>,
+    galaxy: Option<Res<Galaxy>>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let Some(system) = galaxy.as_ref().and_then(|g| g.current_system()) else {
+        distress_events.clear();
+        return;
     };
+    let difficulty = system.difficulty;
+    let difficulty_scale = 1.0 + (difficulty as f32 * 0.1);
+    
+    for call in distress_events.read() {
+        let (_, enemy_types) = FormationType::squadron_for(call.caller_type);
+        println!("[Spawning System] Distress call answered - {} reinforcements inbound", enemy_types.len());
+        queue.waves.push(ReinforcementWave {
+            enemy_types,
+            faction: call.faction,
+            position: call.position,
+            timer: REINFORCEMENT_DELAY,
+            system_id: system.id,
+        });
+    }
     
+    queue.waves.retain(|wave| wave.system_id == system.id);
+    
+    let dt = time.delta_seconds();
+    for wave in queue.waves.iter_mut() {
+        wave.timer -= dt;
+    }
+    
+    let player_pos = player_query.iter().next().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+    let mut enemy_count = enemy_query.iter().count();
+    
+    // Waiting waves hold until there's room under the enemy cap
+    let mut index = 0;
+    while index < queue.waves.len() {
+        if queue.waves[index].timer > 0.0 || enemy_count >= 15 {
+            index += 1;
+            continue;
+        }
+        let mut wave = queue.waves.remove(index);
+        wave.enemy_types.truncate(15 - enemy_count);
+        
+        let rng = game_rng.stream(RngStream::Spawning);
+        let arrival_offset = Vec3::new(
+            rng.gen::<f32>() - 0.5,
+            (rng.gen::<f32>() - 0.5) * 0.3,
+            rng.gen::<f32>() - 0.5,
+        ).normalize_or_zero() * 30.0;
+        let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+            .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+            .collect();
+        let spawn_pos = spawn_placement::clear_of_obstacles(wave.position + arrival_offset, &obstacle_volumes);
+        let leader_rotation = Transform::from_translation(spawn_pos).looking_at(player_pos, Vec3::Y).rotation;
+        
+        let count = wave.enemy_types.len() - 1;
+        let mut ships = Vec::with_capacity(wave.enemy_types.len());
+        for (index, enemy_type) in wave.enemy_types.iter().enumerate() {
+            let slot = if index == 0 { Vec3::ZERO } else { FormationType::Line.slot_offset(index - 1, count) };
+            let position = spawn_pos + leader_rotation * slot;
+            let ship = spawn_enemy_ship(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                *enemy_type,
+                wave.faction,
+                position,
+                position + (player_pos - spawn_pos),
+                difficulty,
+                difficulty_scale,
+            );
+            warp_in(&mut commands, &mut meshes, &mut materials, ship, position, player_pos - spawn_pos);
+            if index > 0 {
+                commands.entity(ship).insert(SquadronMember {
+                    leader: ships[0],
+                    slot,
+                    morale: 1.0,
+                });
+            }
+            ships.push(ship);
+        }
+        
+        if ships.len() > 1 {
+            commands.entity(ships[0]).insert(Squadron::new(FormationType::Line, ships[1..].to_vec()));
+        }
+        enemy_count += ships.len();
+        println!("[Spawning System] {} reinforcements jumped in", ships.len());
+    }
+}
+
+/// Spawn a single NPC combat ship of the given type and faction, scaled for the system's difficulty
+pub fn spawn_enemy_ship(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    enemy_type: EnemyType,
+    faction: Faction,
+    spawn_pos: Vec3,
+    look_at: Vec3,
+    difficulty: u32,
+    difficulty_scale: f32,
+) -> Entity {
     let (mut ship, ai, mut health, mut shield, weapon_mount, ship_type, color) = match enemy_type {
         EnemyType::Fighter => (
             Ship::fighter(),
@@ -136,11 +445,14 @@ pub fn enemy_spawner_system(
     shield.current *= difficulty_scale;
     ship.max_speed *= 1.0 + (difficulty_scale - 1.0) * 0.5; // Scale speed less dramatically
     
+    // Militia fly in green livery
+    let color = if faction == Faction::Militia { Color::srgb(0.25, 0.65, 0.35) } else { color };
+    
     println!("[Spawning System] Spawning {:?} at position {:?}", enemy_type, spawn_pos);
     
     let enemy_ship = commands.spawn((
         SpatialBundle {
-            transform: Transform::from_translation(spawn_pos).looking_at(player_pos, Vec3::Y),
+            transform: Transform::from_translation(spawn_pos).looking_at(look_at, Vec3::Y),
             ..default()
         },
         ship,
@@ -156,18 +468,46 @@ pub fn enemy_spawner_system(
         Velocity(Vec3::ZERO),
         AngularVelocity(Vec3::ZERO),
         Enemy { enemy_type },
-        Faction::Enemy,
+        AISkill::for_enemy(enemy_type, difficulty),
+        faction,
+        PowerDistribution::default(),
+        StatusEffects::default(),
     )).id();
+    
+    // Missile-carrying elites lock on before launching and carry flares
+    if matches!(enemy_type, EnemyType::Frigate | EnemyType::CapitalShip) {
+        let flares = if enemy_type == EnemyType::CapitalShip { 4 } else { 2 };
+        commands.entity(enemy_ship).insert((
+            MissileLock::new(3.0),
+            Countermeasures::new(flares),
+        ));
+    }
+    
+    // Elites in harder systems get special abilities
+    if let Some(abilities) = AbilityController::for_enemy(enemy_type, difficulty) {
+        println!("[Spawning System] {:?} carries abilities: {:?}", enemy_type, abilities.unlocked_abilities);
+        commands.entity(enemy_ship).insert(abilities);
+    }
+    
+    // Capital ships are carriers - hull turrets plus a hangar of fighters
+    if enemy_type == EnemyType::CapitalShip {
+        commands.entity(enemy_ship).insert((
+            AutoTurret::carrier(),
+            Carrier::new(difficulty),
+        ));
+    }
 
     // Build modular ship visuals
     ship_builder::build_ship(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
+        commands,
+        meshes,
+        materials,
         ship_type,
         enemy_ship,
         color,
     );
+    
+    enemy_ship
 }
 
 /// Handle game restart
@@ -177,11 +517,14 @@ pub fn handle_restart_game(
     mut materials: ResMut<Assets<StandardMaterial>>,
     restart_flag: Option<Res<RestartGameFlag>>,
     player_query: Query<Entity, With<Player>>,
-    enemy_query: Query<Entity, With<Enemy>>,
+    enemy_query: Query<Entity, Or<(With<Enemy>, With<Wingman>, With<Civilian>)>>,
     projectiles: Query<Entity, With<Projectile>>,
-    loot_query: Query<Entity, With<Loot>>,
+    loot_query: Query<Entity, Or<(With<Loot>, With<WeaponDrop>)>>,
     mut inventory: ResMut<Inventory>,
     mut upgrades: ResMut<PlayerUpgrades>,
+    mut weapon_stash: ResMut<WeaponStash>,
+    mut reputation: ResMut<Reputation>,
+    restart_seed: Option<Res<RestartSeed>>,
 ) {
     if restart_flag.is_none() {
         return;
@@ -192,11 +535,27 @@ pub fn handle_restart_game(
     // Remove the restart flag
     commands.remove_resource::<RestartGameFlag>();
     
-    // Initialize/reset galaxy
-    let galaxy = Galaxy::new(rand::random());
+    // Initialize/reset galaxy - a replay picks the seed, otherwise it's a fresh one
+    let seed = restart_seed.map_or_else(rand::random, |s| s.0);
+    commands.remove_resource::<RestartSeed>();
+    let galaxy = Galaxy::new(seed);
+    commands.insert_resource(GameRng::new(galaxy.seed));
     commands.insert_resource(galaxy);
+    commands.insert_resource(EncounterDirector::default());
+    commands.insert_resource(RunClock::default());
+    
+    // Per-run timers, queues and orders start over as well
+    commands.insert_resource(crate::systems::boss::BossEncounter::default());
+    commands.insert_resource(ReinforcementQueue::default());
+    commands.insert_resource(crate::components::wingman::WingCommand::default());
+    commands.insert_resource(SubsystemTargeting::default());
+    commands.insert_resource(crate::systems::movement::MouseFlightState::default());
+    commands.insert_resource(TrafficTimer(Timer::from_seconds(8.0, TimerMode::Repeating)));
     
-    // Despawn all enemies
+    // Fresh start with every faction
+    *reputation = Reputation::default();
+    
+    // Despawn all ships (enemies, traffic and the player's wing)
     for entity in enemy_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
@@ -227,6 +586,9 @@ pub fn handle_restart_game(
     // Reset upgrades
     *upgrades = PlayerUpgrades::default();
     
+    // Reset weapon items
+    *weapon_stash = WeaponStash::default();
+    
     // Spawn new player ship
     let player_ship = commands.spawn((
         SpatialBundle {
@@ -260,6 +622,10 @@ pub fn handle_restart_game(
             max: 100.0,
             recharge_rate: 20.0,
         },
+        MissileLock::new(2.0),
+        Countermeasures::new(6),
+        PowerDistribution::default(),
+        StatusEffects::default(),
     )).id();
 
     // Build modular ship visuals
@@ -282,6 +648,13 @@ pub fn handle_restart_game(
         current_weapon: 0,
     });
     
+    // Same loadout as a fresh game: class bonuses (needed to fire), abilities and the autofire turret
+    commands.entity(player_ship).insert((
+        AbilityController::new(),
+        crate::components::ship_classes::ClassBonuses::new(),
+        AutoTurret::default(),
+    ));
+    
     // Trigger system content spawn
     commands.insert_resource(crate::systems::galaxy::SpawnSystemContentFlag);
     
@@ -295,11 +668,13 @@ pub fn handle_load_game(
     mut materials: ResMut<Assets<StandardMaterial>>,
     load_flag: Option<Res<LoadGameFlag>>,
     player_query: Query<Entity, With<Player>>,
-    enemy_query: Query<Entity, With<Enemy>>,
+    enemy_query: Query<Entity, Or<(With<Enemy>, With<Wingman>, With<Civilian>)>>,
     projectiles: Query<Entity, With<Projectile>>,
-    loot_query: Query<Entity, With<Loot>>,
+    loot_query: Query<Entity, Or<(With<Loot>, With<WeaponDrop>)>>,
     mut inventory: ResMut<Inventory>,
     mut upgrades: ResMut<PlayerUpgrades>,
+    mut weapon_stash: ResMut<WeaponStash>,
+    mut reputation: ResMut<Reputation>,
 ) {
     if load_flag.is_none() {
         return;
@@ -321,7 +696,7 @@ pub fn handle_load_game(
         }
     };
     
-    // Despawn all enemies
+    // Despawn all ships (enemies, traffic and the player's wing)
     for entity in enemy_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
@@ -347,9 +722,22 @@ pub fn handle_load_game(
     // Restore upgrades
     *upgrades = save_data.upgrades.clone();
     
+    // Restore weapon items
+    *weapon_stash = save_data.weapon_stash.clone();
+    
+    // Restore faction standings (saves from before reputation start everyone at the defaults)
+    *reputation = Reputation::default();
+    reputation.standings.extend(save_data.reputation.iter().map(|(faction, standing)| (*faction, *standing)));
+    
     // Restore/create galaxy from save data
     let mut galaxy = Galaxy::new(save_data.galaxy_seed);
     galaxy.jump_to_system(save_data.current_system_id);
+    commands.insert_resource(GameRng::new(save_data.galaxy_seed));
+    commands.insert_resource(EncounterDirector::default());
+    commands.insert_resource(RunClock::default());
+    for system_id in &save_data.defeated_bosses {
+        galaxy.mark_boss_defeated(*system_id);
+    }
     commands.insert_resource(galaxy);
     
     // Spawn player ship with saved state
@@ -389,6 +777,10 @@ pub fn handle_load_game(
             max: save_data.max_energy,
             recharge_rate: 20.0,
         },
+        MissileLock::new(2.0),
+        Countermeasures::new(6),
+        PowerDistribution::default(),
+        StatusEffects::default(),
     )).id();
 
     // Build modular ship visuals
@@ -401,15 +793,20 @@ pub fn handle_load_game(
         Color::srgb(0.2, 0.5, 0.8),
     );
 
-    // Add weapon mounts to player
+    // Add weapon mounts to player (equipped weapon items replace stock weapons)
     commands.entity(player_ship).insert(WeaponMount {
-        weapons: vec![
+        weapons: weapon_stash.build_loadout(vec![
             Weapon::laser(),
             Weapon::autocannon(),
             Weapon::plasma(),
-        ],
+        ]),
         current_weapon: 0,
     });
+    commands.entity(player_ship).insert((
+        AbilityController::new(),
+        crate::components::ship_classes::ClassBonuses::new(),
+        AutoTurret::default(),
+    ));
     
     // Trigger system content spawn
     commands.insert_resource(crate::systems::galaxy::SpawnSystemContentFlag);
@@ -420,14 +817,17 @@ pub fn handle_load_game(
 /// Clean up all entities when returning to main menu
 pub fn cleanup_on_main_menu(
     mut commands: Commands,
-    enemy_query: Query<Entity, With<Enemy>>,
+    enemy_query: Query<Entity, Or<(With<Enemy>, With<Wingman>, With<Civilian>)>>,
     projectiles: Query<Entity, With<Projectile>>,
-    loot_query: Query<Entity, With<Loot>>,
+    loot_query: Query<Entity, Or<(With<Loot>, With<WeaponDrop>)>>,
+    mut reinforcements: ResMut<ReinforcementQueue>,
 ) {
-    // Despawn all enemies
+    reinforcements.waves.clear();
+    
+    // Despawn all ships (enemies, traffic and the player's wing)
     let enemy_count = enemy_query.iter().count();
     if enemy_count > 0 {
-        println!("[Spawning System] Cleaning up {} enemies on return to main menu", enemy_count);
+        println!("[Spawning System] Cleaning up {} ships on return to main menu", enemy_count);
         for entity in enemy_query.iter() {
             commands.entity(entity).despawn_recursive();
         }
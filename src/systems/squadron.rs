@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::ai::*;
+use crate::components::squadron::*;
+use crate::resources::{GameRng, RngStream};
+
+/// Squadron coordination - wingmen hold formation slots while the leader closes in,
+/// then split into a pincer (or screen the capital ship) once the leader engages
+pub fn squadron_formation_system(
+    leaders: Query<(&Transform, &AIController, &Squadron), Without<SquadronMember>>,
+    mut wingmen: Query<(&mut AIController, &SquadronMember)>,
+) {
+    for (mut ai, member) in wingmen.iter_mut() {
+        let Ok((leader_transform, leader_ai, squadron)) = leaders.get(member.leader) else {
+            continue;
+        };
+
+        let engaged = !matches!(leader_ai.state, AIBehaviorState::Patrol | AIBehaviorState::Pursue);
+        if !engaged {
+            ai.formation_slot = Some(leader_transform.translation + leader_transform.rotation * member.slot);
+            ai.squad_order = None;
+            continue;
+        }
+
+        ai.formation_slot = None;
+        match squadron.formation {
+            // Escorts stay on the attack around their capital ship
+            FormationType::Escort => {
+                ai.squad_order = Some(AIAction::Attack);
+            }
+            // Each wing swings around its own side of the target
+            FormationType::Wedge | FormationType::Line => {
+                ai.squad_order = Some(AIAction::Flank);
+                ai.flank_side = if member.slot.x < 0.0 { -1.0 } else { 1.0 };
+            }
+        }
+    }
+}
+
+/// Squadron morale - losses wear the squad down, and losing the leader makes
+/// wingmen scatter or run unless their nerve holds
+pub fn squadron_morale_system(
+    mut commands: Commands,
+    mut leaders: Query<(&Enemy, &mut Squadron), Without<SquadronMember>>,
+    mut wingmen: Query<(Entity, &Transform, &Enemy, &mut AIController, &mut SquadronMember)>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let rng = game_rng.stream(RngStream::Ai);
+    for (leader, mut squadron) in leaders.iter_mut() {
+        let before = squadron.members.len();
+        squadron.members.retain(|m| wingmen.contains(*m));
+        let lost = before - squadron.members.len();
+
+        if lost > 0 {
+            squadron.morale = (squadron.morale - Squadron::WINGMAN_LOSS_MORALE * lost as f32).max(0.0);
+            println!("[Squadron] {:?} squadron lost {} wingmen (morale {:.0}%)", leader.enemy_type, lost, squadron.morale * 100.0);
+        }
+
+        for member in squadron.members.iter() {
+            if let Ok((_, _, _, _, mut squad_member)) = wingmen.get_mut(*member) {
+                squad_member.morale = squadron.morale;
+            }
+        }
+    }
+
+    for (entity, transform, enemy, mut ai, member) in wingmen.iter_mut() {
+        if leaders.contains(member.leader) {
+            continue;
+        }
+
+        // Leader down - the wingman is on its own from here
+        let morale = (member.morale - Squadron::LEADER_LOSS_MORALE).max(0.0);
+        ai.formation_slot = None;
+        ai.squad_order = None;
+        commands.entity(entity).remove::<SquadronMember>();
+
+        if rng.gen::<f32>() < morale {
+            println!("[Squadron] {:?} wingman holds its nerve", enemy.enemy_type);
+            continue;
+        }
+
+        if rng.gen::<f32>() < 0.5 {
+            // Scatter: break for a random rally point
+            let direction = Vec3::new(
+                rng.gen::<f32>() - 0.5,
+                (rng.gen::<f32>() - 0.5) * 0.3,
+                rng.gen::<f32>() - 0.5,
+            ).normalize_or_zero();
+            ai.regroup_point = transform.translation + direction * 150.0;
+            ai.action = AIAction::Regroup;
+            println!("[Squadron] {:?} wingman scatters", enemy.enemy_type);
+        } else {
+            ai.action = AIAction::Retreat;
+            println!("[Squadron] {:?} wingman retreats", enemy.enemy_type);
+        }
+        // Stay routed for a while before the utility AI takes over again
+        ai.think_timer = 8.0;
+    }
+}
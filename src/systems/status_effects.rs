@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use crate::components::combat::{Energy, Health, Shield};
+use crate::components::ship::{Player, Ship, Velocity};
+use crate::components::status_effects::*;
+
+/// Tick status effect durations and apply damage over time
+pub fn status_effect_tick_system(
+    time: Res<Time>,
+    mut query: Query<(&mut StatusEffects, &mut Health, Has<Player>)>,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut status, mut health, is_player) in query.iter_mut() {
+        if status.effects.is_empty() {
+            continue;
+        }
+
+        let expiring: Vec<StatusEffectKind> = status.effects.iter()
+            .filter(|e| e.remaining <= dt)
+            .map(|e| e.kind)
+            .collect();
+
+        let damage = status.tick(dt);
+        if damage > 0.0 {
+            health.current -= damage;
+        }
+
+        if is_player {
+            for kind in expiring {
+                debug!("{} wore off", kind.short_name());
+            }
+        }
+    }
+}
+
+/// Apply status effect stat modifiers: slows and disables cap speed, EMP drains energy and stalls shields.
+/// Disabled weapons are checked where weapons fire.
+pub fn status_effect_modifiers_system(
+    time: Res<Time>,
+    mut query: Query<(
+        &StatusEffects,
+        &Ship,
+        &mut Velocity,
+        Option<&mut Energy>,
+        Option<&mut Shield>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+
+    for (status, ship, mut velocity, energy, shield) in query.iter_mut() {
+        if status.effects.is_empty() {
+            continue;
+        }
+
+        let speed_multiplier = status.speed_multiplier();
+        if speed_multiplier < 1.0 {
+            // Boosting can't outrun a slow
+            let max_speed = ship.max_speed * speed_multiplier;
+            if velocity.0.length() > max_speed {
+                velocity.0 = velocity.0.normalize_or_zero() * max_speed;
+            }
+        }
+
+        let drain = status.energy_drain();
+        if drain > 0.0 {
+            if let Some(mut energy) = energy {
+                energy.current = (energy.current - drain * dt).max(0.0);
+            }
+        }
+
+        if status.shields_suppressed() {
+            if let Some(mut shield) = shield {
+                // Keep the recharge delay from ever elapsing
+                shield.time_since_last_hit = 0.0;
+            }
+        }
+    }
+}
+
+/// Apply hazard zone effects to every ship inside them
+pub fn hazard_zone_system(
+    hazards: Query<(&GlobalTransform, &HazardZone)>,
+    mut ships: Query<(&Transform, &mut StatusEffects, Has<Player>)>,
+) {
+    for (hazard_transform, hazard) in hazards.iter() {
+        let hazard_pos = hazard_transform.translation();
+        for (ship_transform, mut status, is_player) in ships.iter_mut() {
+            if ship_transform.translation.distance(hazard_pos) > hazard.radius {
+                continue;
+            }
+            if is_player && !status.has(hazard.kind) {
+                debug!("Hazard: {}!", hazard.kind.short_name());
+            }
+            status.apply(hazard.kind, hazard.duration, hazard.magnitude);
+        }
+    }
+}
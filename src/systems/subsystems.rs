@@ -0,0 +1,163 @@
+use bevy::prelude::*;
+use crate::components::combat::{Health, PowerDistribution, PowerSystem};
+use crate::components::ship::*;
+use crate::resources::PlayerInput;
+
+/// Cycle the player's targeted subsystem (G key)
+pub fn subsystem_target_cycle_system(
+    input: Res<PlayerInput>,
+    mut targeting: ResMut<SubsystemTargeting>,
+) {
+    if input.just_pressed(KeyCode::KeyG) {
+        targeting.cycle();
+        match targeting.target {
+            Some(piece_type) => println!("[Subsystems] Targeting enemy {}", piece_type.subsystem_name()),
+            None => println!("[Subsystems] Subsystem targeting off"),
+        }
+    }
+}
+
+/// Apply hull damage to the subsystem piece closest to the impact point.
+/// With a focused subsystem type, damage goes to the nearest surviving piece of that type instead.
+pub fn damage_subsystem(
+    pieces: &mut Query<(&GlobalTransform, &ShipPiece, &mut SubsystemHealth)>,
+    ship: Entity,
+    impact_point: Vec3,
+    damage: f32,
+    focus: Option<ShipPieceType>,
+) {
+    let hit_radius = 3.0;
+    let mut closest: Option<(f32, Mut<SubsystemHealth>)> = None;
+
+    for (piece_transform, piece, health) in pieces.iter_mut() {
+        if piece.parent_ship != ship || health.current <= 0.0 {
+            continue;
+        }
+        if let Some(focus_type) = focus {
+            if piece.piece_type != focus_type {
+                continue;
+            }
+        }
+
+        let distance = piece_transform.translation().distance(impact_point);
+        if focus.is_none() && distance > hit_radius {
+            continue;
+        }
+        if closest.as_ref().map_or(true, |(d, _)| distance < *d) {
+            closest = Some((distance, health));
+        }
+    }
+
+    if let Some((_, mut health)) = closest {
+        health.current -= damage;
+    }
+}
+
+/// Detach destroyed subsystem pieces and update their ship's subsystem status
+pub fn subsystem_destruction_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    pieces: Query<(Entity, &GlobalTransform, &ShipPiece, &SubsystemHealth)>,
+    mut ships: Query<(&mut SubsystemStatus, &Health, Option<&Velocity>, Has<Player>)>,
+) {
+    for (piece_entity, piece_transform, piece, subsystem_health) in pieces.iter() {
+        if subsystem_health.current > 0.0 {
+            continue;
+        }
+
+        let Ok((mut status, ship_health, ship_velocity, is_player)) = ships.get_mut(piece.parent_ship) else {
+            continue;
+        };
+
+        // Ship is about to be destroyed anyway - let the death system clean up
+        if ship_health.current <= 0.0 {
+            continue;
+        }
+
+        status.mark_destroyed(piece.piece_type);
+
+        if is_player {
+            println!("[Subsystems] WARNING: Player {} subsystem destroyed!", piece.piece_type.subsystem_name());
+        } else {
+            println!("[Subsystems] Enemy {} subsystem destroyed", piece.piece_type.subsystem_name());
+        }
+
+        let position = piece_transform.translation();
+        crate::systems::effects::spawn_hull_spark_effect(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            position,
+            Vec3::Y,
+        );
+
+        // Blow the piece off the ship and let it tumble away
+        let drift = Vec3::new(
+            rand::random::<f32>() - 0.5,
+            rand::random::<f32>() - 0.5,
+            rand::random::<f32>() - 0.5,
+        ).normalize_or_zero() * 6.0;
+        let inherited = ship_velocity.map(|v| v.0).unwrap_or(Vec3::ZERO);
+
+        commands.entity(piece_entity).remove_parent_in_place();
+        commands.entity(piece_entity).remove::<(ShipPiece, SubsystemHealth)>();
+        commands.entity(piece_entity).insert(DetachedPiece {
+            velocity: inherited + drift,
+            angular_velocity: Vec3::new(
+                rand::random::<f32>() * 4.0 - 2.0,
+                rand::random::<f32>() * 4.0 - 2.0,
+                rand::random::<f32>() * 4.0 - 2.0,
+            ),
+            lifetime: 5.0,
+        });
+    }
+}
+
+/// Drift, spin and shrink detached wreckage, despawning when done
+pub fn detached_piece_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut DetachedPiece)>,
+) {
+    let dt = time.delta_seconds();
+
+    for (entity, mut transform, mut piece) in query.iter_mut() {
+        piece.lifetime -= dt;
+
+        if piece.lifetime <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        transform.translation += piece.velocity * dt;
+        transform.rotate(Quat::from_euler(
+            EulerRot::XYZ,
+            piece.angular_velocity.x * dt,
+            piece.angular_velocity.y * dt,
+            piece.angular_velocity.z * dt,
+        ));
+
+        // Shrink away during the last second
+        if piece.lifetime < 1.0 {
+            transform.scale = Vec3::splat(piece.lifetime.max(0.05));
+        }
+    }
+}
+
+/// Apply the effects of destroyed engines: top speed (boosting included) drops with each one lost.
+/// Lost weapon mounts are checked where weapons fire.
+pub fn subsystem_effects_system(
+    mut query: Query<(&SubsystemStatus, &Ship, &mut Velocity, Option<&PowerDistribution>)>,
+) {
+    for (status, ship, mut velocity, power) in query.iter_mut() {
+        let engine_factor = status.engine_factor();
+        if engine_factor < 1.0 {
+            let max_speed = ship.max_speed * ship.boost_multiplier * engine_factor
+                * power.map_or(1.0, |p| p.multiplier(PowerSystem::Engines));
+            if velocity.0.length() > max_speed {
+                velocity.0 = velocity.0.normalize() * max_speed;
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::ship::*;
+use crate::components::combat::*;
+use crate::components::faction::{Civilian, CivilianKind};
+use crate::components::galaxy::{Obstacle, Planet};
+use crate::components::status_effects::StatusEffects;
+use crate::components::travel::JumpGate;
+use crate::resources::{Galaxy, GameRng, RngStream, TrafficTimer};
+use crate::utils::{math, ship_builder, steering};
+
+/// Most civilian ships in a system at once
+const MAX_CIVILIANS: usize = 4;
+/// Seconds a miner works a planet before heading out
+const MINING_TIME: f32 = 20.0;
+/// How close a civilian gets to a gate before jumping out
+const GATE_STANDOFF: f32 = 25.0;
+
+/// Neutral traffic spawner - traders and miners arrive through the system's gates, more often
+/// where civilians have a strong presence
+pub fn civilian_traffic_spawner_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut traffic_timer: ResMut<TrafficTimer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    galaxy: Option<Res<Galaxy>>,
+    gates: Query<(Entity, &GlobalTransform), With<JumpGate>>,
+    planets: Query<(Entity, &Obstacle), With<Planet>>,
+    civilians: Query<(), With<Civilian>>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    traffic_timer.0.tick(time.delta());
+    if !traffic_timer.0.finished() {
+        return;
+    }
+
+    let Some(system) = galaxy.as_ref().and_then(|g| g.current_system()) else {
+        return;
+    };
+    let rng = game_rng.stream(RngStream::Traffic);
+    if civilians.iter().count() >= MAX_CIVILIANS || rng.gen::<f32>() > system.presence(Faction::Neutral) {
+        return;
+    }
+
+    let gate_list: Vec<(Entity, Vec3)> = gates.iter().map(|(e, t)| (e, t.translation())).collect();
+    if gate_list.is_empty() {
+        return;
+    }
+    let (entry_gate, entry_pos) = gate_list[rng.gen_range(0..gate_list.len())];
+    let other_gates: Vec<Entity> = gate_list.iter().map(|(e, _)| *e).filter(|e| *e != entry_gate).collect();
+    let planet_list: Vec<(Entity, f32)> = planets.iter().map(|(e, o)| (e, o.radius)).collect();
+
+    // Traders need somewhere else to go, miners need something to mine
+    let wants_trader = rng.gen_bool(0.6);
+    let (kind, destination, standoff) = if (wants_trader || planet_list.is_empty()) && !other_gates.is_empty() {
+        (CivilianKind::Trader, other_gates[rng.gen_range(0..other_gates.len())], GATE_STANDOFF)
+    } else if let Some((planet, radius)) = (!planet_list.is_empty()).then(|| planet_list[rng.gen_range(0..planet_list.len())]) {
+        (CivilianKind::Miner, planet, radius + 12.0)
+    } else {
+        return;
+    };
+
+    // Emerge just outside the entry gate's ring
+    let offset = Vec3::new(rng.gen::<f32>() - 0.5, 0.0, rng.gen::<f32>() - 0.5).normalize_or_zero();
+    let spawn_pos = entry_pos + offset * GATE_STANDOFF;
+
+    let (ship, ship_type, health, shield) = match kind {
+        CivilianKind::Trader => (
+            Ship { max_speed: 25.0, ..Ship::corvette() },
+            ShipType::Corvette,
+            Health { current: 120.0, max: 120.0 },
+            Shield { current: 40.0, max: 40.0, recharge_rate: 4.0, recharge_delay: 3.0, time_since_last_hit: 10.0 },
+        ),
+        CivilianKind::Miner => (
+            Ship { max_speed: 30.0, ..Ship::fighter() },
+            ShipType::Fighter,
+            Health { current: 60.0, max: 60.0 },
+            Shield { current: 20.0, max: 20.0, recharge_rate: 3.0, recharge_delay: 3.0, time_since_last_hit: 10.0 },
+        ),
+    };
+
+    let civilian = commands.spawn((
+        SpatialBundle {
+            transform: Transform::from_translation(spawn_pos).looking_to(offset, Vec3::Y),
+            ..default()
+        },
+        ship,
+        health,
+        shield,
+        Velocity(Vec3::ZERO),
+        AngularVelocity(Vec3::ZERO),
+        Faction::Neutral,
+        StatusEffects::default(),
+        Civilian {
+            kind,
+            destination,
+            standoff,
+            mining_timer: MINING_TIME,
+            leaving: kind == CivilianKind::Trader,
+        },
+    )).id();
+
+    ship_builder::build_ship(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        ship_type,
+        civilian,
+        Color::srgb(0.75, 0.7, 0.5),
+    );
+
+    println!("[Traffic] {:?} arrived in {}", kind, system.name);
+}
+
+/// Civilian flight - cruise to the destination around planets and gates, mine for a while
+/// (miners), then jump out. Ships under fire run flat out.
+pub fn civilian_movement_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut civilians: Query<(Entity, &mut Transform, &mut Velocity, &Ship, &Shield, &mut Civilian)>,
+    destinations: Query<&GlobalTransform>,
+    gates: Query<Entity, With<JumpGate>>,
+    obstacles: Query<(&GlobalTransform, &Obstacle)>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let dt = time.delta_seconds();
+    let clearance = 6.0;
+    let obstacle_volumes: Vec<(Vec3, f32)> = obstacles.iter()
+        .map(|(transform, obstacle)| (transform.translation(), obstacle.radius))
+        .collect();
+
+    for (entity, mut transform, mut velocity, ship, shield, mut civilian) in civilians.iter_mut() {
+        let Ok(destination) = destinations.get(civilian.destination) else {
+            // Destination gone (system changed under us) - just leave
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let destination_pos = destination.translation();
+        let to_destination = destination_pos - transform.translation;
+        let distance = to_destination.length();
+
+        if distance <= civilian.standoff + 2.0 {
+            if civilian.leaving {
+                println!("[Traffic] {:?} jumped out", civilian.kind);
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+
+            // Miner on station - work the planet, then pick an exit gate
+            civilian.mining_timer -= dt;
+            velocity.0 *= math::damping(0.95, dt);
+            if civilian.mining_timer <= 0.0 {
+                let exits: Vec<Entity> = gates.iter().collect();
+                if exits.is_empty() {
+                    continue;
+                }
+                civilian.destination = exits[game_rng.stream(RngStream::Traffic).gen_range(0..exits.len())];
+                civilian.standoff = GATE_STANDOFF;
+                civilian.leaving = true;
+            }
+            continue;
+        }
+
+        let under_fire = shield.time_since_last_hit < 3.0;
+        let max_speed = if under_fire { ship.max_speed * ship.boost_multiplier } else { ship.max_speed };
+
+        // Aim for the near edge of the destination rather than its center
+        let arrival_point = destination_pos - to_destination / distance.max(0.001) * civilian.standoff;
+        let desired_velocity = steering::arrival(transform.translation, arrival_point, max_speed, 30.0)
+            + steering::obstacle_avoidance(transform.translation, velocity.0, &obstacle_volumes, clearance, 2.0) * max_speed;
+        let steer = (desired_velocity - velocity.0).clamp_length_max(ship.acceleration * dt);
+        velocity.0 += steer;
+        if velocity.0.length() > max_speed {
+            velocity.0 = velocity.0.normalize() * max_speed;
+        }
+
+        let mut position = transform.translation;
+        steering::enforce_clearance(&mut position, &mut velocity.0, &obstacle_volumes, clearance, dt);
+        transform.translation = position;
+
+        if velocity.0.length() > 1.0 {
+            let target_rotation = Transform::from_translation(transform.translation)
+                .looking_to(velocity.0.normalize(), Vec3::Y)
+                .rotation;
+            transform.rotation = transform.rotation.slerp(target_rotation, (ship.turn_rate * dt).min(1.0));
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::components::galaxy::SystemId;
 use crate::components::ai::Enemy;
 use crate::components::combat::Projectile;
 use crate::components::resources::Loot;
-use crate::resources::Galaxy;
+use crate::resources::{Galaxy, PlayerInput};
 
 /// Check if player is near a jump gate and show prompt
 pub fn check_jump_gate_proximity(
@@ -13,7 +13,7 @@ pub fn check_jump_gate_proximity(
     player_query: Query<&Transform, With<Player>>,
     gate_query: Query<(&Transform, &JumpGate), Without<Player>>,
     existing_prompts: Query<Entity, With<JumpPrompt>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: Res<PlayerInput>,
     hyperspace_query: Query<&HyperspaceEffect>,
 ) {
     // Don't show prompts during hyperspace jump
@@ -45,7 +45,7 @@ pub fn check_jump_gate_proximity(
         }
         
         // Check for activation (J key)
-        if keyboard.just_pressed(KeyCode::KeyJ) {
+        if input.just_pressed(KeyCode::KeyJ) {
             initiate_hyperspace_jump(&mut commands, target_system, &existing_prompts);
         }
     } else {
@@ -222,7 +222,8 @@ pub fn handle_system_transition(
     transition_flag: Option<Res<SystemTransitionFlag>>,
     mut galaxy: ResMut<Galaxy>,
     mut player_query: Query<&mut Transform, With<Player>>,
-    enemy_query: Query<Entity, With<Enemy>>,
+    mut wingmen: Query<(&mut Transform, &crate::components::wingman::Wingman), Without<Player>>,
+    enemy_query: Query<Entity, Or<(With<Enemy>, With<crate::components::Civilian>)>>,
     projectiles: Query<Entity, With<Projectile>>,
     loot_query: Query<Entity, With<Loot>>,
 ) {
@@ -244,9 +245,15 @@ pub fn handle_system_transition(
     // Reset player position to center of new system
     if let Ok(mut player_transform) = player_query.get_single_mut() {
         player_transform.translation = Vec3::new(0.0, 0.0, 0.0);
+        
+        // The wing jumps with the player and arrives in formation
+        for (mut wingman_transform, wingman) in wingmen.iter_mut() {
+            wingman_transform.translation = player_transform.translation + player_transform.rotation * wingman.slot;
+            wingman_transform.rotation = player_transform.rotation;
+        }
     }
     
-    // Despawn all enemies from previous system
+    // Despawn all enemies and traffic from previous system
     for entity in enemy_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
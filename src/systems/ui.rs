@@ -3,7 +3,8 @@ use crate::components::ship::Player;
 use crate::components::combat::{Health, Shield, Energy, WeaponMount};
 use crate::components::resources::Inventory;
 use crate::components::upgrades::{PlayerUpgrades, UpgradeType, UpgradeCategory};
-use crate::resources::{GameState, Galaxy};
+use crate::components::{Weapon, WeaponStash};
+use crate::resources::{GameState, Galaxy, Replay};
 use crate::systems::save_load;
 use crate::systems::ui_theme::{colors, borders, PanelConfig};
 use crate::systems::ui_animations::{PulseAnimation, GlitchEffect};
@@ -52,6 +53,26 @@ pub struct ChargeBar;
 #[derive(Component)]
 pub struct ReloadIndicator;
 
+/// Low ammo reserve warning text marker
+#[derive(Component)]
+pub struct AmmoReserveWarning;
+
+/// Missile lock status text marker (below the reticule)
+#[derive(Component)]
+pub struct LockStatusText;
+
+/// Subsystem targeting and status text marker
+#[derive(Component)]
+pub struct SubsystemStatusText;
+
+/// Weapon item tooltip panel marker
+#[derive(Component)]
+pub struct WeaponTooltipPanel;
+
+/// Weapon item tooltip text marker
+#[derive(Component)]
+pub struct WeaponTooltipText;
+
 /// Health percentage text marker
 #[derive(Component)]
 pub struct HealthPercentText;
@@ -60,10 +81,74 @@ pub struct HealthPercentText;
 #[derive(Component)]
 pub struct ShieldPercentText;
 
+/// Player status effect icons marker
+#[derive(Component)]
+pub struct StatusEffectsText;
+
+/// Status effect icon above an enemy health bar
+#[derive(Component)]
+pub struct EnemyStatusIcon {
+    pub kind: crate::components::StatusEffectKind,
+}
+
+/// Per-facing shield readout marker
+#[derive(Component)]
+pub struct ShieldFacingText;
+
+/// AI debug overlay label following an AI ship
+#[derive(Component)]
+pub struct AIDebugLabel {
+    pub ship: Entity,
+}
+
+/// Wing roster and current order marker
+#[derive(Component)]
+pub struct WingmenText;
+
+/// Faction standings marker
+#[derive(Component)]
+pub struct ReputationText;
+
+/// Encounter director readout (shown with the F12 AI debug overlay)
+#[derive(Component)]
+pub struct DirectorDebugText;
+
+/// Boss health bar root (top center, only while a boss is in the system)
+#[derive(Component)]
+pub struct BossBarRoot;
+
+/// Boss hull fill marker
+#[derive(Component)]
+pub struct BossHealthFill;
+
+/// Boss shield fill marker
+#[derive(Component)]
+pub struct BossShieldFill;
+
+/// Boss name and phase marker
+#[derive(Component)]
+pub struct BossNameText;
+
+/// Replay bar root (bottom center, only while recording or playing back)
+#[derive(Component)]
+pub struct ReplayBarRoot;
+
+/// Replay status line marker
+#[derive(Component)]
+pub struct ReplayStatusText;
+
+/// Replay progress fill marker
+#[derive(Component)]
+pub struct ReplayProgressFill;
+
 /// Energy percentage text marker
 #[derive(Component)]
 pub struct EnergyPercentText;
 
+/// Power distribution pips marker
+#[derive(Component)]
+pub struct PowerPipsText;
+
 /// Resource text marker
 #[derive(Component)]
 pub struct ResourceText {
@@ -258,6 +343,25 @@ pub fn setup_targeting_reticule(mut commands: Commands) {
             ReticuleCenter,
             PulseAnimation::new(3.0, colors::NEON_CYAN).with_range(0.7, 1.0),
         ));
+        
+        // Missile lock / countermeasure status below the reticule
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 12.0,
+                    color: colors::NEON_YELLOW,
+                    ..default()
+                },
+            ).with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(58.0),
+                left: Val::Px(-50.0),
+                width: Val::Px(150.0),
+                ..default()
+            }).with_text_justify(JustifyText::Center),
+            LockStatusText,
+        ));
     });
     
     // Create lead indicator (separate from main reticule) - Enhanced
@@ -311,6 +415,8 @@ pub fn update_weapon_hud_system(
     mut charge_bar_query: Query<Entity, With<ChargeBar>>,
     mut reload_text_query: Query<&mut Text, (With<ReloadIndicator>, Without<AmmoText>, Without<WeaponNameText>, Without<AutofireStatusText>)>,
     mut autofire_text_query: Query<&mut Text, (With<AutofireStatusText>, Without<AmmoText>, Without<WeaponNameText>, Without<ReloadIndicator>)>,
+    mut reserve_warning_query: Query<&mut Text, (With<AmmoReserveWarning>, Without<AmmoText>, Without<WeaponNameText>, Without<ReloadIndicator>, Without<AutofireStatusText>)>,
+    threat_query: Query<(), With<crate::components::combat::ProjectileHealth>>,
 ) {
     if let Ok((weapon_mount, turret)) = player_query.get_single() {
         if let Some(weapon) = weapon_mount.weapons.get(weapon_mount.current_weapon) {
@@ -362,6 +468,19 @@ pub fn update_weapon_hud_system(
                 }
             }
             
+            // Update low reserve warning
+            for mut text in reserve_warning_query.iter_mut() {
+                if weapon.uses_ammo() && weapon.current_ammo == 0 && weapon.reserve_ammo == 0 {
+                    text.sections[0].value = "// NO AMMO - FABRICATE [T]".to_string();
+                    text.sections[0].style.color = colors::DANGER_COLOR;
+                } else if weapon.uses_ammo() && weapon.ammo_fraction() < 0.25 {
+                    text.sections[0].value = "// LOW RESERVES - FABRICATE [T]".to_string();
+                    text.sections[0].style.color = colors::WARNING_COLOR;
+                } else {
+                    text.sections[0].value = "".to_string();
+                }
+            }
+            
             // Update charge bar (for plasma)
             for entity in charge_bar_query.iter_mut() {
                 let charge_percent = if weapon.weapon_type == crate::components::combat::WeaponType::Plasma {
@@ -374,35 +493,759 @@ pub fn update_weapon_hud_system(
                     width: Val::Percent(charge_percent),
                     height: Val::Px(15.0),
                     ..default()
-                });
-            }
-            
-            // Update reload indicator
-            for mut text in reload_text_query.iter_mut() {
-                if weapon.is_reloading {
-                    let reload_percent = ((weapon.reload_timer / weapon.reload_time) * 100.0).min(100.0);
-                    text.sections[0].value = format!("RELOADING... {}%", reload_percent as u32);
-                    text.sections[0].style.color = Color::srgb(1.0, 0.8, 0.2);
-                } else {
-                    text.sections[0].value = "".to_string();
-                }
-            }
+                });
+            }
+            
+            // Update reload indicator
+            for mut text in reload_text_query.iter_mut() {
+                if weapon.is_reloading {
+                    let reload_percent = ((weapon.reload_timer / weapon.reload_time) * 100.0).min(100.0);
+                    text.sections[0].value = format!("RELOADING... {}%", reload_percent as u32);
+                    text.sections[0].style.color = Color::srgb(1.0, 0.8, 0.2);
+                } else {
+                    text.sections[0].value = "".to_string();
+                }
+            }
+        }
+        
+        // Update turret status (outside weapon check since it's independent)
+        for mut text in autofire_text_query.iter_mut() {
+            if turret.enabled {
+                let mode_label = match turret.mode {
+                    crate::components::combat::TurretMode::Offensive => "AUTO-TURRET",
+                    crate::components::combat::TurretMode::PointDefense => "AUTO-TURRET [POINT-DEF]",
+                };
+                let locked = turret.hardpoints.iter().filter(|h| h.current_target.is_some()).count();
+                let intercepting = turret.hardpoints.iter()
+                    .any(|h| h.current_target.map_or(false, |t| threat_query.contains(t)));
+                let label = format!("{} {} {}/{}", mode_label, turret.policy.name(), locked, turret.hardpoints.len());
+                if intercepting {
+                    text.sections[0].value = format!("⟨⟨ {}: INTERCEPTING ⟩⟩", label);
+                    text.sections[0].style.color = colors::NEON_ORANGE;
+                } else if locked > 0 {
+                    text.sections[0].value = format!("⟨⟨ {}: LOCKED ⟩⟩", label);
+                    text.sections[0].style.color = colors::NEON_CYAN;
+                } else {
+                    text.sections[0].value = format!("⟨⟨ {}: SEARCHING ⟩⟩", label);
+                    text.sections[0].style.color = colors::NEON_YELLOW;
+                }
+            } else {
+                text.sections[0].value = "".to_string();
+            }
+        }
+    }
+}
+
+/// Update subsystem HUD - targeted enemy subsystem plus the player's own subsystem health
+pub fn update_subsystem_hud_system(
+    targeting: Res<crate::components::ship::SubsystemTargeting>,
+    player_query: Query<&crate::components::ship::SubsystemStatus, With<Player>>,
+    mut text_query: Query<&mut Text, With<SubsystemStatusText>>,
+) {
+    let target_name = targeting.target.map_or("ANY", |t| t.subsystem_name());
+    
+    for mut text in text_query.iter_mut() {
+        let mut sections = vec![TextSection::new(
+            format!("⟨ SUBSYSTEM TARGET: {} [G] ⟩\n", target_name),
+            TextStyle {
+                font_size: 12.0,
+                color: if targeting.target.is_some() { colors::NEON_ORANGE } else { Color::srgb(0.6, 0.7, 0.8) },
+                ..default()
+            },
+        )];
+        
+        if let Ok(status) = player_query.get_single() {
+            let damaged = status.engines_online < status.engines_total
+                || status.weapon_mounts_online < status.weapon_mounts_total
+                || status.shield_emitters_online < status.shield_emitters_total;
+            sections.push(TextSection::new(
+                format!(
+                    "ENG {}/{}  WPN {}/{}  SHD {}/{}",
+                    status.engines_online, status.engines_total,
+                    status.weapon_mounts_online, status.weapon_mounts_total,
+                    status.shield_emitters_online, status.shield_emitters_total,
+                ),
+                TextStyle {
+                    font_size: 11.0,
+                    color: if damaged { colors::WARNING_COLOR } else { Color::srgb(0.6, 0.7, 0.8) },
+                    ..default()
+                },
+            ));
+        }
+        
+        text.sections = sections;
+    }
+}
+
+/// Update the per-facing shield readout - each facing's charge, with its share of capacity when shunted
+pub fn update_shield_facing_hud_system(
+    player_query: Query<(&Shield, &crate::components::combat::ShieldFacings), With<Player>>,
+    mut text_query: Query<&mut Text, With<ShieldFacingText>>,
+) {
+    let Ok((shield, facings)) = player_query.get_single() else {
+        return;
+    };
+    let balanced = facings.allocation.iter().all(|a| (a - 0.25).abs() < 0.01);
+    
+    for mut text in text_query.iter_mut() {
+        text.sections = crate::components::combat::ShieldFacing::ALL.iter().map(|facing| {
+            let fraction = facings.fraction(*facing, shield.max);
+            let color = if fraction <= 0.0 {
+                colors::DANGER_COLOR
+            } else if fraction < 0.3 {
+                colors::WARNING_COLOR
+            } else {
+                colors::SHIELD_COLOR
+            };
+            let allocation = if balanced {
+                String::new()
+            } else {
+                format!("({:.0}%)", facings.allocation[facing.index()] * 100.0)
+            };
+            TextSection::new(
+                format!("{} {:.0}%{}  ", facing.short_name(), fraction * 100.0, allocation),
+                TextStyle {
+                    font_size: 11.0,
+                    color,
+                    ..default()
+                },
+            )
+        }).collect();
+    }
+}
+
+/// Update the player's status effect icons - name, stacks and time left
+pub fn update_status_effects_hud_system(
+    player_query: Query<&crate::components::StatusEffects, With<Player>>,
+    mut text_query: Query<&mut Text, With<StatusEffectsText>>,
+) {
+    let Ok(status) = player_query.get_single() else {
+        return;
+    };
+    
+    for mut text in text_query.iter_mut() {
+        text.sections = status.effects.iter().map(|effect| {
+            let stacks = if effect.stacks > 1 { format!("×{}", effect.stacks) } else { String::new() };
+            TextSection::new(
+                format!("◆ {}{} {:.1}s  ", effect.kind.short_name(), stacks, effect.remaining),
+                TextStyle {
+                    font_size: 12.0,
+                    color: effect.kind.color(),
+                    ..default()
+                },
+            )
+        }).collect();
+    }
+}
+
+/// Show/hide status effect icons above enemy health bars
+pub fn update_enemy_status_icons_system(
+    enemy_query: Query<&crate::components::StatusEffects, With<crate::components::ai::Enemy>>,
+    bar_query: Query<&EnemyHealthBar>,
+    mut icon_query: Query<(&Parent, &EnemyStatusIcon, &mut Visibility)>,
+) {
+    for (parent, icon, mut visibility) in icon_query.iter_mut() {
+        let active = bar_query.get(parent.get())
+            .ok()
+            .and_then(|bar| enemy_query.get(bar.parent_ship).ok())
+            .map_or(false, |status| status.has(icon.kind));
+        
+        let desired = if active { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != desired {
+            *visibility = desired;
+        }
+    }
+}
+
+/// AI debug overlay - each AI ship's decision and action scores, projected above it on screen
+pub fn update_ai_debug_overlay_system(
+    mut commands: Commands,
+    overlay: Res<crate::components::ai::AIDebugOverlay>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<crate::components::camera::CameraController>>,
+    ai_query: Query<(Entity, &GlobalTransform, &crate::components::ai::AIController, Option<&crate::components::ai::Enemy>)>,
+    mut label_query: Query<(Entity, &AIDebugLabel, &mut Text, &mut Style, &mut Visibility)>,
+) {
+    use crate::components::ai::AIAction;
+    
+    if !overlay.enabled {
+        for (entity, ..) in label_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+    
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    
+    let mut labelled = std::collections::HashSet::new();
+    for (entity, label, mut text, mut style, mut visibility) in label_query.iter_mut() {
+        let Ok((_, ship_transform, ai, enemy)) = ai_query.get(label.ship) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        labelled.insert(label.ship);
+        
+        let Some(screen_pos) = camera.world_to_viewport(camera_transform, ship_transform.translation() + Vec3::Y * 5.0) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = Visibility::Inherited;
+        style.left = Val::Px(screen_pos.x - 80.0);
+        style.top = Val::Px(screen_pos.y - 40.0);
+        
+        let mut sections = vec![TextSection::new(
+            format!("{} > {} ({:?})\n", enemy.map_or("Wingman".to_string(), |e| format!("{:?}", e.enemy_type)), ai.action.name(), ai.state),
+            TextStyle {
+                font_size: 12.0,
+                color: colors::NEON_CYAN,
+                ..default()
+            },
+        )];
+        sections.extend(AIAction::ALL.iter().map(|action| {
+            TextSection::new(
+                format!("{} {:.2} ", action.short_name(), ai.scores[action.index()]),
+                TextStyle {
+                    font_size: 11.0,
+                    color: if *action == ai.action { colors::NEON_YELLOW } else { Color::srgb(0.6, 0.6, 0.7) },
+                    ..default()
+                },
+            )
+        }));
+        text.sections = sections;
+    }
+    
+    // New AI ships get an empty label - it's filled in and positioned on the next update
+    for (ship, ..) in ai_query.iter() {
+        if labelled.contains(&ship) {
+            continue;
+        }
+        commands.spawn((
+            TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                z_index: ZIndex::Global(40),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            AIDebugLabel { ship },
+        ));
+    }
+}
+
+/// Update the wing roster - standing order, then each wingman's hull and shields
+pub fn update_wingmen_hud_system(
+    wing: Res<crate::components::wingman::WingCommand>,
+    wingmen: Query<(&crate::components::wingman::Wingman, &Health, &Shield)>,
+    mut text_query: Query<&mut Text, With<WingmenText>>,
+) {
+    let mut roster: Vec<_> = wingmen.iter().collect();
+    roster.sort_by_key(|(wingman, ..)| wingman.callsign);
+    
+    for mut text in text_query.iter_mut() {
+        let mut sections = vec![TextSection::new(
+            if roster.is_empty() {
+                format!("NO WINGMEN ({} SCRAP + {} TECH TO HIRE)\n", wing.hire_scrap_cost, wing.hire_tech_cost)
+            } else {
+                format!("ORDER: {}\n", wing.order.name())
+            },
+            TextStyle {
+                font_size: 12.0,
+                color: colors::NEON_CYAN,
+                ..default()
+            },
+        )];
+        sections.extend(roster.iter().map(|(wingman, health, shield)| {
+            let hull = (health.current / health.max).clamp(0.0, 1.0);
+            let shields = if shield.max > 0.0 { (shield.current / shield.max).clamp(0.0, 1.0) } else { 0.0 };
+            TextSection::new(
+                format!("{:<8} HULL {:>3.0}%  SHD {:>3.0}%\n", wingman.callsign, hull * 100.0, shields * 100.0),
+                TextStyle {
+                    font_size: 12.0,
+                    color: if hull < 0.3 { colors::DANGER_COLOR } else if hull < 0.6 { colors::WARNING_COLOR } else { colors::NEON_GREEN },
+                    ..default()
+                },
+            )
+        }));
+        text.sections = sections;
+    }
+}
+
+/// Update faction standings - one line per NPC faction, colored by attitude
+pub fn update_reputation_hud_system(
+    reputation: Res<crate::components::Reputation>,
+    mut text_query: Query<&mut Text, With<ReputationText>>,
+) {
+    use crate::components::combat::Faction;
+    
+    for mut text in text_query.iter_mut() {
+        text.sections = Faction::NPC.iter().map(|faction| {
+            let label = reputation.label(*faction);
+            TextSection::new(
+                format!("{:<10} {:>4.0}  {}\n", faction.name(), reputation.standing(*faction), label),
+                TextStyle {
+                    font_size: 12.0,
+                    color: match label {
+                        "HOSTILE" => colors::DANGER_COLOR,
+                        "FRIENDLY" => colors::NEON_GREEN,
+                        _ => colors::NEON_CYAN,
+                    },
+                    ..default()
+                },
+            )
+        }).collect();
+    }
+}
+
+/// Encounter director readout - pacing phase, intensity and threat budget while the AI debug overlay is on
+pub fn update_director_debug_system(
+    mut commands: Commands,
+    overlay: Res<crate::components::ai::AIDebugOverlay>,
+    director: Res<crate::components::EncounterDirector>,
+    mut text_query: Query<(Entity, &mut Text), With<DirectorDebugText>>,
+) {
+    if !overlay.enabled {
+        for (entity, _) in text_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+    
+    let readout = format!(
+        "// DIRECTOR: {} ({:.0}s)\nINTENSITY {:.2}\nTHREAT {}/{}\nWAVES {} - LAST: {}",
+        director.phase.name(),
+        director.phase_timer,
+        director.intensity,
+        director.live_threat,
+        director.threat_budget,
+        director.waves_spawned,
+        director.last_encounter.unwrap_or("-"),
+    );
+    
+    let Ok((_, mut text)) = text_query.get_single_mut() else {
+        commands.spawn((
+            TextBundle::from_section(
+                readout,
+                TextStyle {
+                    font_size: 12.0,
+                    color: colors::NEON_CYAN,
+                    ..default()
+                },
+            ).with_style(Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                ..default()
+            }),
+            DirectorDebugText,
+        ));
+        return;
+    };
+    text.sections[0].value = readout;
+}
+
+/// Boss health bar - created when a boss arrives, removed once it's gone
+pub fn update_boss_health_bar_system(
+    mut commands: Commands,
+    bosses: Query<(&crate::components::Boss, &Health, &Shield)>,
+    root_query: Query<Entity, With<BossBarRoot>>,
+    health_fill_query: Query<Entity, With<BossHealthFill>>,
+    shield_fill_query: Query<Entity, With<BossShieldFill>>,
+    mut name_query: Query<&mut Text, With<BossNameText>>,
+) {
+    let Some((boss, health, shield)) = bosses.iter().next() else {
+        for entity in root_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+    
+    if root_query.is_empty() {
+        setup_boss_health_bar(&mut commands);
+        return;
+    }
+    
+    let health_percent = (health.current / health.max).clamp(0.0, 1.0);
+    for entity in health_fill_query.iter() {
+        commands.entity(entity).insert(Style {
+            width: Val::Percent(health_percent * 100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        });
+    }
+    
+    let shield_percent = if shield.max > 0.0 { (shield.current / shield.max).clamp(0.0, 1.0) } else { 0.0 };
+    for entity in shield_fill_query.iter() {
+        commands.entity(entity).insert(Style {
+            width: Val::Percent(shield_percent * 100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        });
+    }
+    
+    for mut text in name_query.iter_mut() {
+        text.sections[0].value = format!(
+            ">> {} // PHASE {}/{} // {:.0}%",
+            boss.name.to_uppercase(),
+            boss.phase + 1,
+            boss.phases.len(),
+            health_percent * 100.0,
+        );
+    }
+}
+
+fn setup_boss_health_bar(commands: &mut Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(20.0),
+                    left: Val::Percent(30.0),
+                    width: Val::Percent(40.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ..default()
+            },
+            BossBarRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: colors::NEON_MAGENTA,
+                        ..default()
+                    },
+                ),
+                BossNameText,
+            ));
+            
+            // Hull bar
+            parent.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(18.0),
+                    border: borders::THICK_BORDER,
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(),
+                border_color: colors::NEON_MAGENTA.into(),
+                border_radius: BorderRadius::all(Val::Px(2.0)),
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: colors::HEALTH_COLOR.into(),
+                        ..default()
+                    },
+                    BossHealthFill,
+                ));
+            });
+            
+            // Thin shield bar underneath
+            parent.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(6.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(),
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: colors::SHIELD_COLOR.into(),
+                        ..default()
+                    },
+                    BossShieldFill,
+                ));
+            });
+        });
+}
+
+/// Update the replay bar - REC/REPLAY, speed, time and sync state, plus playback progress
+pub fn update_replay_bar_system(
+    mut commands: Commands,
+    replay: Res<crate::resources::Replay>,
+    root_query: Query<Entity, With<ReplayBarRoot>>,
+    fill_query: Query<Entity, With<ReplayProgressFill>>,
+    mut status_query: Query<&mut Text, With<ReplayStatusText>>,
+) {
+    use crate::resources::ReplayMode;
+    
+    let Some(data) = replay.data.as_ref().filter(|_| replay.is_active()) else {
+        for entity in root_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+    
+    if root_query.is_empty() {
+        setup_replay_bar(&mut commands);
+        return;
+    }
+    
+    let seconds = |ticks: u64| {
+        let total = (ticks as f64 / data.config.tick_rate) as u64;
+        format!("{}:{:02}", total / 60, total % 60)
+    };
+    let sync = match replay.diverged_at {
+        Some(tick) => format!("DESYNC @ {}", seconds(tick)),
+        None => "SYNC OK".to_string(),
+    };
+    
+    let (status, color, progress) = match replay.mode {
+        ReplayMode::Recording => (
+            format!("● REC // {} // {}", seconds(replay.tick), sync),
+            colors::DANGER_COLOR,
+            1.0,
+        ),
+        _ => {
+            let speed = if replay.seek_target.is_some() {
+                "SEEKING".to_string()
+            } else {
+                format!("{}x", crate::resources::PLAYBACK_SPEEDS[replay.speed])
+            };
+            (
+                format!(
+                    "▶ REPLAY {} // {} / {} // {}",
+                    speed,
+                    seconds(replay.tick),
+                    seconds(data.tick_count()),
+                    sync,
+                ),
+                if replay.diverged_at.is_some() { colors::WARNING_COLOR } else { colors::NEON_CYAN },
+                replay.tick as f32 / data.tick_count().max(1) as f32,
+            )
+        }
+    };
+    
+    for mut text in status_query.iter_mut() {
+        text.sections[0].value = status.clone();
+        text.sections[0].style.color = color;
+    }
+    
+    for entity in fill_query.iter() {
+        commands.entity(entity).insert(Style {
+            width: Val::Percent(progress.clamp(0.0, 1.0) * 100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        });
+    }
+}
+
+fn setup_replay_bar(commands: &mut Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(90.0),
+                    left: Val::Percent(35.0),
+                    width: Val::Percent(30.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ..default()
+            },
+            ReplayBarRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 15.0,
+                        color: colors::NEON_CYAN,
+                        ..default()
+                    },
+                ),
+                ReplayStatusText,
+            ));
+            
+            // Progress through the recording
+            parent.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(6.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(),
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: colors::NEON_CYAN.into(),
+                        ..default()
+                    },
+                    ReplayProgressFill,
+                ));
+            });
+            
+            parent.spawn(TextBundle::from_section(
+                "F9 record/stop  F10 play/stop  - / = speed  , / . seek",
+                TextStyle {
+                    font_size: 12.0,
+                    color: Color::srgba(0.7, 0.7, 0.8, 0.8),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Update the power distribution widget - filled/empty pips per system
+pub fn update_power_hud_system(
+    player_query: Query<&crate::components::combat::PowerDistribution, With<Player>>,
+    mut text_query: Query<&mut Text, With<PowerPipsText>>,
+) {
+    use crate::components::combat::{PowerDistribution, PowerSystem};
+    
+    let Ok(power) = player_query.get_single() else {
+        return;
+    };
+    
+    for mut text in text_query.iter_mut() {
+        text.sections = [
+            ("WPN", PowerSystem::Weapons, colors::ENERGY_COLOR),
+            ("SHD", PowerSystem::Shields, colors::SHIELD_COLOR),
+            ("ENG", PowerSystem::Engines, colors::NEON_ORANGE),
+        ].into_iter().map(|(label, system, color)| {
+            let pips = power.pips(system);
+            let bar: String = (0..PowerDistribution::MAX_PIPS)
+                .map(|i| if i < pips { '■' } else { '□' })
+                .collect();
+            TextSection::new(
+                format!("{} {}  ", label, bar),
+                TextStyle {
+                    font_size: 13.0,
+                    color: if pips == 0 { Color::srgb(0.4, 0.4, 0.5) } else { color },
+                    ..default()
+                },
+            )
+        }).collect();
+    }
+}
+
+/// One comparison line for the weapon tooltip, colored by whether the new value is better
+fn tooltip_stat_line(label: &str, current: f32, new: f32, higher_is_better: bool) -> TextSection {
+    let change = if current.abs() > f32::EPSILON { (new - current) / current * 100.0 } else { 0.0 };
+    let color = if change.abs() < 0.5 {
+        Color::srgb(0.7, 0.7, 0.8)
+    } else if (change > 0.0) == higher_is_better {
+        colors::NEON_GREEN
+    } else {
+        colors::DANGER_COLOR
+    };
+    TextSection::new(
+        format!("{:<10} {:>7.1} -> {:>7.1} ({:+.0}%)\n", label, current, new, change),
+        TextStyle {
+            font_size: 12.0,
+            color,
+            ..default()
+        },
+    )
+}
+
+/// Update weapon item tooltip - compares the selected stash item to the equipped weapon
+pub fn update_weapon_tooltip_system(
+    stash: Res<WeaponStash>,
+    player_query: Query<&WeaponMount, With<Player>>,
+    mut panel_query: Query<&mut Visibility, With<WeaponTooltipPanel>>,
+    mut text_query: Query<&mut Text, With<WeaponTooltipText>>,
+) {
+    let item = stash.selected_item();
+    
+    for mut visibility in panel_query.iter_mut() {
+        *visibility = if item.is_some() { Visibility::Visible } else { Visibility::Hidden };
+    }
+    
+    let (Some(item), Ok(weapon_mount)) = (item, player_query.get_single()) else {
+        return;
+    };
+    let Some(current) = weapon_mount.weapons.get(weapon_mount.current_weapon) else {
+        return;
+    };
+    let new: Weapon = item.to_weapon();
+    
+    for mut text in text_query.iter_mut() {
+        let mut sections = vec![
+            TextSection::new(
+                format!("{}\n", item.name.to_uppercase()),
+                TextStyle {
+                    font_size: 15.0,
+                    color: item.rarity.color(),
+                    ..default()
+                },
+            ),
+            TextSection::new(
+                format!("// STASH {}/{}  vs SLOT {}\n", stash.selected + 1, stash.items.len(), weapon_mount.current_weapon + 1),
+                TextStyle {
+                    font_size: 11.0,
+                    color: Color::srgb(0.7, 0.7, 0.8),
+                    ..default()
+                },
+            ),
+            tooltip_stat_line("DAMAGE", current.damage, new.damage, true),
+            tooltip_stat_line("FIRE RATE", current.fire_rate, new.fire_rate, true),
+            tooltip_stat_line("VS SHIELD", current.shield_damage_multiplier, new.shield_damage_multiplier, true),
+            tooltip_stat_line("VS HULL", current.hull_damage_multiplier, new.hull_damage_multiplier, true),
+            tooltip_stat_line("VELOCITY", current.projectile_speed, new.projectile_speed, true),
+        ];
+        if current.max_heat > 0.0 || new.max_heat > 0.0 {
+            sections.push(tooltip_stat_line("HEAT/SHOT", current.heat_per_shot, new.heat_per_shot, false));
+        }
+        if current.uses_ammo() || new.uses_ammo() {
+            sections.push(tooltip_stat_line("MAGAZINE", current.max_ammo as f32, new.max_ammo as f32, true));
+        }
+        if current.energy_cost > 0.0 || new.energy_cost > 0.0 {
+            sections.push(tooltip_stat_line("ENERGY", current.energy_cost, new.energy_cost, false));
         }
         
-        // Update turret status (outside weapon check since it's independent)
-        for mut text in autofire_text_query.iter_mut() {
-            if turret.enabled {
-                if turret.current_target.is_some() {
-                    text.sections[0].value = "⟨⟨ AUTO-TURRET: LOCKED ⟩⟩".to_string();
-                    text.sections[0].style.color = colors::NEON_CYAN;
-                } else {
-                    text.sections[0].value = "⟨⟨ AUTO-TURRET: SEARCHING ⟩⟩".to_string();
-                    text.sections[0].style.color = colors::NEON_YELLOW;
-                }
-            } else {
-                text.sections[0].value = "".to_string();
-            }
+        for affix in &item.affixes {
+            sections.push(TextSection::new(
+                format!("  + {}\n", affix.description()),
+                TextStyle {
+                    font_size: 12.0,
+                    color: item.rarity.color(),
+                    ..default()
+                },
+            ));
         }
+        
+        sections.push(TextSection::new(
+            "[ / ] BROWSE   [Y] EQUIP   [DEL] SALVAGE",
+            TextStyle {
+                font_size: 11.0,
+                color: colors::NEON_CYAN,
+                ..default()
+            },
+        ));
+        
+        text.sections = sections;
     }
 }
 
@@ -492,6 +1335,7 @@ pub fn update_hud_system(
             ResourceType::EnergyCores => ("[●]", "CORES", inventory.energy_cores),
             ResourceType::RareMinerals => ("[◆]", "MINERALS", inventory.rare_minerals),
             ResourceType::TechComponents => ("[▲]", "TECH", inventory.tech_components),
+            ResourceType::Ammunition => ("[≡]", "AMMO", 0),
         };
         text.sections[0].value = format!("{} {}: {}", icon, name, value);
     }
@@ -742,6 +1586,19 @@ fn setup_hud(commands: &mut Commands) {
                         ShieldPercentText,
                     ));
                 });
+                
+                // Per-facing readout (FWD / AFT / PORT / STBD)
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 11.0,
+                            color: colors::SHIELD_COLOR,
+                            ..default()
+                        },
+                    ),
+                    ShieldFacingText,
+                ));
             });
             
             // Energy bar container - CYBERPUNK STYLE
@@ -811,6 +1668,99 @@ fn setup_hud(commands: &mut Commands) {
                 });
             });
             
+            // Power distribution widget - pips for weapons / shields / engines
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(3.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "// POWER CORE [F1-F4]",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: colors::NEON_YELLOW,
+                        ..default()
+                    },
+                ));
+                
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 13.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    PowerPipsText,
+                ));
+            });
+            
+            // Wing roster - wingmen health and the standing order
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(3.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "// WING [F5-F8 ORDERS, H HIRE]",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: colors::NEON_YELLOW,
+                        ..default()
+                    },
+                ));
+                
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 12.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    WingmenText,
+                ));
+            });
+            
+            // Faction standings - who will shoot at the player
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(3.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "// STANDING",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: colors::NEON_YELLOW,
+                        ..default()
+                    },
+                ));
+                
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 12.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    ReputationText,
+                ));
+            });
+            
             // Weapon status section - CYBERPUNK STYLE
             parent.spawn(NodeBundle {
                 style: Style {
@@ -898,6 +1848,19 @@ fn setup_hud(commands: &mut Commands) {
                     AmmoText,
                 ));
                 
+                // Low reserve warning - CYBERPUNK
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 11.0,
+                            color: colors::WARNING_COLOR,
+                            ..default()
+                        },
+                    ),
+                    AmmoReserveWarning,
+                ));
+                
                 // Charge bar (for plasma) - CYBERPUNK
                 parent.spawn(NodeBundle {
                     style: Style {
@@ -969,6 +1932,32 @@ fn setup_hud(commands: &mut Commands) {
                     AutofireStatusText, // Reusing component name for compatibility
                     PulseAnimation::new(1.0, colors::NEON_CYAN).with_range(0.8, 1.0),
                 ));
+                
+                // Subsystem targeting / own subsystem status - CYBERPUNK
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 12.0,
+                            color: colors::NEON_CYAN,
+                            ..default()
+                        },
+                    ),
+                    SubsystemStatusText,
+                ));
+                
+                // Active status effects (burning, EMP, ...)
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 12.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    StatusEffectsText,
+                ));
             });
             
             // Resource display section - CYBERPUNK CHIPS
@@ -1067,6 +2056,43 @@ fn setup_hud(commands: &mut Commands) {
                 UpgradeNotificationPulse { pulse_timer: 0.0 },
             ));
         });
+    
+    // Weapon item tooltip - right side, only visible when the stash has items
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(20.0),
+                    top: Val::Px(100.0),
+                    width: Val::Px(300.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.75).into(),
+                border_color: colors::NEON_CYAN.into(),
+                border_radius: BorderRadius::all(Val::Px(2.0)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            HudRoot,
+            WeaponTooltipPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::srgb(0.9, 0.9, 1.0),
+                        ..default()
+                    },
+                ),
+                WeaponTooltipText,
+            ));
+        });
 }
 
 /// Main menu marker
@@ -1235,9 +2261,15 @@ pub fn main_menu_system(
 /// Check for upgrade key press
 pub fn check_upgrade_key(
     keyboard: Res<ButtonInput<KeyCode>>,
+    replay: Res<Replay>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     if keyboard.just_pressed(KeyCode::KeyU) {
+        // Purchases aren't part of the tick input - a replay couldn't repeat them
+        if replay.is_active() {
+            println!("[UI System] Upgrades are locked while recording or playing back");
+            return;
+        }
         println!("[UI System] Opening upgrade menu...");
         next_state.set(GameState::Upgrade);
     }
@@ -1906,6 +2938,8 @@ pub fn pause_menu_system(
     player_query: Query<(&Transform, &Health, &Shield, &Energy), With<Player>>,
     inventory: Res<Inventory>,
     upgrades: Res<PlayerUpgrades>,
+    weapon_stash: Res<WeaponStash>,
+    reputation: Res<crate::components::Reputation>,
     galaxy: Option<Res<Galaxy>>,
 ) {
     for (interaction, button_type, mut bg_color) in button_query.iter_mut() {
@@ -1918,7 +2952,7 @@ pub fn pause_menu_system(
                     }
                     PauseMenuButton::Save => {
                         println!("[UI System] Saving game...");
-                        match save_load::save_game(&player_query, &inventory, &upgrades, galaxy.as_deref()) {
+                        match save_load::save_game(&player_query, &inventory, &upgrades, &weapon_stash, &reputation, galaxy.as_deref()) {
                             Ok(_) => println!("[UI System] Game saved successfully!"),
                             Err(e) => println!("[UI System] Failed to save game: {}", e),
                         }
@@ -2045,10 +3079,10 @@ pub fn cleanup_game_over_menu(
     }
 }
 
-/// Cleanup HUD when entering game over
+/// Cleanup HUD (and any boss bar or director readout) when entering game over
 pub fn cleanup_hud_on_game_over(
     mut commands: Commands,
-    hud_query: Query<Entity, With<HudRoot>>,
+    hud_query: Query<Entity, Or<(With<HudRoot>, With<BossBarRoot>, With<DirectorDebugText>, With<ReplayBarRoot>)>>,
 ) {
     for entity in hud_query.iter() {
         commands.entity(entity).despawn_recursive();
@@ -2160,6 +3194,10 @@ fn spawn_cyberpunk_menu_button<T: Component>(parent: &mut ChildBuilder, text: &s
 #[derive(Resource)]
 pub struct RestartGameFlag;
 
+/// Galaxy seed for the next restart (replays need their run's seed) - random when absent
+#[derive(Resource)]
+pub struct RestartSeed(pub u64);
+
 /// Flag resource to indicate game load
 #[derive(Resource)]
 pub struct LoadGameFlag;
@@ -2219,145 +3257,22 @@ pub fn update_upgrade_notification_pulse(
     }
 }
 
-/// Calculate intercept point for a moving target using analytical solution
-/// 
-/// This solves the classic interception problem:
-/// Given:
-///   - P0: Target's current position
-///   - V0: Target's velocity vector
-///   - P1: Shooter's position (projectile origin)
-///   - s1: Projectile speed (scalar, not vector)
-/// 
-/// Find:
-///   - V: The direction vector for the projectile
-///   - t: Time of impact
-/// 
-/// Solution:
-/// At time t, projectile position = P1 + V*t where |V| = s1
-/// At time t, target position = P0 + V0*t
-/// 
-/// These must be equal: P1 + V*t = P0 + V0*t
-/// Since |V| = s1, we have: |P0 + V0*t - P1| = s1*t
-/// 
-/// Squaring both sides and solving the quadratic equation gives us t.
-fn calculate_intercept_point(
-    shooter_pos: Vec3,
-    _shooter_velocity: Vec3,  // Not used since projectiles don't inherit momentum
-    target_pos: Vec3,
-    target_velocity: Vec3,
-    projectile_speed: f32,
-    _shoot_direction: Vec3,
-) -> Option<Vec3> {
-    // Safety checks
-    if !shooter_pos.is_finite() || !target_pos.is_finite() {
-        return None;
-    }
-    if !target_velocity.is_finite() {
-        return None;
-    }
-    if !projectile_speed.is_finite() || projectile_speed <= 0.0 {
-        return None;
-    }
-    
-    // Relative position from shooter to target
-    let to_target = target_pos - shooter_pos;
-    
-    // Solve for time t when projectile intercepts target
-    // |to_target + target_velocity * t| = projectile_speed * t
-    // Squaring both sides:
-    // |to_target|^2 + 2*(to_target · target_velocity)*t + |target_velocity|^2*t^2 = projectile_speed^2 * t^2
-    // 
-    // Rearranging into standard quadratic form: a*t^2 + b*t + c = 0
-    // (|target_velocity|^2 - projectile_speed^2) * t^2 + 2*(to_target · target_velocity) * t + |to_target|^2 = 0
-    
-    let a = target_velocity.length_squared() - projectile_speed * projectile_speed;
-    let b = 2.0 * to_target.dot(target_velocity);
-    let c = to_target.length_squared();
-    
-    // Safety check
-    if !a.is_finite() || !b.is_finite() || !c.is_finite() {
-        return None;
-    }
-    
-    // Special case: target very close to shooter
-    if c < 0.01 {
-        return Some(target_pos);
-    }
-    
-    // Solve the quadratic equation
-    let time_to_intercept = if a.abs() < 0.0001 {
-        // Linear case: target speed ≈ projectile speed
-        // Equation becomes: b*t + c = 0
-        if b.abs() > 0.0001 {
-            let t = -c / b;
-            if t > 0.0 { t } else { return None; }
-        } else {
-            // Target nearly stationary or moving perpendicular
-            let distance = to_target.length();
-            distance / projectile_speed
-        }
-    } else {
-        // Quadratic case: standard solution
-        let discriminant = b * b - 4.0 * a * c;
-        
-        // Check if intercept is possible
-        if discriminant < 0.0 {
-            // No real solution - target too fast to intercept
-            // Use simple prediction as fallback
-            let distance = to_target.length();
-            let simple_time = distance / projectile_speed;
-            let fallback_pos = target_pos + target_velocity * simple_time;
-            return if fallback_pos.is_finite() {
-                Some(fallback_pos)
-            } else {
-                None
-            };
-        }
-        
-        // Two solutions - we want the smallest positive one (earliest intercept)
-        let sqrt_disc = discriminant.sqrt();
-        let t1 = (-b - sqrt_disc) / (2.0 * a);
-        let t2 = (-b + sqrt_disc) / (2.0 * a);
-        
-        // Choose the earliest positive time
-        if t1 > 0.0 && (t2 <= 0.0 || t1 < t2) {
-            t1
-        } else if t2 > 0.0 {
-            t2
-        } else {
-            // Both negative - cannot intercept (target moving away faster than we can catch)
-            return None;
-        }
-    };
-    
-    // Validate time is reasonable
-    if !time_to_intercept.is_finite() || time_to_intercept < 0.0 || time_to_intercept > 100.0 {
-        return None;
-    }
-    
-    // Calculate the intercept point
-    let intercept_pos = target_pos + target_velocity * time_to_intercept;
-    
-    // Final validation
-    if intercept_pos.is_finite() {
-        Some(intercept_pos)
-    } else {
-        None
-    }
-}
-
 /// Update targeting reticule position and color based on where bullets will go
 /// Now accounts for player velocity, enemy velocity, and projectile speed for accurate prediction
 pub fn update_targeting_reticule_system(
-    player_query: Query<(&Transform, &WeaponMount, &crate::components::ship::Velocity, &crate::components::ship_classes::ClassBonuses), With<Player>>,
+    player_query: Query<(&Transform, &WeaponMount, &crate::components::ship_classes::ClassBonuses), With<Player>>,
     enemy_query: Query<(&Transform, &crate::components::ship::Velocity), (With<crate::components::ai::Enemy>, Without<Player>)>,
     camera_query: Query<(&Camera, &GlobalTransform), With<crate::components::camera::CameraController>>,
     mut reticule_query: Query<&mut Style, (With<TargetingReticule>, Without<LeadIndicator>)>,
     mut circle_query: Query<&mut BorderColor, (With<ReticuleCircle>, Without<Player>, Without<LeadIndicator>)>,
     mut center_query: Query<&mut BackgroundColor, (With<ReticuleCenter>, Without<Player>)>,
     mut lead_query: Query<(&mut Style, &mut Visibility, &mut BorderColor), (With<LeadIndicator>, Without<TargetingReticule>, Without<ReticuleCircle>)>,
+    time: Res<Time>,
+    lock_query: Query<(Entity, &crate::components::combat::MissileLock, &crate::components::combat::Countermeasures), With<Player>>,
+    enemy_locks: Query<&crate::components::combat::MissileLock, Without<Player>>,
+    mut lock_text_query: Query<&mut Text, With<LockStatusText>>,
 ) {
-    let Ok((player_transform, weapon_mount, player_velocity, bonuses)) = player_query.get_single() else {
+    let Ok((player_transform, weapon_mount, bonuses)) = player_query.get_single() else {
         return;
     };
     
@@ -2383,7 +3298,6 @@ pub fn update_targeting_reticule_system(
     }
     
     let player_pos = player_transform.translation;
-    let player_vel = player_velocity.0;
     
     // Safety check: ensure player position is valid
     if !player_pos.is_finite() {
@@ -2454,13 +3368,12 @@ pub fn update_targeting_reticule_system(
             // Show lead indicator if target is moving significantly
             if target_speed > 5.0 {
                 // Calculate intercept point using the improved algorithm
-                if let Some(intercept_point) = calculate_intercept_point(
+                // Same intercept solver the AI gunners lead with
+                if let Some(intercept_point) = crate::utils::math::solve_intercept(
                     player_pos,
-                    player_vel,
                     target_pos,
                     target_vel,
                     projectile_speed,
-                    forward_vec,
                 ) {
                     // Safety check: ensure intercept point is valid
                     if intercept_point.is_finite() {
@@ -2531,13 +3444,63 @@ pub fn update_targeting_reticule_system(
         }
     }
     
+    // Missile lock state - the reticule blinks faster as the lock builds (visual lock tone)
+    let player_lock = lock_query.get_single().ok();
+    let lock_state = player_lock.map(|(_, lock, _)| (lock.is_locked(), lock.candidate.is_some(), lock.progress_fraction()));
+    
     // Update reticule colors based on targeting - CYBERPUNK COLORS
-    let (circle_color, dot_color) = if enemy_in_crosshair {
-        (colors::DANGER_COLOR, colors::DANGER_COLOR) // Red when targeting (locked on)
-    } else {
-        (colors::NEON_GREEN, colors::NEON_CYAN) // Green circle, cyan dot when not targeting
+    let (circle_color, dot_color) = match lock_state {
+        Some((true, _, _)) => (colors::DANGER_COLOR, colors::DANGER_COLOR), // Hard missile lock
+        Some((false, true, progress)) => {
+            let blink_rate = 4.0 + progress * 12.0;
+            if (time.elapsed_seconds() * blink_rate).sin() > 0.0 {
+                (colors::NEON_YELLOW, colors::NEON_YELLOW)
+            } else {
+                (colors::NEON_ORANGE, colors::NEON_CYAN)
+            }
+        }
+        _ if enemy_in_crosshair => (colors::DANGER_COLOR, colors::DANGER_COLOR), // Red when targeting
+        _ => (colors::NEON_GREEN, colors::NEON_CYAN), // Green circle, cyan dot when not targeting
     };
     
+    // Lock status text + incoming lock warning + flare count
+    if let Some((player_entity, lock, countermeasures)) = player_lock {
+        let being_locked = enemy_locks.iter().any(|l| l.target == Some(player_entity));
+        let being_tracked = enemy_locks.iter().any(|l| l.candidate == Some(player_entity));
+        
+        for mut text in lock_text_query.iter_mut() {
+            let (lock_line, lock_color) = if lock.is_locked() {
+                ("◆ MISSILE LOCK ◆".to_string(), colors::DANGER_COLOR)
+            } else if lock.candidate.is_some() {
+                let filled = (lock.progress_fraction() * 5.0).round() as usize;
+                (format!("LOCKING {}{}", "▮".repeat(filled), "▯".repeat(5 - filled)), colors::NEON_YELLOW)
+            } else {
+                (String::new(), colors::NEON_YELLOW)
+            };
+            
+            let warning = if being_locked {
+                "\n⚠ MISSILE LOCK WARNING ⚠"
+            } else if being_tracked {
+                "\n⚠ ENEMY LOCKING ⚠"
+            } else {
+                ""
+            };
+            
+            text.sections = vec![
+                TextSection::new(lock_line, TextStyle { font_size: 12.0, color: lock_color, ..default() }),
+                TextSection::new(warning, TextStyle { font_size: 12.0, color: colors::DANGER_COLOR, ..default() }),
+                TextSection::new(
+                    format!("\nFLARES {}/{} [F]", countermeasures.charges, countermeasures.max_charges),
+                    TextStyle {
+                        font_size: 10.0,
+                        color: if countermeasures.charges == 0 { colors::DANGER_COLOR } else { Color::srgb(0.6, 0.7, 0.8) },
+                        ..default()
+                    },
+                ),
+            ];
+        }
+    }
+    
     for mut border_color in circle_query.iter_mut() {
         *border_color = circle_color.into();
     }
@@ -2646,6 +3609,26 @@ pub fn setup_enemy_health_bars(
                 },
                 ShieldBarFill,
             ));
+            
+            // Status effect icons - one slot per kind, shown while active
+            for kind in crate::components::StatusEffectKind::ALL {
+                let x = -0.75 + kind.index() as f32 * 0.5;
+                parent.spawn((
+                    PbrBundle {
+                        mesh: meshes.add(Cuboid::new(0.3, 0.3, 0.01)),
+                        material: materials.add(StandardMaterial {
+                            base_color: kind.color(),
+                            emissive: (kind.color().to_linear() * 2.0).into(),
+                            unlit: true,
+                            ..default()
+                        }),
+                        transform: Transform::from_xyz(x, 0.45, 0.03),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                    EnemyStatusIcon { kind },
+                ));
+            }
         });
     }
 }
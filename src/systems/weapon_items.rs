@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::ai::Enemy;
+use crate::components::combat::WeaponMount;
+use crate::components::resources::Inventory;
+use crate::components::ship::{Player, Velocity};
+use crate::components::weapon_items::*;
+use crate::resources::PlayerInput;
+use crate::systems::resources_system::LootVisual;
+
+/// Roll and spawn a weapon item drop for a destroyed enemy
+pub fn spawn_weapon_drop_for_enemy(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    transform: &Transform,
+    enemy: &Enemy,
+    difficulty: u32,
+    rng: &mut impl Rng,
+) {
+    if rng.gen::<f32>() >= WeaponItem::drop_chance(enemy.enemy_type, difficulty) {
+        return;
+    }
+
+    let item = WeaponItem::roll(enemy.enemy_type, difficulty, rng);
+    println!("[Weapon Items] {:?} dropped {}", enemy.enemy_type, item.name);
+    spawn_weapon_drop(commands, meshes, materials, transform, item, rng);
+}
+
+/// Spawn a pickup for a specific weapon item near `transform`
+pub fn spawn_weapon_drop(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    transform: &Transform,
+    item: WeaponItem,
+    rng: &mut impl Rng,
+) {
+    let color = item.rarity.color();
+    let offset = Vec3::new(
+        (rng.gen::<f32>() - 0.5) * 4.0,
+        1.0,
+        (rng.gen::<f32>() - 0.5) * 4.0,
+    );
+
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(1.2, 0.3, 0.6)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive: LinearRgba::from(color) * 3.0,
+                metallic: 0.6,
+                perceptual_roughness: 0.3,
+                ..default()
+            }),
+            transform: Transform::from_translation(transform.translation + offset),
+            ..default()
+        },
+        WeaponDrop { item },
+        LootVisual {
+            lifetime: 90.0,  // Weapon drops linger longer than resources
+            rotation_speed: 1.5,
+        },
+        Velocity(Vec3::ZERO),
+    ));
+}
+
+/// Pull nearby weapon drops toward the player and add them to the stash
+pub fn weapon_drop_pickup_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut stash: ResMut<WeaponStash>,
+    player_query: Query<&Transform, With<Player>>,
+    mut drop_query: Query<(Entity, &mut Transform, &WeaponDrop, &mut Velocity), Without<Player>>,
+) {
+    let pickup_radius = 5.0;
+    let attraction_range = 60.0;
+    let dt = time.delta_seconds();
+
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    for (entity, mut drop_transform, weapon_drop, mut velocity) in drop_query.iter_mut() {
+        // Don't pull items in when there's no room for them
+        if stash.is_full() {
+            continue;
+        }
+
+        let to_player = player_transform.translation - drop_transform.translation;
+        let distance = to_player.length();
+
+        if distance > pickup_radius && distance < attraction_range {
+            velocity.0 += to_player.normalize() * 40.0 * dt;
+            drop_transform.translation += velocity.0 * dt;
+        }
+
+        if distance < pickup_radius {
+            println!("[Weapon Items] Picked up {} ({} affixes)", weapon_drop.item.name, weapon_drop.item.affixes.len());
+            stash.items.push(weapon_drop.item.clone());
+            stash.selected = stash.items.len() - 1;
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Stash controls: [ / ] cycle items, Y equips into the current weapon slot, Delete salvages for scrap
+pub fn weapon_stash_input_system(
+    input: Res<PlayerInput>,
+    mut stash: ResMut<WeaponStash>,
+    mut inventory: ResMut<Inventory>,
+    mut player_query: Query<&mut WeaponMount, With<Player>>,
+) {
+    if input.just_pressed(KeyCode::BracketRight) {
+        stash.cycle(true);
+    }
+    if input.just_pressed(KeyCode::BracketLeft) {
+        stash.cycle(false);
+    }
+
+    if input.just_pressed(KeyCode::KeyY) {
+        if let Ok(mut weapon_mount) = player_query.get_single_mut() {
+            if let Some(item) = stash.equip_selected(&mut weapon_mount) {
+                println!("[Weapon Items] Equipped {} in slot {}", item.name, weapon_mount.current_weapon + 1);
+            }
+        }
+    }
+
+    if input.just_pressed(KeyCode::Delete) {
+        if let Some(item) = stash.take_selected() {
+            let scrap = item.rarity.salvage_value();
+            inventory.scrap_metal += scrap;
+            println!("[Weapon Items] Salvaged {} for {} scrap", item.name, scrap);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use bevy::prelude::*;
+use crate::components::ship::*;
+use crate::components::combat::*;
+use crate::components::ai::*;
+use crate::components::resources::Inventory;
+use crate::components::squadron::FormationType;
+use crate::components::status_effects::StatusEffects;
+use crate::components::wingman::*;
+use crate::resources::PlayerInput;
+use crate::utils::{ship_builder, steering};
+
+/// Wing orders: F5 attack my target, F6 defend me, F7 form up, F8 hold position
+pub fn wingman_order_input_system(
+    input: Res<PlayerInput>,
+    mut wing: ResMut<WingCommand>,
+    mut wingmen: Query<(&Transform, &mut Wingman)>,
+) {
+    let order = [
+        (KeyCode::F5, WingmanOrder::AttackMyTarget),
+        (KeyCode::F6, WingmanOrder::DefendMe),
+        (KeyCode::F7, WingmanOrder::FormUp),
+        (KeyCode::F8, WingmanOrder::HoldPosition),
+    ].into_iter().find(|(key, _)| input.just_pressed(*key)).map(|(_, order)| order);
+
+    let Some(order) = order else {
+        return;
+    };
+
+    wing.order = order;
+    if order == WingmanOrder::HoldPosition {
+        for (transform, mut wingman) in wingmen.iter_mut() {
+            wingman.hold_point = transform.translation;
+        }
+    }
+    println!("[Wingman] Wing order: {}", order.name());
+}
+
+/// Hire a wingman (H) - costs scrap and tech components, up to the wing's size limit
+pub fn wingman_hire_system(
+    mut commands: Commands,
+    input: Res<PlayerInput>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut inventory: ResMut<Inventory>,
+    wing: Res<WingCommand>,
+    player_query: Query<&Transform, With<Player>>,
+    wingmen: Query<&Wingman>,
+    galaxy: Option<Res<crate::resources::Galaxy>>,
+) {
+    if !input.just_pressed(KeyCode::KeyH) {
+        return;
+    }
+
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    // First free callsign - its index is also the formation slot
+    let Some(index) = (0..wing.max_wingmen)
+        .find(|i| !wingmen.iter().any(|w| w.callsign == WingCommand::CALLSIGNS[*i]))
+    else {
+        println!("[Wingman] Wing is full ({}/{})", wing.max_wingmen, wing.max_wingmen);
+        return;
+    };
+
+    if inventory.scrap_metal < wing.hire_scrap_cost || inventory.tech_components < wing.hire_tech_cost {
+        println!("[Wingman] Not enough resources to hire a wingman ({}/{} scrap, {}/{} tech)",
+            inventory.scrap_metal, wing.hire_scrap_cost, inventory.tech_components, wing.hire_tech_cost);
+        return;
+    }
+    inventory.scrap_metal -= wing.hire_scrap_cost;
+    inventory.tech_components -= wing.hire_tech_cost;
+
+    // Hired pilots are as good as the local talent
+    let difficulty = galaxy.as_ref()
+        .and_then(|g| g.current_system())
+        .map_or(1, |s| s.difficulty);
+
+    let slot = FormationType::Wedge.slot_offset(index, wing.max_wingmen);
+    let position = player_transform.translation + player_transform.rotation * slot;
+    spawn_wingman(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        WingCommand::CALLSIGNS[index],
+        slot,
+        position,
+        player_transform.rotation,
+        difficulty,
+    );
+    println!("[Wingman] {} joins the wing", WingCommand::CALLSIGNS[index]);
+}
+
+/// Spawn a friendly fighter flying in the player's wing
+pub fn spawn_wingman(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    callsign: &'static str,
+    slot: Vec3,
+    position: Vec3,
+    rotation: Quat,
+    difficulty: u32,
+) -> Entity {
+    let wingman = commands.spawn((
+        SpatialBundle {
+            transform: Transform::from_translation(position).with_rotation(rotation),
+            ..default()
+        },
+        Ship::fighter(),
+        AIController::fighter(),
+        AISkill::for_enemy(EnemyType::Fighter, difficulty),
+        Health { current: 80.0, max: 80.0 },
+        Shield { current: 60.0, max: 60.0, recharge_rate: 6.0, recharge_delay: 2.0, time_since_last_hit: 10.0 },
+        WeaponMount {
+            weapons: vec![Weapon::laser()],
+            current_weapon: 0,
+        },
+        Energy {
+            current: 100.0,
+            max: 100.0,
+            recharge_rate: 15.0,
+        },
+        Velocity(Vec3::ZERO),
+        AngularVelocity(Vec3::ZERO),
+        Faction::Player,
+        PowerDistribution::default(),
+        StatusEffects::default(),
+        Wingman {
+            callsign,
+            slot,
+            hold_point: position,
+        },
+    )).id();
+
+    ship_builder::build_ship(
+        commands,
+        meshes,
+        materials,
+        ShipType::Fighter,
+        wingman,
+        Color::srgb(0.2, 0.6, 0.9),
+    );
+
+    wingman
+}
+
+/// Wingman targeting - runs after general target acquisition and narrows it down to the wing's order
+pub fn wingman_targeting_system(
+    wing: Res<WingCommand>,
+    mut wingmen: Query<(&Transform, &mut AIController, &Wingman)>,
+    player_query: Query<(&Transform, Option<&MissileLock>), With<Player>>,
+    ships: Query<(Entity, &Transform, &Faction), (With<Ship>, Without<Wingman>)>,
+    reputation: Res<crate::components::Reputation>,
+) {
+    let Ok((player_transform, player_lock)) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+
+    // Wingmen only pick fights with ships hostile to the player
+    let enemies: Vec<(Entity, Vec3)> = ships.iter()
+        .filter(|(_, _, faction)| reputation.is_hostile(Faction::Player, **faction))
+        .map(|(entity, transform, _)| (entity, transform.translation))
+        .collect();
+    let position_of = |target: Entity| enemies.iter().find(|(e, _)| *e == target).map(|(_, p)| *p);
+
+    match wing.order {
+        WingmanOrder::AttackMyTarget => {
+            // The player's missile lock, or failing that whatever is closest to the crosshair
+            let forward = player_transform.forward().as_vec3();
+            let target = player_lock.and_then(|lock| lock.target).filter(|t| position_of(*t).is_some()).or_else(|| {
+                enemies.iter()
+                    .filter_map(|(entity, position)| {
+                        let to_enemy = *position - player_pos;
+                        let distance = to_enemy.length();
+                        let dot = forward.dot(to_enemy / distance.max(0.001));
+                        (distance < 300.0 && dot > 15.0_f32.to_radians().cos()).then_some((*entity, dot))
+                    })
+                    .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                    .map(|(entity, _)| entity)
+            });
+            for (_, mut ai, _) in wingmen.iter_mut() {
+                // No target called - keep whatever the wingman had picked for itself
+                if target.is_some() {
+                    ai.target = target;
+                }
+                ai.squad_order = Some(AIAction::Attack);
+            }
+        }
+        WingmanOrder::DefendMe => {
+            // Go after whoever is closest to the player
+            let threat = enemies.iter()
+                .map(|(entity, position)| (*entity, position.distance(player_pos)))
+                .filter(|(_, distance)| *distance < WingCommand::DEFEND_RANGE)
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                .map(|(entity, _)| entity);
+            for (_, mut ai, _) in wingmen.iter_mut() {
+                ai.target = threat;
+                ai.squad_order = None;
+            }
+        }
+        WingmanOrder::FormUp => {
+            for (_, mut ai, _) in wingmen.iter_mut() {
+                ai.target = None;
+                ai.squad_order = None;
+            }
+        }
+        WingmanOrder::HoldPosition => {
+            for (_, mut ai, wingman) in wingmen.iter_mut() {
+                let in_reach = ai.target
+                    .and_then(|t| position_of(t))
+                    .map_or(false, |position| position.distance(wingman.hold_point) < WingCommand::HOLD_RANGE);
+                if !in_reach {
+                    ai.target = None;
+                }
+                ai.squad_order = None;
+            }
+        }
+    }
+}
+
+/// Wingmen with nothing to shoot fly their formation slot off the player's wing (or sit on their hold point)
+pub fn wingman_formation_system(
+    time: Res<Time>,
+    wing: Res<WingCommand>,
+    mut wingmen: Query<(&mut Transform, &mut Velocity, &Ship, &AIController, &Wingman), Without<Player>>,
+    player_query: Query<(&Transform, &Velocity), With<Player>>,
+) {
+    let Ok((player_transform, player_velocity)) = player_query.get_single() else {
+        return;
+    };
+    let dt = time.delta_seconds();
+
+    for (mut transform, mut velocity, ship, ai, wingman) in wingmen.iter_mut() {
+        if ai.target.is_some() {
+            continue;
+        }
+
+        let (destination, heading) = if wing.order == WingmanOrder::HoldPosition {
+            (wingman.hold_point, transform.forward().as_vec3())
+        } else {
+            (
+                player_transform.translation + player_transform.rotation * wingman.slot,
+                player_transform.forward().as_vec3(),
+            )
+        };
+
+        // Match the player's speed once in the slot, ease in while catching up
+        let max_speed = ship.max_speed * ship.boost_multiplier;
+        let desired_velocity = steering::arrival(transform.translation, destination, max_speed, 25.0)
+            + if wing.order == WingmanOrder::HoldPosition { Vec3::ZERO } else { player_velocity.0 };
+        let steer = (desired_velocity - velocity.0).clamp_length_max(ship.acceleration * 1.5 * dt);
+        velocity.0 += steer;
+        if velocity.0.length() > max_speed {
+            velocity.0 = velocity.0.normalize() * max_speed;
+        }
+
+        // Face where we're going while catching up, otherwise fly the player's heading
+        let facing = if transform.translation.distance(destination) > 15.0 {
+            (destination - transform.translation).normalize_or_zero()
+        } else {
+            heading
+        };
+        if facing != Vec3::ZERO {
+            let target_rotation = Transform::from_translation(transform.translation)
+                .looking_to(facing, Vec3::Y)
+                .rotation;
+            transform.rotation = transform.rotation.slerp(target_rotation, (ship.turn_rate * dt).min(1.0));
+        }
+    }
+}
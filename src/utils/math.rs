@@ -1,17 +1,62 @@
 use bevy::prelude::*;
 
-/// Calculate lead for targeting moving objects
+/// Refinement passes for the intercept solver - converges well within this for any target slower than the shot
+const INTERCEPT_ITERATIONS: usize = 16;
+/// Intercepts further out than this (seconds) are treated as unreachable
+const MAX_INTERCEPT_TIME: f32 = 10.0;
+
+/// Iteratively solve where a projectile fired now meets a target moving at constant velocity.
+/// Returns None when the target can't be caught (outrunning the shot, or too far out).
+pub fn solve_intercept(
+    shooter_pos: Vec3,
+    target_pos: Vec3,
+    target_velocity: Vec3,
+    projectile_speed: f32,
+) -> Option<Vec3> {
+    if !shooter_pos.is_finite() || !target_pos.is_finite() || !target_velocity.is_finite() {
+        return None;
+    }
+    if !projectile_speed.is_finite() || projectile_speed <= 0.0 {
+        return None;
+    }
+    
+    // Time of flight to where the target will be, re-aimed at the new prediction each pass
+    let mut time_to_impact = (target_pos - shooter_pos).length() / projectile_speed;
+    for _ in 0..INTERCEPT_ITERATIONS {
+        let predicted = target_pos + target_velocity * time_to_impact;
+        let refined = (predicted - shooter_pos).length() / projectile_speed;
+        let converged = (refined - time_to_impact).abs() < 0.001;
+        time_to_impact = refined;
+        if converged {
+            break;
+        }
+    }
+    
+    if !time_to_impact.is_finite() || time_to_impact > MAX_INTERCEPT_TIME {
+        return None;
+    }
+    
+    // Diverging iterations (target outrunning the shot) leave the shot short of the prediction
+    let intercept = target_pos + target_velocity * time_to_impact;
+    let miss = (intercept - shooter_pos).length() - projectile_speed * time_to_impact;
+    if miss.abs() > projectile_speed * time_to_impact * 0.05 + 0.1 {
+        return None;
+    }
+    
+    Some(intercept)
+}
+
+/// Calculate lead for targeting moving objects (falls back to a single-step prediction when no intercept exists)
 pub fn calculate_lead_point(
     shooter_pos: Vec3,
     target_pos: Vec3,
     target_velocity: Vec3,
     projectile_speed: f32,
 ) -> Vec3 {
-    let to_target = target_pos - shooter_pos;
-    let distance = to_target.length();
-    let time_to_impact = distance / projectile_speed;
-    
-    target_pos + target_velocity * time_to_impact
+    solve_intercept(shooter_pos, target_pos, target_velocity, projectile_speed).unwrap_or_else(|| {
+        let time_to_impact = (target_pos - shooter_pos).length() / projectile_speed.max(0.001);
+        target_pos + target_velocity * time_to_impact
+    })
 }
 
 /// Clamp a vector to a maximum length
@@ -23,6 +68,12 @@ pub fn clamp_length(v: Vec3, max_length: f32) -> Vec3 {
     }
 }
 
+/// Frame-rate independent `value *= factor` - `factor` is the damping per 1/60 s step (what the
+/// old per-frame drag was tuned at), scaled to however long `delta_time` actually was
+pub fn damping(factor: f32, delta_time: f32) -> f32 {
+    factor.powf(delta_time * 60.0)
+}
+
 /// Smooth damp for smooth interpolation
 pub fn smooth_damp(current: f32, target: f32, velocity: &mut f32, smooth_time: f32, delta_time: f32) -> f32 {
     let omega = 2.0 / smooth_time;
@@ -34,3 +85,16 @@ pub fn smooth_damp(current: f32, target: f32, velocity: &mut f32, smooth_time: f
     target + (change + temp) * exp
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damping_over_one_second_matches_sixty_steps() {
+        let stepped = (0..60).fold(1.0_f32, |value, _| value * damping(0.98, 1.0 / 60.0));
+        let whole = damping(0.98, 1.0);
+        assert!((stepped - whole).abs() < 1e-5, "{} vs {}", stepped, whole);
+        assert!((whole - 0.98_f32.powi(60)).abs() < 1e-5);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod math;
 pub mod ship_builder;
-
+pub mod steering;
+pub mod spawn_placement;
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
-use crate::components::ship::{ShipType, ShipPiece, ShipPieceType, ShipVisuals, UpgradeVisuals, ShipLight, ShipLightType, LightAnimation};
+use crate::components::carrier::Carrier;
+use crate::components::combat::AutoTurret;
+use crate::components::ship::{ShipType, ShipPiece, ShipPieceType, ShipVisuals, UpgradeVisuals, ShipLight, ShipLightType, LightAnimation, SubsystemHealth, SubsystemStatus};
 
 /// Definition of a ship piece for procedural generation
 pub struct ShipPieceDefinition {
@@ -44,10 +46,24 @@ pub fn build_ship(
         ShipType::CapitalShip => generate_capital_ship_layout(base_color),
     };
 
-    // Add ShipVisuals and UpgradeVisuals components to parent
+    // Health of each functional subsystem piece scales with ship size
+    let subsystem_health = match ship_type {
+        ShipType::Fighter => 15.0,
+        ShipType::Corvette => 30.0,
+        ShipType::Frigate => 50.0,
+        ShipType::CapitalShip => 100.0,
+    };
+    
+    let mut subsystem_status = SubsystemStatus::default();
+    for piece_def in &pieces {
+        subsystem_status.register(piece_def.piece_type);
+    }
+
+    // Add ShipVisuals, UpgradeVisuals and subsystem tracking components to parent
     commands.entity(parent_entity).insert((
         ShipVisuals { ship_type },
         UpgradeVisuals::default(),
+        subsystem_status,
     ));
 
     // Spawn all pieces as children of the parent ship entity
@@ -58,6 +74,7 @@ pub fn build_ship(
             materials,
             parent_entity,
             piece_def,
+            subsystem_health,
         );
     }
     
@@ -87,6 +104,7 @@ fn spawn_ship_piece(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     parent_entity: Entity,
     piece_def: ShipPieceDefinition,
+    subsystem_health: f32,
 ) {
     let mesh = match piece_def.shape {
         PieceShape::Cuboid { x, y, z } => meshes.add(Cuboid::new(x, y, z)),
@@ -121,6 +139,13 @@ fn spawn_ship_piece(
         ))
         .id();
 
+    // Functional pieces can be damaged and destroyed individually
+    if piece_def.piece_type.is_subsystem() {
+        commands.entity(piece_entity).insert(SubsystemHealth {
+            current: subsystem_health,
+        });
+    }
+
     // Make it a child of the parent ship
     commands.entity(parent_entity).add_child(piece_entity);
 }
@@ -174,6 +199,24 @@ fn generate_fighter_layout(base_color: Color) -> Vec<ShipPieceDefinition> {
             metallic: 0.9,
             emissive: Color::NONE,
         },
+        // Nose gun mount
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::WeaponMount,
+            shape: PieceShape::Cuboid { x: 0.2, y: 0.2, z: 0.6 },
+            transform: Transform::from_xyz(0.0, -0.25, 1.1),
+            color: Color::srgb(0.4, 0.4, 0.4),
+            metallic: 0.9,
+            emissive: Color::NONE,
+        },
+        // Shield emitter
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::ShieldEmitter,
+            shape: PieceShape::Capsule { radius: 0.2, depth: 0.1 },
+            transform: Transform::from_xyz(0.0, 0.35, -0.4),
+            color: Color::srgb(0.2, 0.6, 0.8),
+            metallic: 0.6,
+            emissive: Color::srgb(0.1, 0.5, 0.8),
+        },
     ]
 }
 
@@ -245,6 +288,33 @@ fn generate_corvette_layout(base_color: Color) -> Vec<ShipPieceDefinition> {
             metallic: 0.9,
             emissive: Color::NONE,
         },
+        // Left weapon mount
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::WeaponMount,
+            shape: PieceShape::Cuboid { x: 0.25, y: 0.25, z: 0.7 },
+            transform: Transform::from_xyz(-1.4, -0.15, 0.6),
+            color: Color::srgb(0.4, 0.4, 0.4),
+            metallic: 0.9,
+            emissive: Color::NONE,
+        },
+        // Right weapon mount
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::WeaponMount,
+            shape: PieceShape::Cuboid { x: 0.25, y: 0.25, z: 0.7 },
+            transform: Transform::from_xyz(1.4, -0.15, 0.6),
+            color: Color::srgb(0.4, 0.4, 0.4),
+            metallic: 0.9,
+            emissive: Color::NONE,
+        },
+        // Shield emitter
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::ShieldEmitter,
+            shape: PieceShape::Capsule { radius: 0.25, depth: 0.1 },
+            transform: Transform::from_xyz(0.0, 0.45, -0.6),
+            color: Color::srgb(0.2, 0.6, 0.8),
+            metallic: 0.6,
+            emissive: Color::srgb(0.1, 0.5, 0.8),
+        },
     ]
 }
 
@@ -343,12 +413,21 @@ fn generate_frigate_layout(base_color: Color) -> Vec<ShipPieceDefinition> {
             metallic: 0.9,
             emissive: Color::NONE,
         },
+        // Shield emitter
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::ShieldEmitter,
+            shape: PieceShape::Capsule { radius: 0.3, depth: 0.1 },
+            transform: Transform::from_xyz(0.0, 1.2, 0.5),
+            color: Color::srgb(0.2, 0.6, 0.8),
+            metallic: 0.6,
+            emissive: Color::srgb(0.1, 0.5, 0.8),
+        },
     ]
 }
 
 /// Generate capital ship layout - massive ship
 fn generate_capital_ship_layout(base_color: Color) -> Vec<ShipPieceDefinition> {
-    vec![
+    let mut pieces = vec![
         // Main hull - massive center
         ShipPieceDefinition {
             piece_type: ShipPieceType::Hull,
@@ -478,7 +557,49 @@ fn generate_capital_ship_layout(base_color: Color) -> Vec<ShipPieceDefinition> {
             metallic: 0.9,
             emissive: Color::NONE,
         },
-    ]
+        // Port shield emitter
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::ShieldEmitter,
+            shape: PieceShape::Capsule { radius: 0.4, depth: 0.1 },
+            transform: Transform::from_xyz(-1.0, 1.6, -1.5),
+            color: Color::srgb(0.2, 0.6, 0.8),
+            metallic: 0.6,
+            emissive: Color::srgb(0.1, 0.5, 0.8),
+        },
+        // Starboard shield emitter
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::ShieldEmitter,
+            shape: PieceShape::Capsule { radius: 0.4, depth: 0.1 },
+            transform: Transform::from_xyz(1.0, 1.6, -1.5),
+            color: Color::srgb(0.2, 0.6, 0.8),
+            metallic: 0.6,
+            emissive: Color::srgb(0.1, 0.5, 0.8),
+        },
+        // Hangar bay - launches fighter wings until destroyed
+        ShipPieceDefinition {
+            piece_type: ShipPieceType::Hangar,
+            shape: PieceShape::Cuboid { x: 2.2, y: 0.6, z: 2.4 },
+            transform: Transform::from_translation(Carrier::HANGAR_OFFSET),
+            color: Color::srgb(0.25, 0.25, 0.3),
+            metallic: 0.8,
+            emissive: Color::srgb(0.8, 0.4, 0.1),
+        },
+    ];
+
+    // Turret mounts sit exactly on the carrier's turret hardpoints
+    for hardpoint in AutoTurret::carrier().hardpoints {
+        pieces.push(ShipPieceDefinition {
+            piece_type: ShipPieceType::Turret,
+            shape: PieceShape::Cylinder { radius: 0.4, height: 0.6 },
+            transform: Transform::from_translation(hardpoint.offset)
+                .with_rotation(Quat::from_rotation_arc(Vec3::Y, hardpoint.mount_direction)),
+            color: Color::srgb(0.4, 0.4, 0.45),
+            metallic: 0.9,
+            emissive: Color::NONE,
+        });
+    }
+
+    pieces
 }
 
 /// Spawn a single ship light as a child entity
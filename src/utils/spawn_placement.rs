@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Minimum gap between a spawned ship and any planet or gate surface
+const SPAWN_CLEARANCE: f32 = 15.0;
+/// Candidate points tried per spawn
+const SPAWN_CANDIDATES: usize = 24;
+/// Gates further than this from the player aren't worth arriving at
+const MAX_GATE_DISTANCE: f32 = 400.0;
+/// How far outside a gate's ring arrivals appear
+const GATE_ARRIVAL_RANGE: (f32, f32) = (25.0, 45.0);
+
+/// Pick an arrival point `min_distance..max_distance` from the player that's clear of every
+/// obstacle (center, radius). Points out of view (`is_visible` false) and next to jump gates win.
+pub fn find_spawn_position(
+    player_pos: Vec3,
+    min_distance: f32,
+    max_distance: f32,
+    obstacles: &[(Vec3, f32)],
+    gates: &[Vec3],
+    is_visible: impl Fn(Vec3) -> bool,
+    rng: &mut impl Rng,
+) -> Vec3 {
+    let nearby_gates: Vec<Vec3> = gates.iter()
+        .copied()
+        .filter(|gate| gate.distance(player_pos) < MAX_GATE_DISTANCE)
+        .collect();
+
+    let mut best: Option<(f32, Vec3)> = None;
+    for i in 0..SPAWN_CANDIDATES {
+        // Every third candidate comes out of a gate, the rest from the ring around the player
+        let at_gate = !nearby_gates.is_empty() && i % 3 == 0;
+        let candidate = if at_gate {
+            let gate = nearby_gates[rng.gen_range(0..nearby_gates.len())];
+            let (near, far) = GATE_ARRIVAL_RANGE;
+            gate + random_horizontal(rng) * rng.gen_range(near..far)
+        } else {
+            let distance = rng.gen_range(min_distance..max_distance);
+            player_pos + random_horizontal(rng) * distance + Vec3::Y * rng.gen_range(-15.0..15.0)
+        };
+
+        if !is_clear(candidate, obstacles) {
+            continue;
+        }
+
+        let mut score = 0.0;
+        if !is_visible(candidate) {
+            score += 2.0;
+        }
+        if at_gate {
+            score += 1.0;
+        }
+        // Don't drop anyone right on top of the player, however hidden the spot
+        if candidate.distance(player_pos) < min_distance * 0.5 {
+            score -= 3.0;
+        }
+
+        if best.map_or(true, |(best_score, _)| score > best_score) {
+            best = Some((score, candidate));
+        }
+    }
+
+    best.map(|(_, position)| position).unwrap_or_else(|| {
+        let fallback = player_pos + random_horizontal(rng) * max_distance;
+        clear_of_obstacles(fallback, obstacles)
+    })
+}
+
+/// Push a point out of any obstacle it's inside (plus clearance)
+pub fn clear_of_obstacles(mut position: Vec3, obstacles: &[(Vec3, f32)]) -> Vec3 {
+    for (center, radius) in obstacles {
+        let offset = position - *center;
+        let min_distance = radius + SPAWN_CLEARANCE;
+        if offset.length() < min_distance {
+            position = *center + offset.normalize_or(Vec3::X) * min_distance;
+        }
+    }
+    position
+}
+
+fn is_clear(position: Vec3, obstacles: &[(Vec3, f32)]) -> bool {
+    obstacles.iter().all(|(center, radius)| position.distance(*center) >= radius + SPAWN_CLEARANCE)
+}
+
+fn random_horizontal(rng: &mut impl Rng) -> Vec3 {
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    Vec3::new(angle.cos(), 0.0, angle.sin())
+}
+
+/// Whether a world point is inside the camera's view frustum
+pub fn in_view(camera: &Camera, camera_transform: &GlobalTransform, position: Vec3) -> bool {
+    camera.world_to_ndc(camera_transform, position)
+        .map_or(false, |ndc| ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 && (0.0..=1.0).contains(&ndc.z))
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+/// Push away from neighbours inside `radius`, harder the closer they are
+pub fn separation(position: Vec3, neighbours: impl Iterator<Item = Vec3>, radius: f32) -> Vec3 {
+    let mut push = Vec3::ZERO;
+    for neighbour in neighbours {
+        let away = position - neighbour;
+        let distance = away.length();
+        if distance < 0.001 || distance > radius {
+            continue;
+        }
+        push += away / distance * (1.0 - distance / radius);
+    }
+    push
+}
+
+/// Steer around spherical obstacles (center, radius) ahead on the current heading.
+/// Only the most imminent obstacle within `look_ahead_time` seconds is avoided.
+pub fn obstacle_avoidance(
+    position: Vec3,
+    velocity: Vec3,
+    obstacles: &[(Vec3, f32)],
+    clearance: f32,
+    look_ahead_time: f32,
+) -> Vec3 {
+    let speed = velocity.length();
+    if speed < 0.1 {
+        return Vec3::ZERO;
+    }
+    let heading = velocity / speed;
+    let look_ahead = speed * look_ahead_time;
+
+    let mut most_imminent: Option<(f32, Vec3)> = None;
+    for (center, radius) in obstacles {
+        let along = (*center - position).dot(heading);
+        if along < 0.0 || along > look_ahead + radius {
+            continue;
+        }
+        let lateral = position + heading * along - *center;
+        let safe_radius = radius + clearance;
+        let miss = lateral.length();
+        if miss >= safe_radius {
+            continue;
+        }
+        if most_imminent.as_ref().map_or(false, |(a, _)| *a <= along) {
+            continue;
+        }
+
+        // Dead-center approach - pick any sideways direction
+        let side = if miss > 0.001 {
+            lateral / miss
+        } else {
+            heading.any_orthonormal_vector()
+        };
+        let urgency = (1.0 - along / (look_ahead + radius)).clamp(0.2, 1.0);
+        most_imminent = Some((along, side * urgency * (safe_radius - miss) / safe_radius));
+    }
+
+    most_imminent.map_or(Vec3::ZERO, |(_, steer)| steer)
+}
+
+/// Desired velocity to arrive at `target`, easing off inside `slowing_radius`
+pub fn arrival(position: Vec3, target: Vec3, max_speed: f32, slowing_radius: f32) -> Vec3 {
+    let offset = target - position;
+    let distance = offset.length();
+    if distance < 0.001 {
+        return Vec3::ZERO;
+    }
+    let speed = max_speed * (distance / slowing_radius).min(1.0);
+    offset / distance * speed
+}
+
+/// Desired velocity to intercept a moving target - seek where it will be, not where it is
+pub fn pursuit(position: Vec3, target: Vec3, target_velocity: Vec3, max_speed: f32) -> Vec3 {
+    let prediction = (position.distance(target) / max_speed.max(0.001)).min(3.0);
+    (target + target_velocity * prediction - position).normalize_or_zero() * max_speed
+}
+
+/// Keep a ship outside every obstacle's clearance sphere: push it back out if it's inside,
+/// and strip any velocity that would carry it in before the next step
+pub fn enforce_clearance(
+    position: &mut Vec3,
+    velocity: &mut Vec3,
+    obstacles: &[(Vec3, f32)],
+    clearance: f32,
+    dt: f32,
+) {
+    for (center, radius) in obstacles {
+        let min_distance = radius + clearance;
+        let offset = *position - *center;
+        let distance = offset.length();
+        let normal = if distance > 0.001 { offset / distance } else { Vec3::Y };
+
+        if distance < min_distance {
+            *position = *center + normal * min_distance;
+        }
+
+        let next_distance = (*position + *velocity * dt - *center).length();
+        if next_distance < min_distance {
+            let inward = velocity.dot(normal).min(0.0);
+            *velocity -= normal * inward;
+        }
+    }
+}